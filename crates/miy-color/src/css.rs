@@ -0,0 +1,1007 @@
+//! CSS Color Level 4 syntax.
+//!
+//! [`parse`] reads every color a stylesheet can contain without context: hex colors, named
+//! colors, `transparent`, and the `rgb()`, `hsl()`, `hwb()`, `lab()`, `lch()`, `oklab()`,
+//! `oklch()` and `color()` functions in both the modern space separated and the legacy comma
+//! separated syntax. `Color` implements [`FromStr`](core::str::FromStr) with it and
+//! [`Display`](fmt::Display) writes colors back out in a form it reads.
+//!
+//! Like browsers, `rgb()` and friends clamp channels into range. `lab()`, `lch()` and `color()`
+//! can describe colors outside of sRGB, those become [`Oklab`] and are gamut mapped when
+//! converted.
+
+use core::fmt;
+
+use crate::{Color, Hsl, Hwb, Oklab, Oklch, Rgb32, Rgba32, Srgb, Srgba, rgb, srgb};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseColorError {
+    /// Nothing but whitespace
+    Empty,
+    /// `#` followed by something other than 3, 4, 6 or 8 hex digits
+    InvalidHex,
+    /// Not a named color
+    UnknownName,
+    UnknownFunction,
+    /// The first argument of `color()` is not a supported color space
+    UnknownColorSpace,
+    /// Missing parenthesis, empty arguments or a misplaced `/`
+    InvalidSyntax,
+    /// A function got the wrong number of channels
+    ArgumentCount {
+        expected: usize,
+        found: usize,
+    },
+    /// Argument `index`, counting from zero with alpha last, isn't valid at its position
+    InvalidArgument {
+        index: usize,
+    },
+    /// `currentcolor` and system colors depend on the document
+    ContextDependent,
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty color"),
+            Self::InvalidHex => write!(f, "hex colors need 3, 4, 6 or 8 hex digits"),
+            Self::UnknownName => write!(f, "unknown color name"),
+            Self::UnknownFunction => write!(f, "unknown color function"),
+            Self::UnknownColorSpace => write!(f, "unknown color space"),
+            Self::InvalidSyntax => write!(f, "invalid color syntax"),
+            Self::ArgumentCount { expected, found } => {
+                write!(f, "expected {expected} color channels, found {found}")
+            }
+            Self::InvalidArgument { index } => write!(f, "invalid color argument {index}"),
+            Self::ContextDependent => write!(f, "color depends on the document"),
+        }
+    }
+}
+
+impl core::error::Error for ParseColorError {}
+
+/// Parses a CSS color, names and functions are case insensitive
+///
+/// ```
+/// use miy_color::{Color, Srgb};
+///
+/// let color: Color = "rgb(26 36 33 / 50%)".parse().unwrap();
+/// assert_eq!(color.to_string(), "#1a242180");
+/// assert_eq!(miy_color::css::parse("rebeccapurple"), Ok(Color::Srgb(Srgb::from_hex("#663399").unwrap())));
+/// ```
+pub fn parse(input: &str) -> Result<Color, ParseColorError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(ParseColorError::Empty);
+    }
+    if input.starts_with('#') {
+        return Srgba::from_hex(input)
+            .map(|srgba| match input.len() {
+                4 | 7 => Color::Srgb(srgba.into()),
+                _ => Color::Srgba(srgba),
+            })
+            .ok_or(ParseColorError::InvalidHex);
+    }
+
+    let Some((name, arguments)) = input.split_once('(') else {
+        return named(input);
+    };
+    let arguments = arguments
+        .strip_suffix(')')
+        .ok_or(ParseColorError::InvalidSyntax)?;
+    let name = name.trim_end();
+    let function = FUNCTIONS
+        .iter()
+        .find(|(function, _)| function.eq_ignore_ascii_case(name))
+        .map(|(_, function)| *function)
+        .ok_or(ParseColorError::UnknownFunction)?;
+
+    let arguments = Arguments::new(arguments, function)?;
+    let color = match function {
+        Function::Rgb => rgb(&arguments)?,
+        Function::Hsl => hsl(&arguments)?,
+        Function::Hwb => hwb(&arguments)?,
+        Function::Lab => lab(&arguments)?,
+        Function::Lch => lch(&arguments)?,
+        Function::Oklab => oklab(&arguments)?,
+        Function::Oklch => oklch(&arguments)?,
+        Function::Color => color(&arguments)?,
+    };
+    Ok(with_alpha(color, arguments.alpha()?))
+}
+
+fn named(name: &str) -> Result<Color, ParseColorError> {
+    if name.eq_ignore_ascii_case("transparent") {
+        let zero = srgb::Channel::from_u8(0);
+        return Ok(Color::Srgba(Srgba {
+            r: zero,
+            g: zero,
+            b: zero,
+            a: zero,
+        }));
+    }
+    if name.eq_ignore_ascii_case("currentcolor")
+        || SYSTEM_COLORS
+            .iter()
+            .any(|system| system.eq_ignore_ascii_case(name))
+    {
+        return Err(ParseColorError::ContextDependent);
+    }
+
+    NAMED_COLORS
+        .iter()
+        .find(|(named, _)| named.eq_ignore_ascii_case(name))
+        .map(|(_, hex)| Color::Srgb(srgb_from_u32(*hex)))
+        .ok_or(ParseColorError::UnknownName)
+}
+
+pub(crate) fn srgb_from_u32(hex: u32) -> Srgb {
+    let [_, r, g, b] = hex.to_be_bytes();
+    Srgb {
+        r: srgb::Channel::from_u8(r),
+        g: srgb::Channel::from_u8(g),
+        b: srgb::Channel::from_u8(b),
+    }
+}
+
+fn with_alpha(color: Color, alpha: f32) -> Color {
+    if alpha >= 1.0 {
+        return color;
+    }
+    match color {
+        // linear colors keep their channels, so `Color::Rgba32` reads back as itself
+        Color::Rgb32(Rgb32 { r, g, b }) => Color::Rgba32(Rgba32 {
+            r,
+            g,
+            b,
+            a: rgb::Channel::clamped(alpha),
+        }),
+        color => Color::Srgba(Srgba {
+            a: srgb::Channel::clamped(alpha),
+            ..color.to_srgba()
+        }),
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Function {
+    Rgb,
+    Hsl,
+    Hwb,
+    Lab,
+    Lch,
+    Oklab,
+    Oklch,
+    Color,
+}
+
+const FUNCTIONS: [(&str, Function); 10] = [
+    ("rgb", Function::Rgb),
+    ("rgba", Function::Rgb),
+    ("hsl", Function::Hsl),
+    ("hsla", Function::Hsl),
+    ("hwb", Function::Hwb),
+    ("lab", Function::Lab),
+    ("lch", Function::Lch),
+    ("oklab", Function::Oklab),
+    ("oklch", Function::Oklch),
+    ("color", Function::Color),
+];
+
+/// A single channel as written
+#[derive(Copy, Clone, PartialEq)]
+enum Value {
+    Number(f32),
+    Percentage(f32),
+    /// In degrees
+    Angle(f32),
+    None,
+}
+
+/// The channels and alpha of a function, split by either syntax
+struct Arguments<'a> {
+    channels: [&'a str; 4],
+    count: usize,
+    alpha: Option<&'a str>,
+    legacy: bool,
+}
+
+impl<'a> Arguments<'a> {
+    fn new(arguments: &'a str, function: Function) -> Result<Self, ParseColorError> {
+        let legacy = arguments.contains(',');
+        let (channels, count, alpha) = if legacy {
+            if !matches!(function, Function::Rgb | Function::Hsl) || arguments.contains('/') {
+                return Err(ParseColorError::InvalidSyntax);
+            }
+            let (channels, count) = split(arguments.split(',').map(str::trim));
+            // the fourth argument is alpha
+            match channels {
+                [r, g, b, alpha] if count == 4 => ([r, g, b, ""], 3, Some(alpha)),
+                _ if count > 4 => {
+                    return Err(ParseColorError::ArgumentCount {
+                        expected: 3,
+                        found: count.saturating_sub(1),
+                    });
+                }
+                _ => (channels, count, None),
+            }
+        } else {
+            let (channels, alpha) = match arguments.split_once('/') {
+                Some((channels, alpha)) => (channels, Some(alpha.trim())),
+                None => (arguments, None),
+            };
+            let (channels, count) = split(channels.split_whitespace());
+            // color() has the color space as an extra first argument
+            let expected = if function == Function::Color { 4 } else { 3 };
+            if count > expected {
+                return Err(ParseColorError::ArgumentCount {
+                    expected,
+                    found: count,
+                });
+            }
+            (channels, count, alpha)
+        };
+
+        let arguments = Self {
+            channels,
+            count,
+            alpha,
+            legacy,
+        };
+        let invalid = |token: &str| token.is_empty() || token.contains(char::is_whitespace);
+        if arguments.channels().any(invalid) || arguments.alpha.is_some_and(invalid) {
+            return Err(ParseColorError::InvalidSyntax);
+        }
+        Ok(arguments)
+    }
+
+    fn channels(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.channels.iter().take(self.count).copied()
+    }
+
+    /// Parses channel `index`, `none` is only valid in the modern syntax
+    fn value(&self, index: usize) -> Result<Value, ParseColorError> {
+        let token = self.channels.get(index).copied().unwrap_or_default();
+        let value = parse_value(token).ok_or(ParseColorError::InvalidArgument { index })?;
+        if self.legacy && value == Value::None {
+            return Err(ParseColorError::InvalidArgument { index });
+        }
+        Ok(value)
+    }
+
+    fn expect(&self, expected: usize) -> Result<(), ParseColorError> {
+        match self.count == expected {
+            true => Ok(()),
+            false => Err(ParseColorError::ArgumentCount {
+                expected,
+                found: self.count,
+            }),
+        }
+    }
+
+    /// Number or percentage, scaled so 100% is `reference`
+    fn number(&self, index: usize, reference: f32) -> Result<f32, ParseColorError> {
+        match self.value(index)? {
+            Value::Number(number) => Ok(number),
+            Value::Percentage(percentage) => Ok(percentage / 100.0 * reference),
+            Value::None => Ok(0.0),
+            Value::Angle(_) => Err(ParseColorError::InvalidArgument { index }),
+        }
+    }
+
+    /// Hue in degrees
+    fn hue(&self, index: usize) -> Result<f32, ParseColorError> {
+        match self.value(index)? {
+            Value::Number(degrees) | Value::Angle(degrees) => Ok(degrees),
+            Value::None => Ok(0.0),
+            Value::Percentage(_) => Err(ParseColorError::InvalidArgument { index }),
+        }
+    }
+
+    /// Alpha within `0..=1`, opaque if not given
+    fn alpha(&self) -> Result<f32, ParseColorError> {
+        let Some(token) = self.alpha else {
+            return Ok(1.0);
+        };
+        let index = self.count;
+        let alpha = match parse_value(token) {
+            Some(Value::Number(alpha)) => alpha,
+            Some(Value::Percentage(percentage)) => percentage / 100.0,
+            Some(Value::None) if !self.legacy => 0.0,
+            _ => return Err(ParseColorError::InvalidArgument { index }),
+        };
+        Ok(alpha.clamp(0.0, 1.0))
+    }
+}
+
+/// The first four tokens and how many there are in total
+fn split<'a>(tokens: impl Iterator<Item = &'a str> + Clone) -> ([&'a str; 4], usize) {
+    let mut channels = [""; 4];
+    let count = tokens.clone().count();
+    for (channel, token) in channels.iter_mut().zip(tokens) {
+        *channel = token;
+    }
+    (channels, count)
+}
+
+fn parse_value(token: &str) -> Option<Value> {
+    if token.eq_ignore_ascii_case("none") {
+        return Some(Value::None);
+    }
+
+    let number = |digits: &str| {
+        digits
+            .parse::<f32>()
+            .ok()
+            .filter(|number| number.is_finite())
+    };
+    if let Some(percentage) = token.strip_suffix('%') {
+        return number(percentage).map(Value::Percentage);
+    }
+
+    const UNITS: [(&str, f32); 4] = [
+        ("deg", 1.0),
+        ("grad", 0.9),
+        ("rad", 180.0 / core::f32::consts::PI),
+        ("turn", 360.0),
+    ];
+    // "grad" ends in "rad", so it is checked first
+    for (unit, degrees) in UNITS {
+        let split = token
+            .len()
+            .checked_sub(unit.len())
+            .filter(|split| token.is_char_boundary(*split));
+        if let Some(split) = split
+            && token[split..].eq_ignore_ascii_case(unit)
+        {
+            return number(&token[..split]).map(|angle| Value::Angle(angle * degrees));
+        }
+    }
+
+    number(token).map(Value::Number)
+}
+
+fn rgb(arguments: &Arguments) -> Result<Color, ParseColorError> {
+    arguments.expect(3)?;
+    let mut channels = [0.0; 3];
+    let mut percentages = None;
+    for (index, channel) in channels.iter_mut().enumerate() {
+        let value = arguments.value(index)?;
+        // the legacy syntax doesn't mix numbers and percentages
+        let is_percentage = matches!(value, Value::Percentage(_));
+        if arguments.legacy && *percentages.get_or_insert(is_percentage) != is_percentage {
+            return Err(ParseColorError::InvalidArgument { index });
+        }
+        *channel = arguments.number(index, 255.0)? / 255.0;
+    }
+
+    let [r, g, b] = channels.map(srgb::Channel::clamped);
+    Ok(Color::Srgb(Srgb { r, g, b }))
+}
+
+fn hsl(arguments: &Arguments) -> Result<Color, ParseColorError> {
+    arguments.expect(3)?;
+    // the legacy syntax needs percentages, the modern one also takes plain numbers out of 100
+    if arguments.legacy {
+        for index in 1..3 {
+            if !matches!(arguments.value(index)?, Value::Percentage(_)) {
+                return Err(ParseColorError::InvalidArgument { index });
+            }
+        }
+    }
+
+    let h = arguments.hue(0)?;
+    let s = (arguments.number(1, 100.0)? / 100.0).clamp(0.0, 1.0);
+    let l = (arguments.number(2, 100.0)? / 100.0).clamp(0.0, 1.0);
+    Hsl::new(h, s, l)
+        .map(Color::Hsl)
+        .ok_or(ParseColorError::InvalidArgument { index: 0 })
+}
+
+fn hwb(arguments: &Arguments) -> Result<Color, ParseColorError> {
+    arguments.expect(3)?;
+    let h = arguments.hue(0)?;
+    let w = (arguments.number(1, 100.0)? / 100.0).clamp(0.0, 1.0);
+    let b = (arguments.number(2, 100.0)? / 100.0).clamp(0.0, 1.0);
+    Hwb::new(h, w, b)
+        .map(Color::Hwb)
+        .ok_or(ParseColorError::InvalidArgument { index: 0 })
+}
+
+fn lab(arguments: &Arguments) -> Result<Color, ParseColorError> {
+    arguments.expect(3)?;
+    let l = arguments.number(0, 100.0)?.clamp(0.0, 100.0);
+    let a = arguments.number(1, 125.0)?;
+    let b = arguments.number(2, 125.0)?;
+    Ok(from_xyz_d50(lab_to_xyz_d50(l, a, b)))
+}
+
+fn lch(arguments: &Arguments) -> Result<Color, ParseColorError> {
+    arguments.expect(3)?;
+    let l = arguments.number(0, 100.0)?.clamp(0.0, 100.0);
+    let c = arguments.number(1, 150.0)?.max(0.0);
+    let (sin, cos) = arguments.hue(2)?.to_radians().sin_cos();
+    Ok(from_xyz_d50(lab_to_xyz_d50(l, c * cos, c * sin)))
+}
+
+fn oklab(arguments: &Arguments) -> Result<Color, ParseColorError> {
+    arguments.expect(3)?;
+    let l = arguments.number(0, 1.0)?.clamp(0.0, 1.0);
+    let a = arguments.number(1, 0.4)?;
+    let b = arguments.number(2, 0.4)?;
+    Oklab::new(l, a, b)
+        .map(Color::Oklab)
+        .ok_or(ParseColorError::InvalidArgument { index: 1 })
+}
+
+fn oklch(arguments: &Arguments) -> Result<Color, ParseColorError> {
+    arguments.expect(3)?;
+    let l = arguments.number(0, 1.0)?.clamp(0.0, 1.0);
+    let c = arguments.number(1, 0.4)?.max(0.0);
+    let h = arguments.hue(2)?;
+    Oklch::new(l, c, h)
+        .map(Color::Oklch)
+        .ok_or(ParseColorError::InvalidArgument { index: 1 })
+}
+
+/// `color(space c1 c2 c3)`, the channel indices in errors count the color space too
+fn color(arguments: &Arguments) -> Result<Color, ParseColorError> {
+    arguments.expect(4)?;
+    let [space, ..] = arguments.channels;
+    let channels = [1, 2, 3].map(|index| arguments.number(index, 1.0));
+    let [c1, c2, c3] = channels;
+    let values = [c1?, c2?, c3?];
+    let in_range = values.iter().all(|value| (0.0..=1.0).contains(value));
+
+    let space = COLOR_SPACES
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(space))
+        .map(|(_, space)| *space)
+        .ok_or(ParseColorError::UnknownColorSpace)?;
+    let linear = match space {
+        ColorSpace::Srgb if in_range => {
+            let [r, g, b] = values.map(srgb::Channel::clamped);
+            return Ok(Color::Srgb(Srgb { r, g, b }));
+        }
+        ColorSpace::Srgb => values.map(decode_srgb),
+        ColorSpace::SrgbLinear => values,
+        ColorSpace::DisplayP3 => multiply(&P3_TO_SRGB, values.map(decode_srgb)),
+        ColorSpace::A98Rgb => {
+            let xyz = multiply(&A98_TO_XYZ_D65, values.map(decode_a98));
+            multiply(&XYZ_D65_TO_SRGB, xyz)
+        }
+        ColorSpace::ProphotoRgb => {
+            let xyz = multiply(&PROPHOTO_TO_XYZ_D50, values.map(decode_prophoto));
+            return Ok(from_xyz_d50(xyz));
+        }
+        ColorSpace::Rec2020 => {
+            let xyz = multiply(&REC2020_TO_XYZ_D65, values.map(decode_rec2020));
+            multiply(&XYZ_D65_TO_SRGB, xyz)
+        }
+        ColorSpace::XyzD65 => multiply(&XYZ_D65_TO_SRGB, values),
+        ColorSpace::XyzD50 => return Ok(from_xyz_d50(values)),
+    };
+    Ok(from_linear_srgb(linear))
+}
+
+#[derive(Copy, Clone)]
+enum ColorSpace {
+    Srgb,
+    SrgbLinear,
+    DisplayP3,
+    A98Rgb,
+    ProphotoRgb,
+    Rec2020,
+    XyzD65,
+    XyzD50,
+}
+
+const COLOR_SPACES: [(&str, ColorSpace); 9] = [
+    ("srgb", ColorSpace::Srgb),
+    ("srgb-linear", ColorSpace::SrgbLinear),
+    ("display-p3", ColorSpace::DisplayP3),
+    ("a98-rgb", ColorSpace::A98Rgb),
+    ("prophoto-rgb", ColorSpace::ProphotoRgb),
+    ("rec2020", ColorSpace::Rec2020),
+    ("xyz", ColorSpace::XyzD65),
+    ("xyz-d65", ColorSpace::XyzD65),
+    ("xyz-d50", ColorSpace::XyzD50),
+];
+
+const XYZ_D65_TO_SRGB: [[f32; 3]; 3] = [
+    [3.240_97, -1.537_383_2, -0.498_610_76],
+    [-0.969_243_6, 1.875_967_5, 0.041_555_06],
+    [0.055_630_08, -0.203_976_96, 1.056_971_5],
+];
+
+/// Bradford chromatic adaptation
+const XYZ_D50_TO_D65: [[f32; 3]; 3] = [
+    [0.955_473_4, -0.023_098_54, 0.063_259_31],
+    [-0.028_369_7, 1.009_995_5, 0.021_041_4],
+    [0.012_314_01, -0.020_507_7, 1.330_365_9],
+];
+
+const P3_TO_SRGB: [[f32; 3]; 3] = [
+    [1.224_940_2, -0.224_940_4, 0.0],
+    [-0.042_056_955, 1.042_057_1, 0.0],
+    [-0.019_637_555, -0.078_636_05, 1.098_273_5],
+];
+
+const A98_TO_XYZ_D65: [[f32; 3]; 3] = [
+    [0.576_669, 0.185_558_24, 0.188_228_65],
+    [0.297_344_98, 0.627_363_6, 0.075_291_46],
+    [0.027_031_36, 0.070_688_85, 0.991_337_5],
+];
+
+/// ProPhoto RGB uses the D50 white point
+const PROPHOTO_TO_XYZ_D50: [[f32; 3]; 3] = [
+    [0.797_766_6, 0.135_181_3, 0.031_347_73],
+    [0.288_074_83, 0.711_835_2, 0.000_089_94],
+    [0.0, 0.0, 0.825_104_6],
+];
+
+const REC2020_TO_XYZ_D65: [[f32; 3]; 3] = [
+    [0.636_958, 0.144_616_9, 0.168_880_98],
+    [0.262_700_2, 0.677_998_1, 0.059_301_72],
+    [0.0, 0.028_072_69, 1.060_985_1],
+];
+
+fn multiply(matrix: &[[f32; 3]; 3], [x, y, z]: [f32; 3]) -> [f32; 3] {
+    matrix.map(|[a, b, c]| a * x + b * y + c * z)
+}
+
+/// Gamma decoding extended to negative values by symmetry
+fn decode_srgb(value: f32) -> f32 {
+    let magnitude = value.abs();
+    let linear = if magnitude <= 0.04045 {
+        magnitude / 12.92
+    } else {
+        ((magnitude + 0.055) / 1.055).powf(2.4)
+    };
+    linear.copysign(value)
+}
+
+/// Pure gamma of 563/256, extended to negative values like [`decode_srgb`]
+fn decode_a98(value: f32) -> f32 {
+    value.abs().powf(563.0 / 256.0).copysign(value)
+}
+
+/// Gamma of 1.8 with a linear segment near black
+fn decode_prophoto(value: f32) -> f32 {
+    let magnitude = value.abs();
+    let linear = if magnitude <= 16.0 / 512.0 {
+        magnitude / 16.0
+    } else {
+        magnitude.powf(1.8)
+    };
+    linear.copysign(value)
+}
+
+/// Inverse of the ITU-R BT.2020 transfer function
+fn decode_rec2020(value: f32) -> f32 {
+    const ALPHA: f32 = 1.099_296_8;
+    const BETA: f32 = 0.018_053_97;
+
+    let magnitude = value.abs();
+    let linear = if magnitude < BETA * 4.5 {
+        magnitude / 4.5
+    } else {
+        ((magnitude + ALPHA - 1.0) / ALPHA).powf(1.0 / 0.45)
+    };
+    linear.copysign(value)
+}
+
+/// CIE Lab with the D50 white point
+fn lab_to_xyz_d50(l: f32, a: f32, b: f32) -> [f32; 3] {
+    const KAPPA: f32 = 24389.0 / 27.0;
+    const EPSILON: f32 = 216.0 / 24389.0;
+    const WHITE: [f32; 3] = [0.964_22, 1.0, 0.825_21];
+
+    let fy = (l + 16.0) / 116.0;
+    let fx = fy + a / 500.0;
+    let fz = fy - b / 200.0;
+    let component = |f: f32| match f.powi(3) > EPSILON {
+        true => f.powi(3),
+        false => (116.0 * f - 16.0) / KAPPA,
+    };
+    let y = match l > KAPPA * EPSILON {
+        true => fy.powi(3),
+        false => l / KAPPA,
+    };
+    let [wx, wy, wz] = WHITE;
+    [component(fx) * wx, y * wy, component(fz) * wz]
+}
+
+fn from_xyz_d50(xyz: [f32; 3]) -> Color {
+    from_linear_srgb(multiply(&XYZ_D65_TO_SRGB, multiply(&XYZ_D50_TO_D65, xyz)))
+}
+
+/// Linear sRGB that may be out of range, kept exact as [`Oklab`] when it is
+fn from_linear_srgb(linear: [f32; 3]) -> Color {
+    const EPSILON: f32 = 1e-5;
+    if linear
+        .iter()
+        .all(|value| (-EPSILON..=1.0 + EPSILON).contains(value))
+    {
+        let [r, g, b] = linear.map(rgb::Channel::clamped);
+        return Color::Rgb32(Rgb32 { r, g, b });
+    }
+    Color::Oklab(Oklab::from_linear_srgb(linear))
+}
+
+/// CSS syntax that [`parse`] reads back into the same variant, except for [`Color::Hsv`] which
+/// has none and is written as hex
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Srgb(srgb) => write!(f, "{srgb}"),
+            Self::Srgba(srgba) => write!(f, "{srgba}"),
+            Self::Rgb32(rgb) => {
+                let [r, g, b] = rgb.to_array();
+                write!(f, "color(srgb-linear {r} {g} {b})")
+            }
+            Self::Rgba32(rgba) => {
+                let Rgba32 { r, g, b, a } = rgba;
+                let (r, g, b, a) = (r.get(), g.get(), b.get(), a.get());
+                write!(f, "color(srgb-linear {r} {g} {b} / {a})")
+            }
+            Self::Hsl(Hsl { h, s, l }) => {
+                let (h, s, l) = (h.get(), s.get() * 100.0, l.get() * 100.0);
+                write!(f, "hsl({h} {s}% {l}%)")
+            }
+            Self::Hsv(hsv) => write!(f, "{}", Srgb::from(*hsv)),
+            Self::Hwb(Hwb { h, w, b }) => {
+                let (h, w, b) = (h.get(), w.get() * 100.0, b.get() * 100.0);
+                write!(f, "hwb({h} {w}% {b}%)")
+            }
+            Self::Oklab(oklab) => {
+                let (l, a, b) = (oklab.l(), oklab.a(), oklab.b());
+                write!(f, "oklab({l} {a} {b})")
+            }
+            Self::Oklch(oklch) => {
+                let (l, c, h) = (oklch.l(), oklch.c(), oklch.h().get());
+                write!(f, "oklch({l} {c} {h})")
+            }
+        }
+    }
+}
+
+impl core::str::FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        parse(input)
+    }
+}
+
+/// The system colors of CSS Color Level 4, chosen by the user agent
+const SYSTEM_COLORS: [&str; 19] = [
+    "accentcolor",
+    "accentcolortext",
+    "activetext",
+    "buttonborder",
+    "buttonface",
+    "buttontext",
+    "canvas",
+    "canvastext",
+    "field",
+    "fieldtext",
+    "graytext",
+    "highlight",
+    "highlighttext",
+    "linktext",
+    "mark",
+    "marktext",
+    "selecteditem",
+    "selecteditemtext",
+    "visitedtext",
+];
+
+/// The named colors of CSS Color Level 4 as `0xrrggbb`
+pub(crate) const NAMED_COLORS: [(&str, u32); 148] = [
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::mem::discriminant;
+
+    fn srgba(color: Color) -> [u8; 4] {
+        color.to_srgba().to_u8_array()
+    }
+
+    #[test]
+    fn parses_every_syntax() {
+        let jungle = [0x1a, 0x24, 0x21, 0xff];
+        for input in [
+            "#1a2421",
+            "#1A2421FF",
+            "rgb(26 36 33)",
+            "rgb(26, 36, 33)",
+            "RGBA(26,36,33,1)",
+            "rgb(10.2% 14.12% 12.94%)",
+            "color(srgb 0.10196 0.14118 0.12941)",
+            "hsl(162 16.13% 12.16%)",
+            "hsl(162deg, 16.13%, 12.16%)",
+            "hwb(0.45turn 10.2% 85.88%)",
+        ] {
+            assert_eq!(srgba(parse(input).unwrap()), jungle, "{input}");
+        }
+
+        assert_eq!(srgba(parse("  Tomato ").unwrap()), [0xff, 0x63, 0x47, 0xff]);
+        assert_eq!(srgba(parse("transparent").unwrap())[3], 0);
+        assert_eq!(srgba(parse("#f008").unwrap()), [0xff, 0, 0, 0x88]);
+        assert_eq!(srgba(parse("rgb(255 0 0 / 50%)").unwrap())[3], 0x80);
+        assert_eq!(
+            srgba(parse("rgb(300 -20 none / 0.25)").unwrap()),
+            [0xff, 0, 0, 0x40]
+        );
+
+        // reference conversions from the CSS specification
+        assert_eq!(
+            srgba(parse("lab(29.2345% 39.3825 20.0664)").unwrap()),
+            [0x7d, 0x23, 0x29, 0xff]
+        );
+        assert_eq!(
+            srgba(parse("lch(29.2345% 44.2 27)").unwrap()),
+            [0x7d, 0x23, 0x29, 0xff]
+        );
+        assert_eq!(
+            srgba(parse("oklch(62.8% 0.2577 29.23)").unwrap()),
+            [0xff, 0, 0, 0xff]
+        );
+        assert_eq!(srgba(parse("color(display-p3 1 1 1)").unwrap()), [0xff; 4]);
+        // outside of sRGB, kept exactly until converted
+        assert!(matches!(
+            parse("color(display-p3 1 0 0)"),
+            Ok(Color::Oklab(_))
+        ));
+    }
+
+    #[test]
+    fn parses_wide_gamut_spaces() {
+        for space in ["a98-rgb", "prophoto-rgb", "rec2020"] {
+            let white = format!("color({space} 1 1 1)");
+            assert_eq!(srgba(parse(&white).unwrap()), [0xff; 4], "{white}");
+        }
+        // converted with the matrices and transfer functions of the CSS specification
+        let within_srgb = [
+            ("color(a98-rgb 0.5 0.4 0.3)", [137, 102, 74, 0xff]),
+            ("color(prophoto-rgb 0.6 0.5 0.4)", [195, 140, 116, 0xff]),
+            ("color(rec2020 0.5 0.4 0.3)", [154, 112, 87, 0xff]),
+        ];
+        for (input, expected) in within_srgb {
+            assert_eq!(srgba(parse(input).unwrap()), expected, "{input}");
+        }
+
+        let Ok(Color::Oklab(red)) = parse("color(rec2020 1 0 0)") else {
+            panic!("rec2020 red is outside of sRGB");
+        };
+        let expected = Oklab::from_linear_srgb([1.660_5, -0.124_6, -0.018_2]);
+        assert!(red.distance(expected) < 1e-3, "{red:?}");
+    }
+
+    #[test]
+    fn reports_errors() {
+        let invalid = [
+            ("", ParseColorError::Empty),
+            ("#12345", ParseColorError::InvalidHex),
+            ("reddish", ParseColorError::UnknownName),
+            ("currentColor", ParseColorError::ContextDependent),
+            ("ButtonText", ParseColorError::ContextDependent),
+            ("canvas", ParseColorError::ContextDependent),
+            ("rgbx(1 2 3)", ParseColorError::UnknownFunction),
+            ("rgb(1 2 3", ParseColorError::InvalidSyntax),
+            ("rgb(1, 2 3)", ParseColorError::InvalidSyntax),
+            ("hwb(1, 2%, 3%)", ParseColorError::InvalidSyntax),
+            (
+                "rgb(1 2)",
+                ParseColorError::ArgumentCount {
+                    expected: 3,
+                    found: 2,
+                },
+            ),
+            (
+                "rgb(1 2 3 4)",
+                ParseColorError::ArgumentCount {
+                    expected: 3,
+                    found: 4,
+                },
+            ),
+            (
+                "rgb(1, 2%, 3)",
+                ParseColorError::InvalidArgument { index: 1 },
+            ),
+            (
+                "rgb(1, none, 3)",
+                ParseColorError::InvalidArgument { index: 1 },
+            ),
+            (
+                "hsl(10, 20, 30%)",
+                ParseColorError::InvalidArgument { index: 1 },
+            ),
+            (
+                "hsl(10% 20% 30%)",
+                ParseColorError::InvalidArgument { index: 0 },
+            ),
+            (
+                "rgb(1 2 3 / x)",
+                ParseColorError::InvalidArgument { index: 3 },
+            ),
+            ("color(rec2100 1 0 0)", ParseColorError::UnknownColorSpace),
+        ];
+        for (input, error) in invalid {
+            assert_eq!(parse(input), Err(error), "{input}");
+        }
+    }
+
+    #[test]
+    fn displays_parsable_css() {
+        let colors = [
+            Color::Srgba(Srgba::from_hex("#1a242180").unwrap()),
+            Color::Hsl(Hsl::new(200.0, 0.25, 0.5).unwrap()),
+            Color::Hwb(Hwb::new(20.0, 0.1, 0.3).unwrap()),
+            Color::Oklch(Oklch::new(0.5, 0.1, 250.0).unwrap()),
+            Color::Oklab(Oklab::new(0.5, -0.1, 0.05).unwrap()),
+            Color::Rgb32(Rgb32::new(0.25, 0.5, 1.0).unwrap()),
+            Color::Rgba32(Rgba32::new(0.25, 0.5, 1.0, 0.5).unwrap()),
+        ];
+        for color in colors {
+            let parsed = parse(&color.to_string()).unwrap();
+            assert_eq!(srgba(parsed), srgba(color), "{color}");
+            assert_eq!(discriminant(&parsed), discriminant(&color), "{color}");
+        }
+        assert_eq!(
+            Color::Hsl(Hsl::new(200.0, 0.25, 0.5).unwrap()).to_string(),
+            "hsl(200 25% 50%)"
+        );
+    }
+}
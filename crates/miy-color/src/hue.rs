@@ -0,0 +1,313 @@
+//! Hue based color models for pickers and hue shifts.
+//!
+//! [`Hsl`], [`Hsv`] and [`Hwb`] are different coordinates for gamma encoded [`Srgb`] colors,
+//! as in CSS. Their channels are [`srgb::Channel`]s, the hue is a [`Hue`] in degrees.
+
+use miy_math::Interpolate;
+
+use crate::Srgb;
+use crate::srgb::{self, Channel};
+
+/// Hue angle in degrees, always within `0..360`
+#[derive(Debug, PartialEq, PartialOrd, Copy, Clone)]
+pub struct Hue {
+    degrees: f32,
+}
+
+/// Hue, saturation and lightness
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hsl {
+    pub h: Hue,
+    pub s: Channel,
+    pub l: Channel,
+}
+
+/// Hue, saturation and value
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hsv {
+    pub h: Hue,
+    pub s: Channel,
+    pub v: Channel,
+}
+
+/// Hue, whiteness and blackness, a whiteness and blackness adding up to more than one is gray
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hwb {
+    pub h: Hue,
+    pub w: Channel,
+    pub b: Channel,
+}
+
+impl Hue {
+    pub const ZERO: Self = Self { degrees: 0.0 };
+
+    /// Wraps `degrees` into `0..360`, returns `None` if it is not finite
+    pub fn new(degrees: f32) -> Option<Self> {
+        if !degrees.is_finite() {
+            return None;
+        }
+
+        // rem_euclid can round up to exactly 360 for tiny negative values
+        let degrees = degrees.rem_euclid(360.0);
+        Some(Self {
+            degrees: if degrees < 360.0 { degrees } else { 0.0 },
+        })
+    }
+
+    pub const fn get(&self) -> f32 {
+        self.degrees
+    }
+
+    /// Rotates the hue by `degrees`, wrapping around
+    pub fn shift(self, degrees: f32) -> Self {
+        Self::new(self.degrees + degrees).unwrap_or(self)
+    }
+
+    /// Signed difference to `other` along the shorter way around, within `-180..=180`
+    pub fn difference(self, other: Self) -> f32 {
+        let difference = (other.degrees - self.degrees).rem_euclid(360.0);
+        if difference > 180.0 {
+            difference - 360.0
+        } else {
+            difference
+        }
+    }
+}
+
+impl Hsl {
+    /// Returns `None` if `h` is not finite or a channel is outside `0..=1`
+    pub fn new(h: f32, s: f32, l: f32) -> Option<Self> {
+        Some(Self {
+            h: Hue::new(h)?,
+            s: Channel::new(s)?,
+            l: Channel::new(l)?,
+        })
+    }
+}
+
+impl Hsv {
+    /// Returns `None` if `h` is not finite or a channel is outside `0..=1`
+    pub fn new(h: f32, s: f32, v: f32) -> Option<Self> {
+        Some(Self {
+            h: Hue::new(h)?,
+            s: Channel::new(s)?,
+            v: Channel::new(v)?,
+        })
+    }
+}
+
+impl Hwb {
+    /// Returns `None` if `h` is not finite or a channel is outside `0..=1`
+    pub fn new(h: f32, w: f32, b: f32) -> Option<Self> {
+        Some(Self {
+            h: Hue::new(h)?,
+            w: Channel::new(w)?,
+            b: Channel::new(b)?,
+        })
+    }
+}
+
+/// Hue of a color and its largest and smallest channel, gray has a hue of zero
+fn hue_max_min(Srgb { r, g, b }: Srgb) -> (Hue, f32, f32) {
+    let (r, g, b) = (r.get(), g.get(), b.get());
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    let sector = if delta <= 0.0 {
+        0.0
+    } else if max == r {
+        (g - b) / delta
+    } else if max == g {
+        (b - r) / delta + 2.0
+    } else {
+        (r - g) / delta + 4.0
+    };
+    (Hue::new(sector * 60.0).unwrap_or(Hue::ZERO), max, min)
+}
+
+/// Fully saturated color channel `n` of the CSS hsv conversion, scaled by saturation and value
+fn hsv_channel(h: Hue, s: f32, v: f32, n: f32) -> Channel {
+    let k = (n + h.get() / 60.0) % 6.0;
+    Channel::clamped(v - v * s * k.min(4.0 - k).clamp(0.0, 1.0))
+}
+
+impl From<Hsv> for Srgb {
+    fn from(Hsv { h, s, v }: Hsv) -> Self {
+        let (s, v) = (s.get(), v.get());
+        Self {
+            r: hsv_channel(h, s, v, 5.0),
+            g: hsv_channel(h, s, v, 3.0),
+            b: hsv_channel(h, s, v, 1.0),
+        }
+    }
+}
+
+impl From<Srgb> for Hsv {
+    fn from(srgb: Srgb) -> Self {
+        let (h, max, min) = hue_max_min(srgb);
+        let s = if max > 0.0 { (max - min) / max } else { 0.0 };
+        Self {
+            h,
+            s: Channel::clamped(s),
+            v: Channel::clamped(max),
+        }
+    }
+}
+
+impl From<Hsl> for Srgb {
+    fn from(Hsl { h, s, l }: Hsl) -> Self {
+        let (s, l) = (s.get(), l.get());
+        let v = l + s * l.min(1.0 - l);
+        let s = if v > 0.0 { 2.0 * (1.0 - l / v) } else { 0.0 };
+        Hsv {
+            h,
+            s: Channel::clamped(s),
+            v: Channel::clamped(v),
+        }
+        .into()
+    }
+}
+
+impl From<Srgb> for Hsl {
+    fn from(srgb: Srgb) -> Self {
+        let (h, max, min) = hue_max_min(srgb);
+        let l = (max + min) / 2.0;
+        let s = if l > 0.0 && l < 1.0 {
+            (max - l) / l.min(1.0 - l)
+        } else {
+            0.0
+        };
+        Self {
+            h,
+            s: Channel::clamped(s),
+            l: Channel::clamped(l),
+        }
+    }
+}
+
+impl From<Hwb> for Srgb {
+    fn from(Hwb { h, w, b }: Hwb) -> Self {
+        let (w, b) = (w.get(), b.get());
+        if w + b >= 1.0 {
+            let gray = Channel::clamped(w / (w + b));
+            return Self {
+                r: gray,
+                g: gray,
+                b: gray,
+            };
+        }
+
+        let v = 1.0 - b;
+        Hsv {
+            h,
+            s: Channel::clamped(1.0 - w / v),
+            v: Channel::clamped(v),
+        }
+        .into()
+    }
+}
+
+impl From<Srgb> for Hwb {
+    fn from(srgb: Srgb) -> Self {
+        let (h, max, min) = hue_max_min(srgb);
+        Self {
+            h,
+            w: Channel::clamped(min),
+            b: Channel::clamped(1.0 - max),
+        }
+    }
+}
+
+/// Goes the shorter way around the color wheel
+impl Interpolate for Hue {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self.shift(self.difference(*other) * t)
+    }
+}
+
+impl Interpolate for srgb::Channel {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self::clamped(self.get().interpolate(&other.get(), t))
+    }
+}
+
+impl Interpolate for Hsl {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self {
+            h: self.h.interpolate(&other.h, t),
+            s: self.s.interpolate(&other.s, t),
+            l: self.l.interpolate(&other.l, t),
+        }
+    }
+}
+
+impl Interpolate for Hsv {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self {
+            h: self.h.interpolate(&other.h, t),
+            s: self.s.interpolate(&other.s, t),
+            v: self.v.interpolate(&other.v, t),
+        }
+    }
+}
+
+impl Interpolate for Hwb {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self {
+            h: self.h.interpolate(&other.h, t),
+            w: self.w.interpolate(&other.w, t),
+            b: self.b.interpolate(&other.b, t),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn close(a: Srgb, b: Srgb) -> bool {
+        a.to_array()
+            .iter()
+            .zip(b.to_array())
+            .all(|(a, b)| (a - b).abs() < 1e-5)
+    }
+
+    #[test]
+    fn matches_css_references() {
+        let orange = Srgb::new(1.0, 0.5, 0.0).unwrap();
+        assert_eq!(Hsl::from(orange), Hsl::new(30.0, 1.0, 0.5).unwrap());
+        assert_eq!(Hsv::from(orange), Hsv::new(30.0, 1.0, 1.0).unwrap());
+        assert_eq!(Hwb::from(orange), Hwb::new(30.0, 0.0, 0.0).unwrap());
+
+        let teal = Hsl::new(180.0, 0.5, 0.25).unwrap();
+        assert!(close(teal.into(), Srgb::new(0.125, 0.375, 0.375).unwrap()));
+
+        // whiteness and blackness adding up to more than one normalize to gray
+        let gray = Hwb::new(90.0, 0.6, 0.6).unwrap();
+        assert!(close(gray.into(), Srgb::new(0.5, 0.5, 0.5).unwrap()));
+
+        for r in 0..=4 {
+            for g in 0..=4 {
+                for b in 0..=4 {
+                    let srgb = Srgb::new(r as f32 / 4.0, g as f32 / 4.0, b as f32 / 4.0).unwrap();
+                    assert!(close(Hsl::from(srgb).into(), srgb), "{srgb:?} through hsl");
+                    assert!(close(Hsv::from(srgb).into(), srgb), "{srgb:?} through hsv");
+                    assert!(close(Hwb::from(srgb).into(), srgb), "{srgb:?} through hwb");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hue_wraps_the_short_way() {
+        assert_eq!(Hue::new(-30.0).unwrap().get(), 330.0);
+        assert_eq!(Hue::new(f32::NAN), None);
+        let (red, violet) = (Hue::new(10.0).unwrap(), Hue::new(330.0).unwrap());
+        assert_eq!(red.difference(violet), -40.0);
+        assert_eq!(red.interpolate(&violet, 0.5).get(), 350.0);
+    }
+}
@@ -1,16 +1,34 @@
+pub mod css;
+pub mod hue;
+pub mod oklab;
+pub mod packed;
 pub mod palette;
 pub mod rgb;
 pub mod srgb;
 
+#[cfg(feature = "serde")]
+mod serialization;
+
+pub use css::ParseColorError;
+pub use hue::{Hsl, Hsv, Hue, Hwb};
+pub use oklab::{Oklab, Oklch};
+pub use packed::{Rgba8, Srgba8};
+pub use palette::{Palette, ParsePaletteError};
 pub use rgb::{Rgb32, Rgba32};
 pub use srgb::{Srgb, Srgba};
 
-#[derive(Copy, Clone)]
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Color {
     Rgba32(Rgba32),
     Rgb32(Rgb32),
     Srgba(Srgba),
     Srgb(Srgb),
+    Hsl(Hsl),
+    Hsv(Hsv),
+    Hwb(Hwb),
+    Oklab(Oklab),
+    Oklch(Oklch),
 }
 
 impl Color {
@@ -21,13 +39,13 @@ impl Color {
     pub const BLUE: Self = Self::Rgb32(Rgb32::new(0.0, 0.0, 1.0).unwrap());
 
     // /// Almost black with a touch of green
-    pub const DARK_JUNGLE_GREEN: Self = from_srgb(0.102, 0.141, 0.129);
+    pub const DARK_JUNGLE_GREEN: Self = Self::Srgb(Srgb::from_hex("#1a2421").unwrap());
 
     // /// Grape like purplee
-    pub const PERSIAN_INDIGO: Self = from_srgb(0.20, 0.07, 0.47);
+    pub const PERSIAN_INDIGO: Self = Self::Srgb(Srgb::from_hex("#331278").unwrap());
 
     // /// Dirty Whitee
-    pub const GAINSBORO: Self = from_srgb(0.79, 0.92, 0.87);
+    pub const GAINSBORO: Self = Self::Srgb(Srgb::from_hex("#c9ebde").unwrap());
 
     // /// It's really nice to look at
     pub const UNITY_YELLOW: Self = Self::Rgb32(Rgb32::new(1.0, 0.92, 0.016).unwrap());
@@ -38,6 +56,11 @@ impl Color {
             Self::Rgb32(rgb) => rgb.into(),
             Self::Srgba(srgba) => srgba.into(),
             Self::Srgb(srgb) => srgb.into(),
+            Self::Hsl(hsl) => Srgb::from(hsl).into(),
+            Self::Hsv(hsv) => Srgb::from(hsv).into(),
+            Self::Hwb(hwb) => Srgb::from(hwb).into(),
+            Self::Oklab(oklab) => Rgb32::from(oklab).into(),
+            Self::Oklch(oklch) => Rgb32::from(oklch).into(),
         }
     }
 
@@ -47,6 +70,11 @@ impl Color {
             Color::Rgb32(rgb32) => rgb32.into(),
             Color::Srgba(srgba) => srgba,
             Color::Srgb(srgb) => srgb.into(),
+            Color::Hsl(hsl) => Srgb::from(hsl).into(),
+            Color::Hsv(hsv) => Srgb::from(hsv).into(),
+            Color::Hwb(hwb) => Srgb::from(hwb).into(),
+            Color::Oklab(oklab) => Rgb32::from(oklab).into(),
+            Color::Oklch(oklch) => Rgb32::from(oklch).into(),
         }
     }
 
@@ -56,6 +84,59 @@ impl Color {
             Self::Rgb32(rgb) => rgb,
             Self::Srgba(srgba) => srgba.into(),
             Self::Srgb(srgb) => srgb.into(),
+            Self::Hsl(hsl) => Srgb::from(hsl).into(),
+            Self::Hsv(hsv) => Srgb::from(hsv).into(),
+            Self::Hwb(hwb) => Srgb::from(hwb).into(),
+            Self::Oklab(oklab) => oklab.into(),
+            Self::Oklch(oklch) => oklch.into(),
+        }
+    }
+
+    /// The color as gamma encoded sRGB, alpha is dropped
+    pub fn to_srgb(self) -> Srgb {
+        match self {
+            Self::Srgb(srgb) => srgb,
+            Self::Hsl(hsl) => hsl.into(),
+            Self::Hsv(hsv) => hsv.into(),
+            Self::Hwb(hwb) => hwb.into(),
+            _ => self.to_srgba().into(),
+        }
+    }
+
+    pub fn to_hsl(self) -> Hsl {
+        match self {
+            Self::Hsl(hsl) => hsl,
+            _ => self.to_srgb().into(),
+        }
+    }
+
+    pub fn to_hsv(self) -> Hsv {
+        match self {
+            Self::Hsv(hsv) => hsv,
+            _ => self.to_srgb().into(),
+        }
+    }
+
+    pub fn to_hwb(self) -> Hwb {
+        match self {
+            Self::Hwb(hwb) => hwb,
+            _ => self.to_srgb().into(),
+        }
+    }
+
+    pub fn to_oklab(self) -> Oklab {
+        match self {
+            Self::Oklab(oklab) => oklab,
+            Self::Oklch(oklch) => oklch.into(),
+            _ => self.to_rgb32().into(),
+        }
+    }
+
+    pub fn to_oklch(self) -> Oklch {
+        match self {
+            Self::Oklch(oklch) => oklch,
+            Self::Oklab(oklab) => oklab.into(),
+            _ => self.to_rgb32().into(),
         }
     }
 }
@@ -84,6 +165,32 @@ impl From<Srgba> for Color {
     }
 }
 
-const fn from_srgb(r: f32, g: f32, b: f32) -> Color {
-    Color::Srgb(Srgb::new(r, g, b).unwrap())
+impl From<Hsl> for Color {
+    fn from(value: Hsl) -> Self {
+        Self::Hsl(value)
+    }
+}
+
+impl From<Hsv> for Color {
+    fn from(value: Hsv) -> Self {
+        Self::Hsv(value)
+    }
+}
+
+impl From<Hwb> for Color {
+    fn from(value: Hwb) -> Self {
+        Self::Hwb(value)
+    }
+}
+
+impl From<Oklab> for Color {
+    fn from(value: Oklab) -> Self {
+        Self::Oklab(value)
+    }
+}
+
+impl From<Oklch> for Color {
+    fn from(value: Oklch) -> Self {
+        Self::Oklch(value)
+    }
 }
@@ -0,0 +1,357 @@
+//! Perceptual color spaces for gradients and lightness or chroma tweaks.
+//!
+//! [`Oklab`] is a perceptually uniform space by Björn Ottosson, equal steps look like equal
+//! changes, so blends avoid the muddy midpoints of mixing [`Rgb32`]. [`Oklch`] is the same space
+//! in polar coordinates. See <https://bottosson.github.io/posts/oklab/>.
+//!
+//! Both cover more than sRGB, converting back maps colors outside of it into range by reducing
+//! chroma while keeping lightness and hue, as CSS does.
+
+use miy_math::Interpolate;
+
+use crate::rgb::Channel;
+use crate::{Hue, Rgb32};
+
+/// Lightness `l` within `0..=1` and the green-red `a` and blue-yellow `b` axes
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Oklab {
+    l: f32,
+    a: f32,
+    b: f32,
+}
+
+/// Lightness `l` within `0..=1`, chroma `c` and hue `h` of [`Oklab`]
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Oklch {
+    l: f32,
+    c: f32,
+    h: Hue,
+}
+
+impl Oklab {
+    /// Returns `None` unless `l` is within `0..=1` and `a` and `b` are finite
+    pub fn new(l: f32, a: f32, b: f32) -> Option<Self> {
+        ((0.0..=1.0).contains(&l) && a.is_finite() && b.is_finite()).then_some(Self { l, a, b })
+    }
+
+    pub fn l(&self) -> f32 {
+        self.l
+    }
+
+    pub fn a(&self) -> f32 {
+        self.a
+    }
+
+    pub fn b(&self) -> f32 {
+        self.b
+    }
+
+    /// Adds `amount` to the lightness, clamped to `0..=1`
+    pub fn lighten(self, amount: f32) -> Self {
+        Self {
+            l: clamp_lightness(self.l + amount, self.l),
+            ..self
+        }
+    }
+
+    /// Subtracts `amount` from the lightness, clamped to `0..=1`
+    pub fn darken(self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Perceptual blend, same as [`Interpolate::interpolate`]
+    pub fn mix(self, other: Self, t: f32) -> Self {
+        self.interpolate(&other, t)
+    }
+
+    /// Euclidean distance, a difference of about 0.02 is just noticeable
+    pub fn distance(self, other: Self) -> f32 {
+        let (l, a, b) = (self.l - other.l, self.a - other.a, self.b - other.b);
+        (l * l + a * a + b * b).sqrt()
+    }
+
+    /// Linear sRGB, possibly outside `0..=1`
+    fn to_linear(self) -> [f32; 3] {
+        let l = (self.l + 0.396_337_78 * self.a + 0.215_803_76 * self.b).powi(3);
+        let m = (self.l - 0.105_561_346 * self.a - 0.063_854_17 * self.b).powi(3);
+        let s = (self.l - 0.089_484_18 * self.a - 1.291_485_5 * self.b).powi(3);
+        [
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+        ]
+    }
+
+    /// Linear sRGB that may be outside `0..=1`, for colors from wider color spaces
+    pub(crate) fn from_linear_srgb([r, g, b]: [f32; 3]) -> Self {
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+        Self {
+            // white lands a hair above one
+            l: (0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s).clamp(0.0, 1.0),
+            a: 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            b: 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        }
+    }
+
+    fn in_gamut(self) -> bool {
+        const EPSILON: f32 = 1e-4;
+        self.to_linear()
+            .iter()
+            .all(|channel| (-EPSILON..=1.0 + EPSILON).contains(channel))
+    }
+
+    /// Converts to [`Rgb32`], reducing chroma until the color fits
+    pub fn to_rgb32(self) -> Rgb32 {
+        Oklch::from(self).to_rgb32()
+    }
+}
+
+impl Oklch {
+    /// Returns `None` unless `l` is within `0..=1`, `c` is finite and not negative and `h` is finite
+    pub fn new(l: f32, c: f32, h: f32) -> Option<Self> {
+        let valid = (0.0..=1.0).contains(&l) && c.is_finite() && c >= 0.0;
+        Some(Self {
+            l,
+            c,
+            h: Hue::new(h)?,
+        })
+        .filter(|_| valid)
+    }
+
+    pub fn l(&self) -> f32 {
+        self.l
+    }
+
+    pub fn c(&self) -> f32 {
+        self.c
+    }
+
+    pub fn h(&self) -> Hue {
+        self.h
+    }
+
+    /// Adds `amount` to the lightness, clamped to `0..=1`
+    pub fn lighten(self, amount: f32) -> Self {
+        Self {
+            l: clamp_lightness(self.l + amount, self.l),
+            ..self
+        }
+    }
+
+    /// Subtracts `amount` from the lightness, clamped to `0..=1`
+    pub fn darken(self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Replaces the chroma, negative or non-finite values keep the current one
+    pub fn with_chroma(self, c: f32) -> Self {
+        match c.is_finite() && c >= 0.0 {
+            true => Self { c, ..self },
+            false => self,
+        }
+    }
+
+    /// Multiplies the chroma by `factor`, zero gives the gray of the same lightness
+    pub fn scale_chroma(self, factor: f32) -> Self {
+        self.with_chroma(self.c * factor)
+    }
+
+    /// Rotates the hue by `degrees`
+    pub fn shift_hue(self, degrees: f32) -> Self {
+        Self {
+            h: self.h.shift(degrees),
+            ..self
+        }
+    }
+
+    /// Perceptual blend going the shorter way around the hue circle
+    pub fn mix(self, other: Self, t: f32) -> Self {
+        self.interpolate(&other, t)
+    }
+
+    /// Converts to [`Rgb32`], reducing chroma until the color fits
+    pub fn to_rgb32(self) -> Rgb32 {
+        if self.l >= 1.0 {
+            return gray(Channel::ONE);
+        }
+        if self.l <= 0.0 {
+            return gray(Channel::ZERO);
+        }
+
+        let lab = Oklab::from(self);
+        if lab.in_gamut() {
+            return clip(lab);
+        }
+
+        // binary search for the largest chroma that still fits
+        let (mut low, mut high) = (0.0, self.c);
+        for _ in 0..24 {
+            let chroma = (low + high) / 2.0;
+            match Oklab::from(self.with_chroma(chroma)).in_gamut() {
+                true => low = chroma,
+                false => high = chroma,
+            }
+        }
+        clip(self.with_chroma(low).into())
+    }
+}
+
+fn clamp_lightness(l: f32, fallback: f32) -> f32 {
+    if l.is_nan() {
+        fallback
+    } else {
+        l.clamp(0.0, 1.0)
+    }
+}
+
+fn gray(value: Channel) -> Rgb32 {
+    Rgb32 {
+        r: value,
+        g: value,
+        b: value,
+    }
+}
+
+fn clip(lab: Oklab) -> Rgb32 {
+    let [r, g, b] = lab.to_linear();
+    Rgb32 {
+        r: Channel::clamped(r),
+        g: Channel::clamped(g),
+        b: Channel::clamped(b),
+    }
+}
+
+impl From<Rgb32> for Oklab {
+    fn from(rgb: Rgb32) -> Self {
+        Self::from_linear_srgb(rgb.to_array())
+    }
+}
+
+impl From<Oklab> for Rgb32 {
+    fn from(lab: Oklab) -> Self {
+        lab.to_rgb32()
+    }
+}
+
+impl From<Oklch> for Rgb32 {
+    fn from(lch: Oklch) -> Self {
+        lch.to_rgb32()
+    }
+}
+
+impl From<Rgb32> for Oklch {
+    fn from(rgb: Rgb32) -> Self {
+        Oklab::from(rgb).into()
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(Oklab { l, a, b }: Oklab) -> Self {
+        let c = (a * a + b * b).sqrt();
+        // grays have no meaningful hue, rounding noise would otherwise pick a random one
+        let h = match c > 1e-6 {
+            true => Hue::new(b.atan2(a).to_degrees()).unwrap_or(Hue::ZERO),
+            false => Hue::ZERO,
+        };
+        Self { l, c, h }
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(Oklch { l, c, h }: Oklch) -> Self {
+        let (sin, cos) = h.get().to_radians().sin_cos();
+        Self {
+            l,
+            a: c * cos,
+            b: c * sin,
+        }
+    }
+}
+
+impl Interpolate for Oklab {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self {
+            l: clamp_lightness(self.l.interpolate(&other.l, t), self.l),
+            a: self.a.interpolate(&other.a, t),
+            b: self.b.interpolate(&other.b, t),
+        }
+    }
+}
+
+/// Grays take the hue of the other color, so fading from gray doesn't sweep through other hues
+impl Interpolate for Oklch {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        let (from, to) = match (self.c > 1e-6, other.c > 1e-6) {
+            (false, true) => (other.h, other.h),
+            (true, false) => (self.h, self.h),
+            _ => (self.h, other.h),
+        };
+        Self {
+            l: clamp_lightness(self.l.interpolate(&other.l, t), self.l),
+            c: self.c.interpolate(&other.c, t).max(0.0),
+            h: from.interpolate(&to, t),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn matches_reference_values() {
+        let white = Oklab::from(Rgb32::new(1.0, 1.0, 1.0).unwrap());
+        assert!((white.l() - 1.0).abs() < 1e-5 && white.a().abs() < 1e-4);
+
+        // reference values from the Oklab post
+        let red = Oklab::from(Rgb32::new(1.0, 0.0, 0.0).unwrap());
+        assert!(red.distance(Oklab::new(0.627_955, 0.224_863, 0.125_846).unwrap()) < 1e-4);
+        let blue = Oklch::from(Rgb32::new(0.0, 0.0, 1.0).unwrap());
+        assert!((blue.l() - 0.452_014).abs() < 1e-4);
+        assert!((blue.h().get() - 264.052).abs() < 1e-2);
+
+        for rgb in [
+            [0.2, 0.5, 0.9],
+            [1.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0],
+            [0.4, 0.4, 0.4],
+        ] {
+            let [r, g, b] = rgb;
+            let back = Oklch::from(Rgb32::new(r, g, b).unwrap())
+                .to_rgb32()
+                .to_array();
+            assert!(back.iter().zip(rgb).all(|(a, b)| (a - b).abs() < 1e-4));
+        }
+    }
+
+    #[test]
+    fn maps_out_of_gamut_colors() {
+        // far more chroma than any sRGB color has
+        let vivid = Oklch::new(0.7, 0.4, 150.0).unwrap();
+        let mapped = Oklch::from(vivid.to_rgb32());
+        assert!((mapped.l() - 0.7).abs() < 0.01);
+        assert!(mapped.h().difference(vivid.h()).abs() < 1.0);
+        assert!(mapped.c() < 0.4);
+
+        assert_eq!(
+            Oklch::new(1.0, 0.3, 20.0).unwrap().to_rgb32(),
+            Rgb32::new(1.0, 1.0, 1.0).unwrap()
+        );
+    }
+
+    #[test]
+    fn mixes_perceptually() {
+        let blue = Oklab::from(Rgb32::new(0.0, 0.0, 1.0).unwrap());
+        let white = Oklab::from(Rgb32::new(1.0, 1.0, 1.0).unwrap());
+        let middle = blue.mix(white, 0.5);
+        assert!((middle.l() - (blue.l() + white.l()) / 2.0).abs() < 1e-6);
+
+        let gray = Oklch::new(0.5, 0.0, 0.0).unwrap();
+        let green = Oklch::new(0.5, 0.1, 140.0).unwrap();
+        assert_eq!(gray.mix(green, 0.5).h(), green.h());
+        assert_eq!(green.lighten(0.8).l(), 1.0);
+        assert_eq!(green.darken(0.2).scale_chroma(0.5).c(), 0.05);
+    }
+}
@@ -0,0 +1,183 @@
+//! Four byte colors for vertex attributes and texture pixels.
+//!
+//! [`Rgba8`] stores linear channels and [`Srgba8`] gamma encoded ones, both are `#[repr(C)]` with
+//! the bytes in `r, g, b, a` order, so a slice of them uploads as `InternalFormat::RGBA8` with
+//! `TextureFormat::RGBA` and `TextureDataFormat::U8`. Prefer [`Srgba8`] for anything a person
+//! looks at, 8 linear bits band visibly in dark gradients.
+//!
+//! The `u32` packings name the channels from the most to the least significant byte, they match
+//! the `U32_8_8_8_8` data formats independent of the platform's byte order:
+//!
+//! | packing | value        | upload as                                 |
+//! |---------|--------------|-------------------------------------------|
+//! | RGBA    | `0xRRGGBBAA` | `TextureFormat::RGBA`, `U32_8_8_8_8`      |
+//! | ABGR    | `0xAABBGGRR` | `TextureFormat::RGBA`, `U32_8_8_8_8_REV`  |
+//! | BGRA    | `0xBBGGRRAA` | `TextureFormat::BGRA`, `U32_8_8_8_8`      |
+
+use crate::{Rgba32, Srgba, rgb, srgb};
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rgba8 {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Srgba8 {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+macro_rules! packed {
+    ($($name:ident),+) => {
+        $(
+            impl $name {
+                pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+                    Self { r, g, b, a }
+                }
+
+                pub const fn from_array([r, g, b, a]: [u8; 4]) -> Self {
+                    Self { r, g, b, a }
+                }
+
+                pub const fn to_array(self) -> [u8; 4] {
+                    [self.r, self.g, self.b, self.a]
+                }
+
+                /// `0xRRGGBBAA`
+                pub const fn from_rgba_u32(rgba: u32) -> Self {
+                    Self::from_array(rgba.to_be_bytes())
+                }
+
+                /// `0xRRGGBBAA`
+                pub const fn to_rgba_u32(self) -> u32 {
+                    u32::from_be_bytes(self.to_array())
+                }
+
+                /// `0xAABBGGRR`, the bytes of `self` read as a little endian `u32`
+                pub const fn from_abgr_u32(abgr: u32) -> Self {
+                    Self::from_array(abgr.to_le_bytes())
+                }
+
+                /// `0xAABBGGRR`, the bytes of `self` read as a little endian `u32`
+                pub const fn to_abgr_u32(self) -> u32 {
+                    u32::from_le_bytes(self.to_array())
+                }
+
+                /// `0xBBGGRRAA`
+                pub const fn from_bgra_u32(bgra: u32) -> Self {
+                    let [b, g, r, a] = bgra.to_be_bytes();
+                    Self { r, g, b, a }
+                }
+
+                /// `0xBBGGRRAA`
+                pub const fn to_bgra_u32(self) -> u32 {
+                    let Self { r, g, b, a } = self;
+                    u32::from_be_bytes([b, g, r, a])
+                }
+            }
+        )+
+    };
+}
+
+packed!(Rgba8, Srgba8);
+
+impl From<Rgba32> for Rgba8 {
+    fn from(Rgba32 { r, g, b, a }: Rgba32) -> Self {
+        Self::new(r.to_u8(), g.to_u8(), b.to_u8(), a.to_u8())
+    }
+}
+
+impl From<Rgba8> for Rgba32 {
+    fn from(Rgba8 { r, g, b, a }: Rgba8) -> Self {
+        Self {
+            r: rgb::Channel::from_u8(r),
+            g: rgb::Channel::from_u8(g),
+            b: rgb::Channel::from_u8(b),
+            a: rgb::Channel::from_u8(a),
+        }
+    }
+}
+
+impl From<Srgba> for Srgba8 {
+    fn from(srgba: Srgba) -> Self {
+        Self::from_array(srgba.to_u8_array())
+    }
+}
+
+impl From<Srgba8> for Srgba {
+    fn from(Srgba8 { r, g, b, a }: Srgba8) -> Self {
+        Self {
+            r: srgb::Channel::from_u8(r),
+            g: srgb::Channel::from_u8(g),
+            b: srgb::Channel::from_u8(b),
+            a: srgb::Channel::from_u8(a),
+        }
+    }
+}
+
+/// Encodes before quantizing, which keeps dark linear colors apart
+impl From<Rgba32> for Srgba8 {
+    fn from(rgba: Rgba32) -> Self {
+        Srgba::from(rgba).into()
+    }
+}
+
+impl From<Srgba8> for Rgba32 {
+    fn from(srgba: Srgba8) -> Self {
+        Srgba::from(srgba).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn layout_matches_rgba8_uploads() {
+        let pixels = [Srgba8::new(1, 2, 3, 4), Srgba8::new(5, 6, 7, 8)];
+        assert_eq!(size_of::<Srgba8>(), 4);
+        assert_eq!(align_of::<Rgba8>(), 1);
+        // SAFETY: `Srgba8` is four `u8` in `repr(C)`, without padding
+        let bytes: &[u8; 8] = unsafe { &*pixels.as_ptr().cast() };
+        assert_eq!(bytes, &[1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(
+            pixels.map(Srgba8::to_abgr_u32),
+            pixels.map(|pixel| u32::from_le_bytes(pixel.to_array()))
+        );
+    }
+
+    #[test]
+    fn packs_in_every_order() {
+        let color = Rgba8::new(0x11, 0x22, 0x33, 0x44);
+        assert_eq!(color.to_rgba_u32(), 0x1122_3344);
+        assert_eq!(color.to_abgr_u32(), 0x4433_2211);
+        assert_eq!(color.to_bgra_u32(), 0x3322_1144);
+        assert_eq!(Rgba8::from_rgba_u32(0x1122_3344), color);
+        assert_eq!(Rgba8::from_abgr_u32(0x4433_2211), color);
+        assert_eq!(Rgba8::from_bgra_u32(0x3322_1144), color);
+    }
+
+    #[test]
+    fn rounds_to_nearest() {
+        let rgba = Rgba32::new(0.5, 0.0019, 0.002, 1.0).unwrap();
+        assert_eq!(Rgba8::from(rgba).to_array(), [128, 0, 1, 255]);
+
+        for value in 0..=u8::MAX {
+            let srgba8 = Srgba8::new(value, value, value, value);
+            assert_eq!(Srgba8::from(Rgba32::from(srgba8)), srgba8);
+            let rgba8 = Rgba8::new(value, value, value, value);
+            assert_eq!(Rgba8::from(Rgba32::from(rgba8)), rgba8);
+        }
+        // linear 1/255 is far brighter than one gamma encoded step
+        assert_eq!(Srgba8::from(Rgba32::from(Rgba8::new(1, 1, 1, 255))).r, 13);
+    }
+}
@@ -1,2 +1,432 @@
+//! Ordered sets of named colors.
+//!
+//! A [`Palette`] comes from one of the built-in sets or from the palette files art tools export:
+//! GIMP `.gpl`, Paint.NET `.txt`, Lospec `.hex` and JASC `.pal`. The loaders take the file
+//! contents and the writers return them, reading and writing files is left to the caller.
+//!
+//! ```
+//! use miy_color::{Color, Palette};
+//!
+//! let palette = Palette::pico_8();
+//! let index = palette.nearest(Color::RED).unwrap();
+//! assert_eq!(palette.get(index).unwrap().name.as_deref(), Some("red"));
+//! ```
+
+use core::fmt::{self, Write};
+use core::ops::Index;
+
+use crate::css::{NAMED_COLORS, srgb_from_u32};
+use crate::{Color, Srgb, srgb};
+
+/// A color with an optional name, like `dark-blue` or `aliceblue`
+#[derive(Debug, PartialEq, Clone)]
+pub struct Entry {
+    pub color: Srgb,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Palette {
+    name: String,
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParsePaletteError {
+    /// The first line isn't the header of the format
+    MissingHeader,
+    /// Line `line`, counting from one, is neither a color nor something the format allows
+    InvalidLine { line: usize },
+    /// A JASC palette declared `expected` colors but listed `found`
+    CountMismatch { expected: usize, found: usize },
+}
+
+impl fmt::Display for ParsePaletteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingHeader => write!(f, "missing palette header"),
+            Self::InvalidLine { line } => write!(f, "invalid palette line {line}"),
+            Self::CountMismatch { expected, found } => {
+                write!(f, "expected {expected} palette colors, found {found}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParsePaletteError {}
+
+impl Palette {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Appends a color, indices of earlier entries stay the same
+    pub fn push(&mut self, color: Srgb, name: Option<String>) {
+        self.entries.push(Entry { color, name });
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Entry> {
+        self.entries.get(index)
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    pub fn colors(&self) -> impl Iterator<Item = Srgb> + '_ {
+        self.entries.iter().map(|entry| entry.color)
+    }
+
+    /// The first color called `name`, ignoring ASCII case
+    pub fn find(&self, name: &str) -> Option<Srgb> {
+        self.entries
+            .iter()
+            .find(|entry| {
+                entry
+                    .name
+                    .as_deref()
+                    .is_some_and(|entry| entry.eq_ignore_ascii_case(name))
+            })
+            .map(|entry| entry.color)
+    }
+
+    /// Index of the perceptually closest color by [`Oklab`](crate::Oklab) distance, the first
+    /// one on ties and `None` for an empty palette
+    pub fn nearest(&self, color: impl Into<Color>) -> Option<usize> {
+        let target = color.into().to_oklab();
+        self.colors()
+            .map(|color| Color::Srgb(color).to_oklab().distance(target))
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(index, _)| index)
+    }
+
+    /// The 16 colors of the PICO-8 fantasy console, named as in its manual
+    pub fn pico_8() -> Self {
+        Self::from_table("PICO-8", PICO_8.map(|(name, hex)| (Some(name), hex)))
+    }
+
+    /// DawnBringer's 32 color palette
+    pub fn db32() -> Self {
+        Self::from_table("DB32", DB32.map(|hex| (None, hex)))
+    }
+
+    /// ENDESGA 32
+    pub fn endesga_32() -> Self {
+        Self::from_table("ENDESGA 32", ENDESGA_32.map(|hex| (None, hex)))
+    }
+
+    /// The 148 named colors of CSS in alphabetical order, aliases like `grey` included
+    pub fn css() -> Self {
+        Self::from_table("CSS", NAMED_COLORS.map(|(name, hex)| (Some(name), hex)))
+    }
+
+    fn from_table(
+        name: &str,
+        table: impl IntoIterator<Item = (Option<&'static str>, u32)>,
+    ) -> Self {
+        let mut palette = Self::new(name);
+        for (name, hex) in table {
+            palette.push(srgb_from_u32(hex), name.map(String::from));
+        }
+        palette
+    }
+
+    /// Reads a GIMP palette, `Name` is kept while `Columns` and comments are skipped
+    pub fn from_gpl(contents: &str) -> Result<Self, ParsePaletteError> {
+        let mut lines = lines(contents);
+        match lines.next() {
+            Some((_, "GIMP Palette")) => {}
+            _ => return Err(ParsePaletteError::MissingHeader),
+        }
+
+        let mut palette = Self::default();
+        for (number, line) in lines {
+            if line.is_empty() || line.starts_with('#') || line.starts_with("Columns:") {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix("Name:") {
+                palette.name = name.trim().into();
+                continue;
+            }
+
+            let invalid = ParsePaletteError::InvalidLine { line: number };
+            let (color, name) = split_rgb(line).ok_or(invalid)?;
+            let name = (!name.is_empty()).then(|| name.into());
+            palette.push(color, name);
+        }
+        Ok(palette)
+    }
+
+    pub fn to_gpl(&self) -> String {
+        let mut gpl = format!("GIMP Palette\nName: {}\n#\n", self.name);
+        for Entry { color, name } in &self.entries {
+            let [r, g, b] = color.to_u8_array();
+            let name = name.as_deref().unwrap_or_default();
+            _ = writeln!(gpl, "{r:3} {g:3} {b:3}\t{name}");
+        }
+        gpl
+    }
+
+    /// Reads a Paint.NET palette of `aarrggbb` lines, alpha is ignored and `;` starts a comment
+    pub fn from_paint_net(contents: &str) -> Result<Self, ParsePaletteError> {
+        let mut palette = Self::default();
+        for (number, line) in lines(contents) {
+            if line.is_empty() || line.starts_with(';') {
+                continue;
+            }
+            let color =
+                parse_hex(line, 8).ok_or(ParsePaletteError::InvalidLine { line: number })?;
+            palette.push(color, None);
+        }
+        Ok(palette)
+    }
+
+    /// Opaque `FFRRGGBB` lines after a comment with the name
+    pub fn to_paint_net(&self) -> String {
+        let mut txt = format!("; paint.net Palette File\n; {}\n", self.name);
+        for color in self.colors() {
+            _ = writeln!(txt, "FF{color:X}");
+        }
+        txt
+    }
+
+    /// Reads a Lospec palette of `rrggbb` lines
+    pub fn from_lospec_hex(contents: &str) -> Result<Self, ParsePaletteError> {
+        let mut palette = Self::default();
+        for (number, line) in lines(contents) {
+            if line.is_empty() {
+                continue;
+            }
+            let color =
+                parse_hex(line, 6).ok_or(ParsePaletteError::InvalidLine { line: number })?;
+            palette.push(color, None);
+        }
+        Ok(palette)
+    }
+
+    /// The format has no room for names, only colors are written
+    pub fn to_lospec_hex(&self) -> String {
+        let mut hex = String::new();
+        for color in self.colors() {
+            _ = writeln!(hex, "{color:x}");
+        }
+        hex
+    }
+
+    /// Reads a JASC palette as written by Paint Shop Pro and Aseprite
+    pub fn from_jasc_pal(contents: &str) -> Result<Self, ParsePaletteError> {
+        let mut lines = lines(contents).filter(|(_, line)| !line.is_empty());
+        match (lines.next(), lines.next()) {
+            (Some((_, "JASC-PAL")), Some((_, "0100"))) => {}
+            _ => return Err(ParsePaletteError::MissingHeader),
+        }
+        let (number, count) = lines.next().ok_or(ParsePaletteError::MissingHeader)?;
+        let expected = count
+            .parse()
+            .map_err(|_| ParsePaletteError::InvalidLine { line: number })?;
+
+        let mut palette = Self::default();
+        for (number, line) in lines {
+            let color = split_rgb(line)
+                .filter(|(_, rest)| rest.is_empty())
+                .ok_or(ParsePaletteError::InvalidLine { line: number })?
+                .0;
+            palette.push(color, None);
+        }
+        match palette.len() == expected {
+            true => Ok(palette),
+            false => Err(ParsePaletteError::CountMismatch {
+                expected,
+                found: palette.len(),
+            }),
+        }
+    }
+
+    /// Uses `\r\n` line endings like Paint Shop Pro did
+    pub fn to_jasc_pal(&self) -> String {
+        let mut pal = format!("JASC-PAL\r\n0100\r\n{}\r\n", self.len());
+        for color in self.colors() {
+            let [r, g, b] = color.to_u8_array();
+            _ = write!(pal, "{r} {g} {b}\r\n");
+        }
+        pal
+    }
+}
+
+/// The color
+impl Index<usize> for Palette {
+    type Output = Srgb;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.entries[index].color
+    }
+}
+
+impl<'a> IntoIterator for &'a Palette {
+    type Item = &'a Entry;
+    type IntoIter = core::slice::Iter<'a, Entry>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter()
+    }
+}
+
+/// Trimmed lines with their number counting from one, without a byte order mark
+fn lines(contents: &str) -> impl Iterator<Item = (usize, &str)> {
+    (1..).zip(
+        contents
+            .trim_start_matches('\u{feff}')
+            .lines()
+            .map(str::trim),
+    )
+}
+
+/// Three decimal channels and the rest of the line
+fn split_rgb(line: &str) -> Option<(Srgb, &str)> {
+    let mut rest = line;
+    let mut channels = [0; 3];
+    for channel in &mut channels {
+        let (value, tail) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+        *channel = value.parse().ok()?;
+        rest = tail.trim_start();
+    }
+
+    let [r, g, b] = channels.map(srgb::Channel::from_u8);
+    Some((Srgb { r, g, b }, rest.trim_end()))
+}
+
+/// `digits` hex digits, an alpha byte in front of `rrggbb` is dropped
+fn parse_hex(hex: &str, digits: usize) -> Option<Srgb> {
+    let valid = hex.len() == digits && hex.bytes().all(|digit| digit.is_ascii_hexdigit());
+    let hex = u32::from_str_radix(hex, 16).ok().filter(|_| valid)?;
+    Some(srgb_from_u32(hex))
+}
+
+const PICO_8: [(&str, u32); 16] = [
+    ("black", 0x000000),
+    ("dark-blue", 0x1d2b53),
+    ("dark-purple", 0x7e2553),
+    ("dark-green", 0x008751),
+    ("brown", 0xab5236),
+    ("dark-grey", 0x5f574f),
+    ("light-grey", 0xc2c3c7),
+    ("white", 0xfff1e8),
+    ("red", 0xff004d),
+    ("orange", 0xffa300),
+    ("yellow", 0xffec27),
+    ("green", 0x00e436),
+    ("blue", 0x29adff),
+    ("lavender", 0x83769c),
+    ("pink", 0xff77a8),
+    ("light-peach", 0xffccaa),
+];
+
+const DB32: [u32; 32] = [
+    0x000000, 0x222034, 0x45283c, 0x663931, 0x8f563b, 0xdf7126, 0xd9a066, 0xeec39a, 0xfbf236,
+    0x99e550, 0x6abe30, 0x37946e, 0x4b692f, 0x524b24, 0x323c39, 0x3f3f74, 0x306082, 0x5b6ee1,
+    0x639bff, 0x5fcde4, 0xcbdbfc, 0xffffff, 0x9badb7, 0x847e87, 0x696a6a, 0x595652, 0x76428a,
+    0xac3232, 0xd95763, 0xd77bba, 0x8f974a, 0x8a6f30,
+];
+
+const ENDESGA_32: [u32; 32] = [
+    0xbe4a2f, 0xd77643, 0xead4aa, 0xe4a672, 0xb86f50, 0x733e39, 0x3e2731, 0xa22633, 0xe43b44,
+    0xf77622, 0xfeae34, 0xfee761, 0x63c74d, 0x3e8948, 0x265c42, 0x193c3e, 0x124e89, 0x0099db,
+    0x2ce8f5, 0xffffff, 0xc0cbdc, 0x8b9bb4, 0x5a6988, 0x3a4466, 0x262b44, 0x181425, 0xff0044,
+    0x68386c, 0xb55088, 0xf6757a, 0xe8b796, 0xc28569,
+];
+
+/// Empty placeholder kept so existing imports still compile
+#[deprecated(note = "has no items, use `Palette` instead")]
 pub struct Base;
+
+/// Empty placeholder kept so existing imports still compile
+#[deprecated(note = "has no items, use `Palette` instead")]
 pub mod base {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn builtin_palettes() {
+        assert_eq!(Palette::pico_8().len(), 16);
+        assert_eq!(Palette::db32().len(), 32);
+        assert_eq!(Palette::endesga_32().len(), 32);
+
+        let css = Palette::css();
+        assert_eq!(css.len(), 148);
+        assert_eq!(css.find("RebeccaPurple"), Srgb::from_hex("#663399"));
+        assert_eq!(Palette::pico_8()[8], Srgb::from_hex("#ff004d").unwrap());
+    }
+
+    #[test]
+    fn nearest_color() {
+        let pico = Palette::pico_8();
+        assert_eq!(pico.nearest(Srgb::from_hex("#2030a0").unwrap()), Some(1));
+        assert_eq!(pico.nearest(Color::WHITE), Some(7));
+        assert_eq!(Palette::new("empty").nearest(Color::WHITE), None);
+    }
+
+    #[test]
+    fn file_round_trips() {
+        let pico = Palette::pico_8();
+        let colors = |palette: &Palette| palette.colors().collect::<Vec<_>>();
+
+        assert_eq!(Palette::from_gpl(&pico.to_gpl()), Ok(pico.clone()));
+        for palette in [
+            Palette::from_paint_net(&pico.to_paint_net()),
+            Palette::from_lospec_hex(&pico.to_lospec_hex()),
+            Palette::from_jasc_pal(&pico.to_jasc_pal()),
+        ] {
+            assert_eq!(colors(&palette.unwrap()), colors(&pico));
+        }
+    }
+
+    #[test]
+    fn reads_tool_output() {
+        let gpl =
+            "GIMP Palette\nName: Dusk\nColumns: 4\n#\n 26  36  33\tjungle green\n255 255 255\n";
+        let dusk = Palette::from_gpl(gpl).unwrap();
+        assert_eq!(dusk.name(), "Dusk");
+        assert_eq!(dusk.find("jungle green"), Srgb::from_hex("#1a2421"));
+        assert_eq!(dusk.get(1).unwrap().name, None);
+
+        let txt = "\u{feff}; paint.net Palette File\r\n;Colors: 1\r\nFF1A2421\r\n";
+        assert_eq!(Palette::from_paint_net(txt).unwrap()[0], dusk[0]);
+
+        let errors = [
+            Palette::from_gpl("JASC-PAL\n"),
+            Palette::from_gpl("GIMP Palette\n1 2\n"),
+            Palette::from_lospec_hex("1a2421\n#ffffff\n"),
+            Palette::from_jasc_pal("JASC-PAL\n0100\n2\n1 2 3\n"),
+        ];
+        assert_eq!(
+            errors.map(|result| result.err()),
+            [
+                Some(ParsePaletteError::MissingHeader),
+                Some(ParsePaletteError::InvalidLine { line: 2 }),
+                Some(ParsePaletteError::InvalidLine { line: 2 }),
+                Some(ParsePaletteError::CountMismatch {
+                    expected: 2,
+                    found: 1
+                }),
+            ]
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use miy_math::{AsStd140, AsStd430, Interpolate, Vec3, Vec4};
+
 use crate::srgb;
 
 #[derive(Debug, PartialEq, Copy, Clone)]
@@ -6,6 +8,7 @@ pub struct Channel {
 }
 
 #[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rgba32 {
     pub r: Channel,
     pub g: Channel,
@@ -14,6 +17,7 @@ pub struct Rgba32 {
 }
 
 #[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rgb32 {
     pub r: Channel,
     pub g: Channel,
@@ -35,6 +39,28 @@ impl Channel {
     pub const fn get(&self) -> f32 {
         self.value
     }
+
+    /// Clamps `value` into `0..=1`, NaN becomes zero
+    pub const fn clamped(value: f32) -> Self {
+        if value.is_nan() {
+            return Self::ZERO;
+        }
+
+        Self {
+            value: value.clamp(0.0, 1.0),
+        }
+    }
+
+    pub const fn from_u8(value: u8) -> Self {
+        Self {
+            value: value as f32 / 255.0,
+        }
+    }
+
+    /// Nearest of the 256 steps of an 8 bit channel, dark colors lose the most detail
+    pub fn to_u8(self) -> u8 {
+        (self.value * 255.0).round() as u8
+    }
 }
 
 impl Rgb32 {
@@ -80,6 +106,75 @@ impl Rgba32 {
     }
 }
 
+/// Blends in linear light, overshooting eased progress is clamped to a valid channel
+impl Interpolate for Channel {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        let value = self.value.interpolate(&other.value, t);
+        Self::new(value.clamp(0.0, 1.0)).unwrap_or(*self)
+    }
+}
+
+impl Interpolate for Rgb32 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self {
+            r: self.r.interpolate(&other.r, t),
+            g: self.g.interpolate(&other.g, t),
+            b: self.b.interpolate(&other.b, t),
+        }
+    }
+}
+
+impl Interpolate for Rgba32 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self {
+            r: self.r.interpolate(&other.r, t),
+            g: self.g.interpolate(&other.g, t),
+            b: self.b.interpolate(&other.b, t),
+            a: self.a.interpolate(&other.a, t),
+        }
+    }
+}
+
+/// Linear colors go into blocks as a `vec3`
+impl AsStd140 for Rgb32 {
+    const STD140_ALIGN: usize = 16;
+    const STD140_SIZE: usize = 12;
+
+    fn write_std140(&self, out: &mut [u8]) {
+        let [r, g, b] = self.to_array();
+        Vec3::<(), f32>::new(r, g, b).write_std140(out);
+    }
+}
+
+impl AsStd430 for Rgb32 {
+    const STD430_ALIGN: usize = 16;
+    const STD430_SIZE: usize = 12;
+
+    fn write_std430(&self, out: &mut [u8]) {
+        self.write_std140(out);
+    }
+}
+
+/// Linear colors with alpha go into blocks as a `vec4`
+impl AsStd140 for Rgba32 {
+    const STD140_ALIGN: usize = 16;
+    const STD140_SIZE: usize = 16;
+
+    fn write_std140(&self, out: &mut [u8]) {
+        let [r, g, b, a] = self.to_array();
+        Vec4::<(), f32>::new(r, g, b, a).write_std140(out);
+    }
+}
+
+impl AsStd430 for Rgba32 {
+    const STD430_ALIGN: usize = 16;
+    const STD430_SIZE: usize = 16;
+
+    fn write_std430(&self, out: &mut [u8]) {
+        self.write_std140(out);
+    }
+}
+
 impl From<Rgb32> for Rgba32 {
     fn from(Rgb32 { r, g, b }: Rgb32) -> Self {
         Self {
@@ -0,0 +1,118 @@
+//! Serde support, enabled with the `serde` feature.
+//!
+//! Channels are written as plain numbers and go through [`Channel::new`](rgb::Channel::new) when
+//! loaded, so out of range values are rejected. The color structs derive their impls on top.
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Hue, Oklab, Oklch, rgb, srgb};
+
+macro_rules! channel {
+    ($($channel:ty),+) => {
+        $(
+            impl Serialize for $channel {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    self.get().serialize(serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $channel {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let value = f32::deserialize(deserializer)?;
+                    Self::new(value).ok_or_else(|| D::Error::custom("channel must be within 0..=1"))
+                }
+            }
+        )+
+    };
+}
+
+channel!(rgb::Channel, srgb::Channel);
+
+/// Written in degrees, any finite value loads and is wrapped into `0..360`
+impl Serialize for Hue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Hue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let degrees = f32::deserialize(deserializer)?;
+        Self::new(degrees).ok_or_else(|| D::Error::custom("hue must be finite"))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Oklab")]
+struct Lab {
+    l: f32,
+    a: f32,
+    b: f32,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Oklch")]
+struct Lch {
+    l: f32,
+    c: f32,
+    h: f32,
+}
+
+impl Serialize for Oklab {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Lab {
+            l: self.l(),
+            a: self.a(),
+            b: self.b(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Oklab {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Lab { l, a, b } = Lab::deserialize(deserializer)?;
+        Self::new(l, a, b).ok_or_else(|| D::Error::custom("invalid Oklab color"))
+    }
+}
+
+impl Serialize for Oklch {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Lch {
+            l: self.l(),
+            c: self.c(),
+            h: self.h().get(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Oklch {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Lch { l, c, h } = Lch::deserialize(deserializer)?;
+        Self::new(l, c, h).ok_or_else(|| D::Error::custom("invalid Oklch color"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Color, Rgb32, Srgba};
+
+    #[test]
+    fn round_trips_and_validates() {
+        let srgba = Srgba::new(1.0, 0.5, 0.25, 0.75).unwrap();
+        let json = serde_json::to_string(&srgba).unwrap();
+        assert_eq!(json, r#"{"r":1.0,"g":0.5,"b":0.25,"a":0.75}"#);
+        assert_eq!(serde_json::from_str::<Srgba>(&json).unwrap(), srgba);
+        assert!(serde_json::from_str::<Rgb32>(r#"{"r":1.5,"g":0.0,"b":0.0}"#).is_err());
+
+        let color = Color::Rgb32(Rgb32::new(0.0, 1.0, 0.0).unwrap());
+        let json = serde_json::to_string(&color).unwrap();
+        assert_eq!(json, r#"{"Rgb32":{"r":0.0,"g":1.0,"b":0.0}}"#);
+        assert_eq!(
+            serde_json::from_str::<Color>(&json).unwrap().to_rgb32(),
+            color.to_rgb32()
+        );
+    }
+}
@@ -1,3 +1,7 @@
+use core::fmt;
+
+use miy_math::Interpolate;
+
 use crate::rgb;
 
 #[derive(Debug, PartialEq, Copy, Clone)]
@@ -6,6 +10,7 @@ pub struct Channel {
 }
 
 #[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Srgba {
     pub r: Channel,
     pub g: Channel,
@@ -14,6 +19,7 @@ pub struct Srgba {
 }
 
 #[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Srgb {
     pub r: Channel,
     pub g: Channel,
@@ -35,6 +41,89 @@ impl Channel {
     pub const fn get(&self) -> f32 {
         self.value
     }
+
+    /// Clamps `value` into `0..=1`, NaN becomes zero
+    pub const fn clamped(value: f32) -> Self {
+        if value.is_nan() {
+            return Self::ZERO;
+        }
+
+        Self {
+            value: value.clamp(0.0, 1.0),
+        }
+    }
+
+    pub const fn from_u8(value: u8) -> Self {
+        Self {
+            value: value as f32 / 255.0,
+        }
+    }
+
+    /// Nearest of the 256 steps of an 8 bit channel
+    pub fn to_u8(self) -> u8 {
+        (self.value * 255.0).round() as u8
+    }
+}
+
+/// Parses `#rgb`, `#rgba`, `#rrggbb` and `#rrggbbaa` with an optional `#`, alpha is `0xff` for the
+/// forms without it. The `bool` tells whether alpha digits were present.
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "channel indices stay below four and two nibbles combine to at most 0xff"
+)]
+const fn parse_hex(hex: &str) -> Option<([u8; 4], bool)> {
+    let bytes = hex.as_bytes();
+    let digits = match bytes {
+        [b'#', digits @ ..] => digits,
+        digits => digits,
+    };
+
+    let short = matches!(digits.len(), 3 | 4);
+    if !short && !matches!(digits.len(), 6 | 8) {
+        return None;
+    }
+
+    let mut channels = [0xff; 4];
+    let mut index = 0;
+    while index < channels.len() {
+        let value = if short {
+            if index >= digits.len() {
+                break;
+            }
+            let Some(digit) = hex_digit(digits[index]) else {
+                return None;
+            };
+            digit * 0x11
+        } else {
+            if index * 2 >= digits.len() {
+                break;
+            }
+            let (Some(high), Some(low)) = (
+                hex_digit(digits[index * 2]),
+                hex_digit(digits[index * 2 + 1]),
+            ) else {
+                return None;
+            };
+            high * 0x10 + low
+        };
+        channels[index] = value;
+        index += 1;
+    }
+
+    Some((channels, matches!(digits.len(), 4 | 8)))
+}
+
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "each arm only subtracts the start of the range it matched"
+)]
+const fn hex_digit(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None,
+    }
 }
 
 impl Srgb {
@@ -52,9 +141,33 @@ impl Srgb {
         Some(Self { r, g, b })
     }
 
+    /// Parses `#rgb` or `#rrggbb`, the `#` is optional. Usable for constants:
+    ///
+    /// ```
+    /// use miy_color::Srgb;
+    ///
+    /// const JUNGLE: Srgb = Srgb::from_hex("#1a2421").unwrap();
+    /// assert_eq!(JUNGLE.to_u8_array(), [0x1a, 0x24, 0x21]);
+    /// assert_eq!(Srgb::from_hex("#1a242180"), None);
+    /// ```
+    pub const fn from_hex(hex: &str) -> Option<Self> {
+        match parse_hex(hex) {
+            Some(([r, g, b, _], false)) => Some(Self {
+                r: Channel::from_u8(r),
+                g: Channel::from_u8(g),
+                b: Channel::from_u8(b),
+            }),
+            _ => None,
+        }
+    }
+
     pub fn to_array(self) -> [f32; 3] {
         [self.r.get(), self.g.get(), self.b.get()]
     }
+
+    pub fn to_u8_array(self) -> [u8; 3] {
+        [self.r.to_u8(), self.g.to_u8(), self.b.to_u8()]
+    }
 }
 
 impl Srgba {
@@ -75,11 +188,74 @@ impl Srgba {
         Some(Self { r, g, b, a })
     }
 
+    /// Parses `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`, the `#` is optional and alpha defaults
+    /// to opaque
+    pub const fn from_hex(hex: &str) -> Option<Self> {
+        match parse_hex(hex) {
+            Some(([r, g, b, a], _)) => Some(Self {
+                r: Channel::from_u8(r),
+                g: Channel::from_u8(g),
+                b: Channel::from_u8(b),
+                a: Channel::from_u8(a),
+            }),
+            None => None,
+        }
+    }
+
     pub fn to_array(self) -> [f32; 4] {
         [self.r.get(), self.g.get(), self.b.get(), self.a.get()]
     }
+
+    pub fn to_u8_array(self) -> [u8; 4] {
+        [
+            self.r.to_u8(),
+            self.g.to_u8(),
+            self.b.to_u8(),
+            self.a.to_u8(),
+        ]
+    }
+}
+
+/// `#rrggbb`
+impl fmt::Display for Srgb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#x}")
+    }
+}
+
+/// `#rrggbbaa`
+impl fmt::Display for Srgba {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:#x}")
+    }
 }
 
+/// Hex digits, prefixed with `#` by the alternate flag `{:#x}`
+macro_rules! hex_format {
+    ($($trait:ident, $name:ty, $format:literal);+) => {
+        $(
+            impl fmt::$trait for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    if f.alternate() {
+                        f.write_str("#")?;
+                    }
+                    for channel in self.to_u8_array() {
+                        write!(f, $format, channel)?;
+                    }
+                    Ok(())
+                }
+            }
+        )+
+    };
+}
+
+hex_format!(
+    LowerHex, Srgb, "{:02x}";
+    UpperHex, Srgb, "{:02X}";
+    LowerHex, Srgba, "{:02x}";
+    UpperHex, Srgba, "{:02X}"
+);
+
 impl From<Srgb> for Srgba {
     fn from(Srgb { r, g, b }: Srgb) -> Self {
         let a = Channel::ONE;
@@ -93,6 +269,24 @@ impl From<Srgba> for Srgb {
     }
 }
 
+/// Blends in linear light, which avoids the dark fringes of mixing gamma encoded values
+impl Interpolate for Srgb {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        crate::Rgb32::from(*self)
+            .interpolate(&crate::Rgb32::from(*other), t)
+            .into()
+    }
+}
+
+/// Blends in linear light, the alpha channel is linear already
+impl Interpolate for Srgba {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        crate::Rgba32::from(*self)
+            .interpolate(&crate::Rgba32::from(*other), t)
+            .into()
+    }
+}
+
 impl From<crate::Rgb32> for Srgb {
     fn from(crate::Rgb32 { r, g, b }: crate::Rgb32) -> Self {
         Self {
@@ -140,3 +334,41 @@ impl From<rgb::Channel> for Channel {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn interpolates_in_linear_light() {
+        let black = Srgba::new(0.0, 0.0, 0.0, 0.0).unwrap();
+        let white = Srgba::new(1.0, 1.0, 1.0, 1.0).unwrap();
+
+        let [r, g, b, a] = black.interpolate(&white, 0.5).to_array();
+        // half the light is brighter than half the gamma encoded value
+        assert!((r - 0.7354).abs() < 1e-3);
+        assert_eq!((r, g), (g, b));
+        assert!((a - 0.5).abs() < 1e-6);
+
+        let overshoot = black.interpolate(&white, 1.5).to_array();
+        assert!(overshoot.iter().all(|channel| (channel - 1.0).abs() < 1e-6));
+        assert_eq!(black.interpolate(&white, -0.5), black);
+    }
+
+    #[test]
+    fn hex_round_trips() {
+        const SHORT: Srgba = Srgba::from_hex("#f80c").unwrap();
+        assert_eq!(SHORT.to_u8_array(), [0xff, 0x88, 0x00, 0xcc]);
+        assert_eq!(
+            Srgba::from_hex("1A2421").unwrap().to_u8_array(),
+            [0x1a, 0x24, 0x21, 0xff]
+        );
+        assert_eq!(Srgb::from_hex("#1a242"), None);
+        assert_eq!(Srgb::from_hex("#1g2421"), None);
+
+        let srgba = Srgba::from_hex("#1a242180").unwrap();
+        assert_eq!(srgba.to_string(), "#1a242180");
+        assert_eq!(format!("{:X}", Srgb::from(srgba)), "1A2421");
+        assert_eq!(format!("{:#x}", Srgb::from(srgba)), "#1a2421");
+    }
+}
@@ -0,0 +1,116 @@
+//! Derives `AsStd140` and `AsStd430` from `miy-math` for structs whose fields implement them.
+//!
+//! The generated code refers to `::miy_math`, crates using it through another path name it with
+//! `#[layout(crate = path::to::miy_math)]`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, Index, Member, Path, parse_macro_input, parse_quote};
+
+#[proc_macro_derive(AsStd140, attributes(layout))]
+pub fn derive_as_std140(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, Standard::Std140)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(AsStd430, attributes(layout))]
+pub fn derive_as_std430(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input, Standard::Std430)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[derive(Copy, Clone)]
+enum Standard {
+    Std140,
+    Std430,
+}
+
+fn expand(input: &DeriveInput, standard: Standard) -> syn::Result<TokenStream2> {
+    let krate = crate_path(input)?;
+    let (trait_name, align, size, write, start) = match standard {
+        Standard::Std140 => (
+            format_ident!("AsStd140"),
+            format_ident!("STD140_ALIGN"),
+            format_ident!("STD140_SIZE"),
+            format_ident!("write_std140"),
+            quote!(#krate::layout::Layout::std140()),
+        ),
+        Standard::Std430 => (
+            format_ident!("AsStd430"),
+            format_ident!("STD430_ALIGN"),
+            format_ident!("STD430_SIZE"),
+            format_ident!("write_std430"),
+            quote!(#krate::layout::Layout::std430()),
+        ),
+    };
+    let trait_path = quote!(#krate::layout::#trait_name);
+
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "GPU block layouts can only be derived for structs",
+        ));
+    };
+    let members: Vec<Member> = match &data.fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .filter_map(|field| field.ident.clone().map(Member::Named))
+            .collect(),
+        Fields::Unnamed(fields) => (0..fields.unnamed.len())
+            .map(|index| Member::Unnamed(Index::from(index)))
+            .collect(),
+        Fields::Unit => Vec::new(),
+    };
+    let types: Vec<_> = data.fields.iter().map(|field| &field.ty).collect();
+
+    let mut generics = input.generics.clone();
+    let where_clause = generics.make_where_clause();
+    for ty in &types {
+        where_clause.predicates.push(parse_quote!(#ty: #trait_path));
+    }
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let name = &input.ident;
+
+    Ok(quote! {
+        impl #impl_generics #trait_path for #name #type_generics #where_clause {
+            const #align: usize = #start
+                #(.field(<#types as #trait_path>::#align, <#types as #trait_path>::#size))*
+                .align();
+            const #size: usize = #start
+                #(.field(<#types as #trait_path>::#align, <#types as #trait_path>::#size))*
+                .size();
+
+            fn #write(&self, out: &mut [u8]) {
+                let layout = #start;
+                #(
+                    let offset = layout.offset(<#types as #trait_path>::#align);
+                    #trait_path::#write(&self.#members, &mut out[offset..]);
+                    let layout = layout.field(<#types as #trait_path>::#align, <#types as #trait_path>::#size);
+                )*
+                let _ = layout;
+            }
+        }
+    })
+}
+
+/// Path from `#[layout(crate = ...)]`, `::miy_math` by default
+fn crate_path(input: &DeriveInput) -> syn::Result<Path> {
+    let mut path = parse_quote!(::miy_math);
+    for attribute in input.attrs.iter().filter(|a| a.path().is_ident("layout")) {
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("crate") {
+                path = meta.value()?.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("expected `crate = path`"))
+            }
+        })?;
+    }
+    Ok(path)
+}
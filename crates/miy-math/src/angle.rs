@@ -0,0 +1,181 @@
+use core::{
+    f32::consts::{PI, TAU},
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
+};
+
+mod unit {
+    #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Debug)]
+    pub struct Radians;
+
+    #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Debug)]
+    pub struct Degrees;
+}
+
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct Angle<Unit, Kind> {
+    value: Kind,
+    phantom: PhantomData<Unit>,
+}
+
+pub type Radians = Angle<unit::Radians, f32>;
+pub type Degrees = Angle<unit::Degrees, f32>;
+
+impl<Unit, Kind> Angle<Unit, Kind> {
+    pub const fn get(&self) -> Kind
+    where
+        Kind: Copy,
+    {
+        self.value
+    }
+}
+
+impl<Unit> Angle<Unit, f32> {
+    pub const ZERO: Self = Self::new_unchecked(0.0);
+
+    /// Returns `None` if `value` is not finite
+    pub const fn new(value: f32) -> Option<Self> {
+        if value.is_finite() {
+            Some(Self::new_unchecked(value))
+        } else {
+            None
+        }
+    }
+
+    const fn new_unchecked(value: f32) -> Self {
+        Self {
+            value,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl Radians {
+    pub const FRAC_PI_2: Self = Self::new_unchecked(PI / 2.0);
+    pub const PI: Self = Self::new_unchecked(PI);
+    pub const TAU: Self = Self::new_unchecked(TAU);
+
+    pub fn to_degrees(self) -> Degrees {
+        Degrees::new_unchecked(self.value.to_degrees())
+    }
+
+    pub fn sin(self) -> f32 {
+        self.value.sin()
+    }
+
+    pub fn cos(self) -> f32 {
+        self.value.cos()
+    }
+
+    pub fn sin_cos(self) -> (f32, f32) {
+        self.value.sin_cos()
+    }
+
+    pub fn tan(self) -> f32 {
+        self.value.tan()
+    }
+
+    /// The same angle in `[-π, π)`
+    pub fn wrap(self) -> Self {
+        Self::new_unchecked((self.value + PI).rem_euclid(TAU) - PI)
+    }
+}
+
+impl Degrees {
+    pub fn to_radians(self) -> Radians {
+        Radians::new_unchecked(self.value.to_radians())
+    }
+
+    /// The same angle in `[-180, 180)`
+    pub fn wrap(self) -> Self {
+        Self::new_unchecked((self.value + 180.0).rem_euclid(360.0) - 180.0)
+    }
+}
+
+impl From<Degrees> for Radians {
+    fn from(value: Degrees) -> Self {
+        value.to_radians()
+    }
+}
+
+impl From<Radians> for Degrees {
+    fn from(value: Radians) -> Self {
+        value.to_degrees()
+    }
+}
+
+impl<Unit> Add for Angle<Unit, f32> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new_unchecked(self.value + rhs.value)
+    }
+}
+
+impl<Unit> Sub for Angle<Unit, f32> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new_unchecked(self.value - rhs.value)
+    }
+}
+
+impl<Unit> Mul<f32> for Angle<Unit, f32> {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self {
+        Self::new_unchecked(self.value * rhs)
+    }
+}
+
+impl<Unit> Div<f32> for Angle<Unit, f32> {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self {
+        Self::new_unchecked(self.value / rhs)
+    }
+}
+
+impl<Unit> Neg for Angle<Unit, f32> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new_unchecked(-self.value)
+    }
+}
+
+impl<Unit> AddAssign for Angle<Unit, f32> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.value += rhs.value;
+    }
+}
+
+impl<Unit> SubAssign for Angle<Unit, f32> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.value -= rhs.value;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn angle_conversions() {
+        let right = Degrees::new(90.0).unwrap();
+        assert_eq!(Radians::from(right), Radians::FRAC_PI_2);
+        assert_eq!(Radians::PI.to_degrees().get(), 180.0);
+
+        assert_eq!(Degrees::new(270.0).unwrap().wrap().get(), -90.0);
+        assert_eq!(Degrees::new(-540.0).unwrap().wrap().get(), -180.0);
+        assert!(
+            ((Radians::TAU + Radians::FRAC_PI_2).wrap() - Radians::FRAC_PI_2)
+                .get()
+                .abs()
+                < 1e-5
+        );
+
+        assert_eq!(Radians::new(f32::NAN), None);
+        assert_eq!(Degrees::new(f32::INFINITY), None);
+    }
+}
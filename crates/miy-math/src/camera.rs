@@ -0,0 +1,306 @@
+use crate::{
+    ClipVec4, DepthRange, Mat4, ScreenDimensions, Vec2, Vec3, Vec4, WorldPosition, WorldVec3, space,
+};
+
+pub type ScreenPosition = Vec2<space::Screen, f32>;
+pub type ClipPosition = Vec3<space::Clip, f32>;
+
+/// The pixel rectangle of a surface that is rendered into.
+///
+/// Screen space has its origin in the top left corner of the surface with y growing downwards,
+/// the same as window and cursor coordinates.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Viewport {
+    x: u32,
+    y: u32,
+    dimensions: ScreenDimensions,
+}
+
+/// Converts points between [`space::Screen`], [`space::Clip`] and [`space::World`]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Camera {
+    viewport: Viewport,
+    view_projection: Mat4,
+    inverse_view_projection: Mat4,
+    depth_range: DepthRange,
+}
+
+impl Viewport {
+    /// Viewport covering a whole surface of `dimensions`
+    pub fn new(dimensions: ScreenDimensions) -> Self {
+        Self {
+            x: 0,
+            y: 0,
+            dimensions,
+        }
+    }
+
+    /// Moves the top left corner of the viewport to `x` and `y` pixels
+    pub fn with_offset(self, x: u32, y: u32) -> Self {
+        Self { x, y, ..self }
+    }
+
+    pub fn offset(&self) -> (u32, u32) {
+        (self.x, self.y)
+    }
+
+    pub fn dimensions(&self) -> ScreenDimensions {
+        self.dimensions
+    }
+
+    pub fn aspect_ratio(&self) -> f32 {
+        self.dimensions.width().get() as f32 / self.dimensions.height().get() as f32
+    }
+
+    /// Returns `true` if `point` lies inside the viewport
+    pub fn contains(&self, point: ScreenPosition) -> bool {
+        let (x, y, width, height) = self.as_f32();
+        point.x >= x && point.y >= y && point.x < x + width && point.y < y + height
+    }
+
+    /// Maps a pixel position to normalized device coordinates, `[-1, 1]` inside the viewport
+    pub fn screen_to_clip(&self, point: ScreenPosition) -> Vec2<space::Clip, f32> {
+        let (x, y, width, height) = self.as_f32();
+
+        Vec2::new(
+            (point.x - x) / width * 2.0 - 1.0,
+            1.0 - (point.y - y) / height * 2.0,
+        )
+    }
+
+    /// Maps normalized device coordinates to a pixel position
+    pub fn clip_to_screen(&self, point: Vec2<space::Clip, f32>) -> ScreenPosition {
+        let (x, y, width, height) = self.as_f32();
+
+        Vec2::new(
+            x + (point.x + 1.0) * 0.5 * width,
+            y + (1.0 - point.y) * 0.5 * height,
+        )
+    }
+
+    fn as_f32(&self) -> (f32, f32, f32, f32) {
+        (
+            self.x as f32,
+            self.y as f32,
+            self.dimensions.width().get() as f32,
+            self.dimensions.height().get() as f32,
+        )
+    }
+}
+
+impl Camera {
+    /// Camera drawing into `viewport` with `view_projection` mapping world to clip space,
+    /// e.g. a [`ProjectionMatrix`](crate::ProjectionMatrix) or `projection * view`.
+    ///
+    /// Returns `None` if `view_projection` can't be inverted
+    pub fn new(viewport: Viewport, view_projection: impl Into<Mat4>) -> Option<Self> {
+        let view_projection = view_projection.into();
+
+        Some(Self {
+            viewport,
+            view_projection,
+            inverse_view_projection: view_projection.inverse()?,
+            depth_range: DepthRange::default(),
+        })
+    }
+
+    /// The depth range the projection was built with, OpenGL's `-1..=1` if not set
+    pub fn with_depth_range(self, depth_range: DepthRange) -> Self {
+        Self {
+            depth_range,
+            ..self
+        }
+    }
+
+    pub fn depth_range(&self) -> DepthRange {
+        self.depth_range
+    }
+
+    pub fn viewport(&self) -> Viewport {
+        self.viewport
+    }
+
+    pub fn view_projection(&self) -> Mat4 {
+        self.view_projection
+    }
+
+    pub fn inverse_view_projection(&self) -> Mat4 {
+        self.inverse_view_projection
+    }
+
+    /// Homogeneous clip coordinates of `point`, before the perspective divide
+    pub fn world_to_clip(&self, point: WorldVec3) -> ClipVec4 {
+        self.view_projection
+            .transform_vec4(point.extend(1.0))
+            .cast_space()
+    }
+
+    /// Normalized device coordinates of `point`.
+    ///
+    /// Returns `None` if the point is behind the camera
+    pub fn world_to_ndc(&self, point: WorldVec3) -> Option<ClipPosition> {
+        let Vec4 { x, y, z, w, .. } = self.world_to_clip(point);
+        (w > 0.0).then(|| Vec3::new(x / w, y / w, z / w))
+    }
+
+    /// Maps normalized device coordinates back into the world
+    pub fn ndc_to_world(&self, point: ClipPosition) -> WorldVec3 {
+        self.inverse_view_projection
+            .transform_point(point)
+            .cast_space()
+    }
+
+    /// Pixel position of `point`, e.g. for placing world anchored labels.
+    ///
+    /// Returns `None` if the point is behind the camera
+    pub fn world_to_screen(&self, point: WorldVec3) -> Option<ScreenPosition> {
+        let ndc = self.world_to_ndc(point)?;
+        Some(self.viewport.clip_to_screen(ndc.truncate()))
+    }
+
+    /// World position under the pixel `point` at the normalized device `depth`
+    pub fn screen_to_world_at_depth(&self, point: ScreenPosition, depth: f32) -> WorldVec3 {
+        self.ndc_to_world(self.viewport.screen_to_clip(point).extend(depth))
+    }
+
+    /// World position under the pixel `point`, for 2D cameras where depth doesn't affect x and y
+    pub fn screen_to_world(&self, point: ScreenPosition) -> WorldPosition {
+        self.screen_to_world_at_depth(point, 0.0).truncate()
+    }
+
+    /// Origin on the near plane and normalized direction of the ray going through the pixel
+    /// `point`, pointing away from the camera.
+    ///
+    /// The direction is taken halfway into the depth range, which stays finite for
+    /// [infinite projections](crate::ProjectionMatrix::perspective_infinite).
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "difference of two world positions"
+    )]
+    pub fn screen_to_ray(&self, point: ScreenPosition) -> Option<(WorldVec3, WorldVec3)> {
+        let (near, middle) = match self.depth_range {
+            DepthRange::NegativeOneToOne => (-1.0, 0.0),
+            DepthRange::ZeroToOne => (0.0, 0.5),
+            DepthRange::ReversedZeroToOne => (1.0, 0.5),
+        };
+        let origin = self.screen_to_world_at_depth(point, near);
+        let middle = self.screen_to_world_at_depth(point, middle);
+        Some((origin, (middle - origin).normalize()?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        DepthRange, Orthographic, OrthographicAnchor, ProjectionMatrix, WorldDimensions2D,
+    };
+
+    #[test]
+    fn orthographic_conversions() {
+        let world = WorldDimensions2D::new(200.0, 100.0).unwrap();
+        let viewport = Viewport::new(ScreenDimensions::new(400, 200).unwrap()).with_offset(10, 20);
+        let ortho = Orthographic::from_anchor(world, OrthographicAnchor::Center).unwrap();
+        let camera = Camera::new(viewport, ortho.projection()).unwrap();
+
+        let top_left = camera.screen_to_world(ScreenPosition::new(10.0, 20.0));
+        assert_eq!(top_left, WorldPosition::new(-100.0, 50.0));
+
+        let center = camera.screen_to_world(ScreenPosition::new(210.0, 120.0));
+        assert_eq!(center, WorldPosition::new(0.0, 0.0));
+
+        let label = camera.world_to_screen(WorldVec3::new(50.0, -25.0, 0.0));
+        assert_eq!(label, Some(ScreenPosition::new(310.0, 170.0)));
+
+        assert!(viewport.contains(ScreenPosition::new(10.0, 20.0)));
+        assert!(!viewport.contains(ScreenPosition::new(410.0, 20.0)));
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "combines projection and view"
+    )]
+    fn perspective_camera(projection: ProjectionMatrix, depth_range: DepthRange) -> Camera {
+        let view = Mat4::look_at(
+            WorldVec3::new(0.0, 0.0, 10.0),
+            WorldVec3::ZERO,
+            WorldVec3::new(0.0, 1.0, 0.0),
+        )
+        .unwrap();
+        let viewport = Viewport::new(ScreenDimensions::new(800, 600).unwrap());
+        Camera::new(viewport, projection * view)
+            .unwrap()
+            .with_depth_range(depth_range)
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "measures distances to the expected ray"
+    )]
+    fn assert_ray_from_near_plane(camera: &Camera) {
+        let center = ScreenPosition::new(400.0, 300.0);
+        let (origin, direction) = camera.screen_to_ray(center).unwrap();
+        assert!((origin - WorldVec3::new(0.0, 0.0, 9.9)).length() < 1e-3);
+        assert!((direction - WorldVec3::new(0.0, 0.0, -1.0)).length() < 1e-3);
+    }
+
+    #[test]
+    fn perspective_conversions() {
+        let projection = ProjectionMatrix::perspective_with_depth_range(
+            crate::Radians::FRAC_PI_2,
+            800.0 / 600.0,
+            0.1,
+            100.0,
+            DepthRange::NegativeOneToOne,
+        )
+        .unwrap();
+        let camera = perspective_camera(projection, DepthRange::NegativeOneToOne);
+
+        let center = camera.world_to_screen(WorldVec3::ZERO).unwrap();
+        assert!((center - ScreenPosition::new(400.0, 300.0)).length() < 1e-3);
+        assert_eq!(camera.world_to_screen(WorldVec3::new(0.0, 0.0, 20.0)), None);
+
+        let point = WorldVec3::new(3.0, -2.0, 1.0);
+        let ndc = camera.world_to_ndc(point).unwrap();
+        assert!((camera.ndc_to_world(ndc) - point).length() < 1e-3);
+    }
+
+    #[test]
+    fn rays_for_every_depth_range() {
+        let ranges = [
+            DepthRange::NegativeOneToOne,
+            DepthRange::ZeroToOne,
+            DepthRange::ReversedZeroToOne,
+        ];
+        for depth_range in ranges {
+            let projection = ProjectionMatrix::perspective_with_depth_range(
+                crate::Radians::FRAC_PI_2,
+                800.0 / 600.0,
+                0.1,
+                100.0,
+                depth_range,
+            )
+            .unwrap();
+            assert_ray_from_near_plane(&perspective_camera(projection, depth_range));
+        }
+    }
+
+    #[test]
+    fn rays_for_infinite_projections() {
+        let ranges = [
+            DepthRange::NegativeOneToOne,
+            DepthRange::ZeroToOne,
+            DepthRange::ReversedZeroToOne,
+        ];
+        for depth_range in ranges {
+            let projection = ProjectionMatrix::perspective_infinite(
+                crate::Radians::FRAC_PI_2,
+                800.0 / 600.0,
+                0.1,
+                depth_range,
+            )
+            .unwrap();
+            assert_ray_from_near_plane(&perspective_camera(projection, depth_range));
+        }
+    }
+}
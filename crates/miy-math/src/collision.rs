@@ -0,0 +1,647 @@
+//! Narrow-phase collision tests and raycasts between 2D shapes.
+//!
+//! Every shape is treated as a convex hull that is rounded by a radius: a circle is a point
+//! with a radius, a capsule a segment with a radius and boxes and polygons have a radius of zero.
+//! This lets every pair of shapes go through the same separating axis test.
+
+use alloc::vec::Vec;
+use core::f32::consts::TAU;
+
+use crate::{Radians, Rect, Vec2};
+
+/// Result of an overlap test between two shapes
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Contact<Space> {
+    /// Unit vector pointing from the first towards the second shape.
+    /// Moving the second shape by `normal * depth` separates them.
+    pub normal: Vec2<Space, f32>,
+    /// How far the shapes overlap along `normal`
+    pub depth: f32,
+}
+
+/// Ray starting at `origin` going towards `direction`
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Ray<Space> {
+    origin: Vec2<Space, f32>,
+    direction: Vec2<Space, f32>,
+}
+
+/// Result of a raycast
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct RayHit<Space> {
+    /// Distance along the ray
+    pub distance: f32,
+    pub point: Vec2<Space, f32>,
+    /// Surface normal of the shape at `point`
+    pub normal: Vec2<Space, f32>,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Circle<Space> {
+    pub center: Vec2<Space, f32>,
+    pub radius: f32,
+}
+
+/// All points within `radius` of the segment from `start` to `end`
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Capsule<Space> {
+    pub start: Vec2<Space, f32>,
+    pub end: Vec2<Space, f32>,
+    pub radius: f32,
+}
+
+/// Oriented box, rotated counter-clockwise by `rotation` around its center
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Obb<Space> {
+    pub center: Vec2<Space, f32>,
+    pub half_extents: Vec2<Space, f32>,
+    pub rotation: Radians,
+}
+
+/// Convex polygon with counter-clockwise winding
+#[derive(Clone, PartialEq, Debug)]
+pub struct ConvexPolygon<Space> {
+    points: Vec<Vec2<Space, f32>>,
+}
+
+/// The convex core of a shape, which is rounded by [`Shape::radius`]
+pub struct Hull<'a, Space>(HullPoints<'a, Space>);
+
+enum HullPoints<'a, Space> {
+    /// Never more than four points
+    Inline([Vec2<Space, f32>; 4], usize),
+    Borrowed(&'a [Vec2<Space, f32>]),
+}
+
+/// A convex shape that can be used in [`collide`] and [`raycast`]
+pub trait Shape<Space> {
+    /// Counter-clockwise points of the convex core
+    fn hull(&self) -> Hull<'_, Space>;
+
+    fn radius(&self) -> f32 {
+        0.0
+    }
+}
+
+impl<'a, Space> Hull<'a, Space> {
+    /// The first `len` of `points`, for shapes that don't store their points
+    pub fn inline(points: [Vec2<Space, f32>; 4], len: usize) -> Self {
+        debug_assert!(len <= 4, "inline hulls have at most four points");
+        Self(HullPoints::Inline(points, len.min(4)))
+    }
+
+    pub fn borrowed(points: &'a [Vec2<Space, f32>]) -> Self {
+        Self(HullPoints::Borrowed(points))
+    }
+
+    pub fn points(&self) -> &[Vec2<Space, f32>] {
+        match &self.0 {
+            HullPoints::Inline(points, len) => &points[..*len],
+            HullPoints::Borrowed(points) => points,
+        }
+    }
+}
+
+impl<Space> Ray<Space> {
+    /// Returns `None` if `direction` can't be normalized
+    pub fn new(origin: Vec2<Space, f32>, direction: Vec2<Space, f32>) -> Option<Self> {
+        Some(Self {
+            origin,
+            direction: direction.normalize()?,
+        })
+    }
+
+    pub fn origin(&self) -> Vec2<Space, f32> {
+        self.origin
+    }
+
+    pub fn direction(&self) -> Vec2<Space, f32> {
+        self.direction
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "a point along the ray, float vectors"
+    )]
+    pub fn at(&self, distance: f32) -> Vec2<Space, f32> {
+        self.origin + self.direction * distance
+    }
+}
+
+impl<Space> ConvexPolygon<Space> {
+    /// Returns `None` if there are less than three points or the polygon isn't strictly convex.
+    /// Clockwise polygons are reversed.
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "edge vectors between float points"
+    )]
+    pub fn new(mut points: Vec<Vec2<Space, f32>>) -> Option<Self> {
+        if points.len() < 3 {
+            return None;
+        }
+
+        let mut sign = 0.0;
+        let mut turning: f32 = 0.0;
+        for (a, b, c) in triples(&points) {
+            let (incoming, outgoing) = (b - a, c - b);
+            let turn = incoming.perp_dot(outgoing);
+            if turn == 0.0 || !turn.is_finite() || turn * sign < 0.0 {
+                return None;
+            }
+            sign = turn;
+            turning += turn.atan2(incoming.dot(outgoing));
+        }
+
+        // turning the same way at every corner also allows stars, which wind around more than once
+        if turning.abs() > TAU + 1e-3 {
+            return None;
+        }
+
+        if sign < 0.0 {
+            points.reverse();
+        }
+
+        Some(Self { points })
+    }
+
+    pub fn points(&self) -> &[Vec2<Space, f32>] {
+        &self.points
+    }
+}
+
+impl<Space> Obb<Space> {
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "rotated float half extents around the center"
+    )]
+    pub fn corners(&self) -> [Vec2<Space, f32>; 4] {
+        let (sin, cos) = self.rotation.sin_cos();
+        let x = Vec2::new(cos, sin) * self.half_extents.x;
+        let y = Vec2::new(-sin, cos) * self.half_extents.y;
+
+        [
+            self.center - x - y,
+            self.center + x - y,
+            self.center + x + y,
+            self.center - x + y,
+        ]
+    }
+}
+
+impl<Space> Shape<Space> for Circle<Space> {
+    fn hull(&self) -> Hull<'_, Space> {
+        Hull::inline([self.center; 4], 1)
+    }
+
+    fn radius(&self) -> f32 {
+        self.radius
+    }
+}
+
+impl<Space> Shape<Space> for Capsule<Space> {
+    fn hull(&self) -> Hull<'_, Space> {
+        Hull::inline([self.start, self.end, self.end, self.end], 2)
+    }
+
+    fn radius(&self) -> f32 {
+        self.radius
+    }
+}
+
+impl<Space> Shape<Space> for Obb<Space> {
+    fn hull(&self) -> Hull<'_, Space> {
+        Hull::inline(self.corners(), 4)
+    }
+}
+
+impl<Space> Shape<Space> for Rect<Space, f32> {
+    fn hull(&self) -> Hull<'_, Space> {
+        let (min, max) = (self.min(), self.max());
+        Hull::inline(
+            [min, Vec2::new(max.x, min.y), max, Vec2::new(min.x, max.y)],
+            4,
+        )
+    }
+}
+
+impl<Space> Shape<Space> for ConvexPolygon<Space> {
+    fn hull(&self) -> Hull<'_, Space> {
+        Hull::borrowed(&self.points)
+    }
+}
+
+/// Tests two shapes for overlap with the separating axis theorem.
+///
+/// Returns `None` if the shapes don't overlap, touching shapes overlap with a depth of zero
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "axes and directions between float points"
+)]
+pub fn collide<Space, A, B>(a: &A, b: &B) -> Option<Contact<Space>>
+where
+    A: Shape<Space> + ?Sized,
+    B: Shape<Space> + ?Sized,
+{
+    let (hull_a, hull_b) = (a.hull(), b.hull());
+    let (points_a, points_b) = (hull_a.points(), hull_b.points());
+    let (radius_a, radius_b) = (a.radius(), b.radius());
+
+    let mut axes = Vec::new();
+    axes.extend(edge_normals(points_a));
+    axes.extend(edge_normals(points_b));
+
+    // rounded shapes can also be separated along the axis between a point and the closest feature
+    if radius_a > 0.0 || radius_b > 0.0 {
+        for &point in points_a {
+            axes.extend((closest_point_on_hull(points_b, point) - point).normalize());
+        }
+        for &point in points_b {
+            axes.extend((closest_point_on_hull(points_a, point) - point).normalize());
+        }
+    }
+
+    if axes.is_empty() {
+        axes.push(Vec2::new(1.0, 0.0));
+    }
+
+    let mut best: Option<(Vec2<Space, f32>, f32)> = None;
+    for axis in axes {
+        let (min_a, max_a) = project(points_a, radius_a, axis);
+        let (min_b, max_b) = project(points_b, radius_b, axis);
+
+        let depth = max_a.min(max_b) - min_a.max(min_b);
+        if depth < 0.0 {
+            return None;
+        }
+
+        if best.is_none_or(|(_, best)| depth < best) {
+            best = Some((axis, depth));
+        }
+    }
+
+    let (mut normal, depth) = best?;
+    if (centroid(points_b) - centroid(points_a)).dot(normal) < 0.0 {
+        normal = -normal;
+    }
+
+    Some(Contact { normal, depth })
+}
+
+/// Returns `true` if `point` is inside or on the edge of `shape`
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "edge and offset vectors of float points"
+)]
+pub fn contains<Space, S>(shape: &S, point: Vec2<Space, f32>) -> bool
+where
+    S: Shape<Space> + ?Sized,
+{
+    let hull = shape.hull();
+    let points = hull.points();
+
+    if points.len() >= 3 && edges(points).all(|(a, b)| (b - a).perp_dot(point - a) >= 0.0) {
+        return true;
+    }
+
+    (closest_point_on_hull(points, point) - point).length() <= shape.radius()
+}
+
+/// Casts `ray` against `shape` up to `max_distance`.
+///
+/// Rays starting inside a shape don't hit it.
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "offsets edges by the float radius"
+)]
+pub fn raycast<Space, S>(ray: &Ray<Space>, max_distance: f32, shape: &S) -> Option<RayHit<Space>>
+where
+    S: Shape<Space> + ?Sized,
+{
+    if contains(shape, ray.origin) {
+        return None;
+    }
+
+    let hull = shape.hull();
+    let points = hull.points();
+    let radius = shape.radius();
+
+    let mut best: Option<(f32, Vec2<Space, f32>)> = None;
+    let mut consider = |distance: f32, normal: Vec2<Space, f32>| {
+        if (0.0..=max_distance).contains(&distance) && best.is_none_or(|(b, _)| distance < b) {
+            best = Some((distance, normal));
+        }
+    };
+
+    for (a, b) in edges(points) {
+        let Some(normal) = Vec2::new(b.y - a.y, a.x - b.x).normalize() else {
+            continue;
+        };
+
+        let offset = normal * radius;
+        if let Some(distance) = ray_segment(ray, a + offset, b + offset) {
+            consider(distance, normal);
+        }
+    }
+
+    if radius > 0.0 {
+        for &center in points {
+            if let Some(distance) = ray_circle(ray, center, radius) {
+                let normal = (ray.at(distance) - center) / radius;
+                consider(distance, normal);
+            }
+        }
+    }
+
+    let (distance, normal) = best?;
+    Some(RayHit {
+        distance,
+        point: ray.at(distance),
+        normal,
+    })
+}
+
+/// Casts the segment from `start` to `end` against `shape`
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "direction and length between the float end points"
+)]
+pub fn segment_cast<Space, S>(
+    start: Vec2<Space, f32>,
+    end: Vec2<Space, f32>,
+    shape: &S,
+) -> Option<RayHit<Space>>
+where
+    S: Shape<Space> + ?Sized,
+{
+    let ray = Ray::new(start, end - start)?;
+    raycast(&ray, (end - start).length(), shape)
+}
+
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "solves the intersection with float vectors"
+)]
+fn ray_segment<Space>(ray: &Ray<Space>, a: Vec2<Space, f32>, b: Vec2<Space, f32>) -> Option<f32> {
+    let edge = b - a;
+    let denominator = ray.direction.perp_dot(edge);
+    if denominator == 0.0 {
+        return None;
+    }
+
+    let offset = a - ray.origin;
+    let distance = offset.perp_dot(edge) / denominator;
+    let along_edge = offset.perp_dot(ray.direction) / denominator;
+
+    ((0.0..=1.0).contains(&along_edge) && distance >= 0.0).then_some(distance)
+}
+
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "solves the quadratic with float vectors"
+)]
+fn ray_circle<Space>(ray: &Ray<Space>, center: Vec2<Space, f32>, radius: f32) -> Option<f32> {
+    let offset = ray.origin - center;
+    let b = offset.dot(ray.direction);
+    let c = offset.length_squared() - radius * radius;
+    let discriminant = b * b - c;
+    if discriminant < 0.0 {
+        return None;
+    }
+
+    let distance = -b - discriminant.sqrt();
+    (distance >= 0.0).then_some(distance)
+}
+
+fn edges<Space>(
+    points: &[Vec2<Space, f32>],
+) -> impl Iterator<Item = (Vec2<Space, f32>, Vec2<Space, f32>)> + '_ {
+    let count = if points.len() >= 2 { points.len() } else { 0 };
+    points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .take(count)
+        .map(|(&a, &b)| (a, b))
+}
+
+fn triples<Space>(
+    points: &[Vec2<Space, f32>],
+) -> impl Iterator<Item = (Vec2<Space, f32>, Vec2<Space, f32>, Vec2<Space, f32>)> + '_ {
+    edges(points)
+        .zip(points.iter().cycle().skip(2))
+        .map(|((a, b), &c)| (a, b, c))
+}
+
+fn edge_normals<Space>(points: &[Vec2<Space, f32>]) -> impl Iterator<Item = Vec2<Space, f32>> + '_ {
+    edges(points).filter_map(|(a, b)| Vec2::new(b.y - a.y, a.x - b.x).normalize())
+}
+
+fn project<Space>(points: &[Vec2<Space, f32>], radius: f32, axis: Vec2<Space, f32>) -> (f32, f32) {
+    let (min, max) = points
+        .iter()
+        .fold((f32::MAX, f32::MIN), |(min, max), point| {
+            let projected = point.dot(axis);
+            (min.min(projected), max.max(projected))
+        });
+
+    (min - radius, max + radius)
+}
+
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "averages float points, the count is at least one"
+)]
+fn centroid<Space>(points: &[Vec2<Space, f32>]) -> Vec2<Space, f32> {
+    let sum = points
+        .iter()
+        .fold(Vec2::<Space, f32>::ZERO, |sum, &point| sum + point);
+    sum / points.len().max(1) as f32
+}
+
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "projects onto a float segment, a zero length edge is handled"
+)]
+fn closest_point_on_segment<Space>(
+    a: Vec2<Space, f32>,
+    b: Vec2<Space, f32>,
+    point: Vec2<Space, f32>,
+) -> Vec2<Space, f32> {
+    let edge = b - a;
+    let length_squared = edge.length_squared();
+    if length_squared == 0.0 {
+        return a;
+    }
+
+    let t = ((point - a).dot(edge) / length_squared).clamp(0.0, 1.0);
+    a + edge * t
+}
+
+/// Closest point on the outline of the hull
+#[expect(clippy::arithmetic_side_effects, reason = "compares float distances")]
+fn closest_point_on_hull<Space>(
+    points: &[Vec2<Space, f32>],
+    point: Vec2<Space, f32>,
+) -> Vec2<Space, f32> {
+    if let [single] = points {
+        return *single;
+    }
+
+    edges(points)
+        .map(|(a, b)| closest_point_on_segment(a, b, point))
+        .min_by(|a, b| {
+            (*a - point)
+                .length_squared()
+                .total_cmp(&(*b - point).length_squared())
+        })
+        .unwrap_or(point)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{WorldPosition, WorldRect, space::World};
+
+    fn approx(a: f32, b: f32) -> bool {
+        (a - b).abs() < 1e-4
+    }
+
+    #[test]
+    fn circle_and_capsule_contacts() {
+        let a = Circle::<World> {
+            center: WorldPosition::new(0.0, 0.0),
+            radius: 1.0,
+        };
+        let b = Circle {
+            center: WorldPosition::new(1.5, 0.0),
+            radius: 1.0,
+        };
+
+        let contact = collide(&a, &b).unwrap();
+        assert!(approx(contact.depth, 0.5));
+        assert_eq!(contact.normal, WorldPosition::new(1.0, 0.0));
+        assert_eq!(
+            collide(&b, &a).unwrap().normal,
+            WorldPosition::new(-1.0, 0.0)
+        );
+
+        let capsule = Capsule {
+            start: WorldPosition::new(-5.0, 1.5),
+            end: WorldPosition::new(5.0, 1.5),
+            radius: 0.75,
+        };
+        let contact = collide(&a, &capsule).unwrap();
+        assert!(approx(contact.depth, 0.25));
+        assert!((contact.normal - WorldPosition::new(0.0, 1.0)).length() < 1e-4);
+
+        let far = Circle {
+            center: WorldPosition::new(3.0, 0.0),
+            radius: 1.0,
+        };
+        assert_eq!(collide(&a, &far), None);
+    }
+
+    #[test]
+    fn polygon_contacts() {
+        let square =
+            WorldRect::from_corners(WorldPosition::new(0.0, 0.0), WorldPosition::new(2.0, 2.0));
+        let rotated = Obb {
+            center: WorldPosition::new(3.0, 1.0),
+            half_extents: WorldPosition::new(1.0, 1.0),
+            rotation: Radians::FRAC_PI_2 / 2.0,
+        };
+
+        let contact = collide(&square, &rotated).unwrap();
+        assert!(approx(contact.depth, 2.0_f32.sqrt() - 1.0));
+        assert!((contact.normal - WorldPosition::new(1.0, 0.0)).length() < 1e-4);
+
+        let triangle = ConvexPolygon::new(alloc::vec![
+            WorldPosition::new(0.0, 3.0),
+            WorldPosition::new(2.0, 3.0),
+            WorldPosition::new(1.0, 1.5),
+        ])
+        .unwrap();
+        assert_eq!(triangle.points()[1], WorldPosition::new(2.0, 3.0));
+        let contact = collide(&square, &triangle).unwrap();
+        assert!(approx(contact.depth, 0.5));
+        assert!((contact.normal - WorldPosition::new(0.0, 1.0)).length() < 1e-4);
+
+        let circle = Circle {
+            center: WorldPosition::new(2.5, 2.5),
+            radius: 0.5,
+        };
+        assert_eq!(collide(&square, &circle), None);
+
+        assert!(
+            ConvexPolygon::new(alloc::vec![
+                WorldPosition::new(0.0, 0.0),
+                WorldPosition::new(2.0, 0.0),
+                WorldPosition::new(1.0, 0.5),
+                WorldPosition::new(1.0, 2.0),
+            ])
+            .is_none()
+        );
+
+        // every corner of a pentagram turns the same way, but it winds around twice
+        let star = |step: f32| {
+            (0..5)
+                .map(|i| {
+                    let (sin, cos) = (i as f32 * step).to_radians().sin_cos();
+                    WorldPosition::new(cos, sin)
+                })
+                .collect()
+        };
+        assert!(ConvexPolygon::new(star(72.0)).is_some());
+        assert!(ConvexPolygon::new(star(144.0)).is_none());
+
+        assert!(contains(&rotated, WorldPosition::new(3.0, 2.3)));
+        assert!(!contains(&rotated, WorldPosition::new(3.9, 1.9)));
+    }
+
+    #[test]
+    fn raycasts() {
+        let ray =
+            Ray::<World>::new(WorldPosition::new(-5.0, 0.0), WorldPosition::new(1.0, 0.0)).unwrap();
+
+        let circle = Circle {
+            center: WorldPosition::new(0.0, 0.0),
+            radius: 1.0,
+        };
+        let hit = raycast(&ray, 100.0, &circle).unwrap();
+        assert!(approx(hit.distance, 4.0));
+        assert_eq!(hit.point, WorldPosition::new(-1.0, 0.0));
+        assert_eq!(hit.normal, WorldPosition::new(-1.0, 0.0));
+        assert_eq!(raycast(&ray, 3.0, &circle), None);
+
+        let square =
+            WorldRect::from_corners(WorldPosition::new(1.0, -1.0), WorldPosition::new(3.0, 3.0));
+        let hit = raycast(&ray, 100.0, &square).unwrap();
+        assert!(approx(hit.distance, 6.0));
+        assert_eq!(hit.normal, WorldPosition::new(-1.0, 0.0));
+
+        let capsule = Capsule {
+            start: WorldPosition::new(0.0, 2.0),
+            end: WorldPosition::new(0.0, 6.0),
+            radius: 1.0,
+        };
+        let hit = segment_cast(
+            WorldPosition::new(0.0, -2.0),
+            WorldPosition::new(0.0, 10.0),
+            &capsule,
+        )
+        .unwrap();
+        assert!(approx(hit.distance, 3.0));
+        assert!((hit.normal - WorldPosition::new(0.0, -1.0)).length() < 1e-4);
+
+        let hit = segment_cast(
+            WorldPosition::new(-3.0, 4.0),
+            WorldPosition::new(3.0, 4.0),
+            &capsule,
+        )
+        .unwrap();
+        assert!(approx(hit.distance, 2.0));
+        assert_eq!(hit.normal, WorldPosition::new(-1.0, 0.0));
+
+        let inside = Ray::new(WorldPosition::new(0.0, 0.0), WorldPosition::new(1.0, 0.0)).unwrap();
+        assert_eq!(raycast(&inside, 100.0, &circle), None);
+    }
+}
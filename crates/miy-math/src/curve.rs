@@ -0,0 +1,630 @@
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Sub};
+
+use crate::{Aabb, Rect, Vec2, Vec3};
+
+/// Points a curve can be built from, implemented for 2D and 3D float vectors of every space
+pub trait CurvePoint:
+    Copy + Add<Output = Self> + Sub<Output = Self> + Mul<f32, Output = Self>
+{
+    /// [`Rect`] for 2D points, [`Aabb`] for 3D points
+    type Bounds;
+
+    fn length(self) -> f32;
+
+    fn axes(self) -> impl Iterator<Item = f32>;
+
+    /// Smallest bounds containing all `points`, `None` if there are none
+    fn bounds(points: impl IntoIterator<Item = Self>) -> Option<Self::Bounds>;
+}
+
+impl<Space> CurvePoint for Vec2<Space, f32> {
+    type Bounds = Rect<Space, f32>;
+
+    fn length(self) -> f32 {
+        Vec2::<Space, f32>::length(self)
+    }
+
+    fn axes(self) -> impl Iterator<Item = f32> {
+        self.to_array().into_iter()
+    }
+
+    fn bounds(points: impl IntoIterator<Item = Self>) -> Option<Self::Bounds> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(
+            points.fold(Rect::from_corners(first, first), |rect, point| {
+                rect.include(point)
+            }),
+        )
+    }
+}
+
+impl<Space> CurvePoint for Vec3<Space, f32> {
+    type Bounds = Aabb<Space>;
+
+    fn length(self) -> f32 {
+        Vec3::<Space, f32>::length(self)
+    }
+
+    fn axes(self) -> impl Iterator<Item = f32> {
+        self.to_array().into_iter()
+    }
+
+    fn bounds(points: impl IntoIterator<Item = Self>) -> Option<Self::Bounds> {
+        Aabb::from_points(points)
+    }
+}
+
+/// A parametric curve over `[0, domain]`
+pub trait Curve<P: CurvePoint> {
+    /// The end of the parameter range, the start is always zero
+    fn domain(&self) -> f32;
+
+    fn point(&self, t: f32) -> P;
+
+    /// Tangent at `t`, its length is the speed along the curve
+    fn derivative(&self, t: f32) -> P;
+
+    fn bounds(&self) -> P::Bounds;
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct QuadraticBezier<P> {
+    pub start: P,
+    pub control: P,
+    pub end: P,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct CubicBezier<P> {
+    pub start: P,
+    pub control_start: P,
+    pub control_end: P,
+    pub end: P,
+}
+
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "interpolating between two curve points is plain float vector math"
+)]
+fn lerp<P: CurvePoint>(a: P, b: P, t: f32) -> P {
+    a + (b - a) * t
+}
+
+/// Parameters within `(0, 1)` where `a·t² + b·t + c` has a root
+fn quadratic_roots(a: f32, b: f32, c: f32) -> impl Iterator<Item = f32> {
+    let roots = if a.abs() < 1e-12 {
+        [(b.abs() > 1e-12).then(|| -c / b), None]
+    } else {
+        let discriminant = b * b - 4.0 * a * c;
+        if discriminant < 0.0 {
+            [None, None]
+        } else {
+            let root = discriminant.sqrt();
+            [Some((-b + root) / (2.0 * a)), Some((-b - root) / (2.0 * a))]
+        }
+    };
+    roots.into_iter().flatten().filter(|t| *t > 0.0 && *t < 1.0)
+}
+
+impl<P: CurvePoint> QuadraticBezier<P> {
+    pub fn new(start: P, control: P, end: P) -> Self {
+        Self {
+            start,
+            control,
+            end,
+        }
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "the second difference of three finite control points stays finite"
+    )]
+    pub fn second_derivative(&self) -> P {
+        (self.end - self.control * 2.0 + self.start) * 2.0
+    }
+
+    /// Splits the curve at `t` into two curves that trace the same path
+    pub fn split(&self, t: f32) -> (Self, Self) {
+        let a = lerp(self.start, self.control, t);
+        let b = lerp(self.control, self.end, t);
+        let middle = lerp(a, b, t);
+        (
+            Self::new(self.start, a, middle),
+            Self::new(middle, b, self.end),
+        )
+    }
+
+    /// The same curve, raised to a cubic
+    pub fn to_cubic(&self) -> CubicBezier<P> {
+        CubicBezier::new(
+            self.start,
+            lerp(self.start, self.control, 2.0 / 3.0),
+            lerp(self.end, self.control, 2.0 / 3.0),
+            self.end,
+        )
+    }
+}
+
+impl<P: CurvePoint> Curve<P> for QuadraticBezier<P> {
+    fn domain(&self) -> f32 {
+        1.0
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "the quadratic weights sum to one, the point stays within the three control points"
+    )]
+    fn point(&self, t: f32) -> P {
+        let u = 1.0 - t;
+        self.start * (u * u) + self.control * (2.0 * u * t) + self.end * (t * t)
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "the derivative blends the two control point differences with float weights"
+    )]
+    fn derivative(&self, t: f32) -> P {
+        ((self.control - self.start) * (1.0 - t) + (self.end - self.control) * t) * 2.0
+    }
+
+    /// Exact bounds, the control point only counts where the curve bends towards it
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "the control point differences are plain float vector subtractions"
+    )]
+    fn bounds(&self) -> P::Bounds {
+        // the derivative is linear, each axis has at most one extreme
+        let a = self.control - self.start;
+        let b = self.end - self.control;
+        let extremes = a
+            .axes()
+            .zip(b.axes())
+            .flat_map(|(a, b)| quadratic_roots(0.0, b - a, a))
+            .map(|t| self.point(t));
+
+        let points = [self.start, self.end].into_iter().chain(extremes);
+        P::bounds(points).unwrap_or_else(|| unreachable!("the endpoints are always present"))
+    }
+}
+
+impl<P: CurvePoint> CubicBezier<P> {
+    pub fn new(start: P, control_start: P, control_end: P, end: P) -> Self {
+        Self {
+            start,
+            control_start,
+            control_end,
+            end,
+        }
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "the second derivative blends two second differences of the control points"
+    )]
+    pub fn second_derivative(&self, t: f32) -> P {
+        let a = self.control_end - self.control_start * 2.0 + self.start;
+        let b = self.end - self.control_end * 2.0 + self.control_start;
+        (a * (1.0 - t) + b * t) * 6.0
+    }
+
+    /// Splits the curve at `t` into two curves that trace the same path
+    pub fn split(&self, t: f32) -> (Self, Self) {
+        let a = lerp(self.start, self.control_start, t);
+        let b = lerp(self.control_start, self.control_end, t);
+        let c = lerp(self.control_end, self.end, t);
+        let ab = lerp(a, b, t);
+        let bc = lerp(b, c, t);
+        let middle = lerp(ab, bc, t);
+        (
+            Self::new(self.start, a, ab, middle),
+            Self::new(middle, bc, c, self.end),
+        )
+    }
+
+    /// Points where the curve turns around on some axis
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "the derivative coefficients are differences of neighbouring control points"
+    )]
+    fn extremes(self) -> impl Iterator<Item = P> {
+        // the derivative divided by 3 is `a·t² + b·t + c` on every axis
+        let p = self.control_start - self.start;
+        let q = self.control_end - self.control_start;
+        let r = self.end - self.control_end;
+        p.axes()
+            .zip(q.axes())
+            .zip(r.axes())
+            .flat_map(|((p, q), r)| quadratic_roots(p - 2.0 * q + r, 2.0 * (q - p), p))
+            .map(move |t| self.point(t))
+    }
+
+    /// Splits the curve into `count` pieces of equal parameter range, empty if `count` is zero
+    pub fn subdivide(&self, count: usize) -> Vec<Self> {
+        let mut pieces = Vec::with_capacity(count);
+        let mut rest = *self;
+        for remaining in (2..=count).rev() {
+            // each split happens on what is left, so the parameter is relative to the rest
+            let t = 1.0 / remaining as f32;
+            let (piece, remaining) = rest.split(t);
+            pieces.push(piece);
+            rest = remaining;
+        }
+        if count > 0 {
+            pieces.push(rest);
+        }
+        pieces
+    }
+}
+
+impl<P: CurvePoint> Curve<P> for CubicBezier<P> {
+    fn domain(&self) -> f32 {
+        1.0
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "the cubic weights sum to one, the point stays within the four control points"
+    )]
+    fn point(&self, t: f32) -> P {
+        let u = 1.0 - t;
+        self.start * (u * u * u)
+            + self.control_start * (3.0 * u * u * t)
+            + self.control_end * (3.0 * u * t * t)
+            + self.end * (t * t * t)
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "the derivative blends the three control point differences with float weights"
+    )]
+    fn derivative(&self, t: f32) -> P {
+        let u = 1.0 - t;
+        ((self.control_start - self.start) * (u * u)
+            + (self.control_end - self.control_start) * (2.0 * u * t)
+            + (self.end - self.control_end) * (t * t))
+            * 3.0
+    }
+
+    /// Exact bounds, the control points only count where the curve bends towards them
+    fn bounds(&self) -> P::Bounds {
+        let points = [self.start, self.end].into_iter().chain(self.extremes());
+        P::bounds(points).unwrap_or_else(|| unreachable!("the endpoints are always present"))
+    }
+}
+
+/// Maps a spline parameter to the segment it falls into and the parameter within it
+fn segment_at(t: f32, segments: usize) -> (usize, f32) {
+    let last = segments.saturating_sub(1);
+    let t = t.clamp(0.0, segments as f32);
+    let index = (t.floor() as usize).min(last);
+    (index, t - index as f32)
+}
+
+fn spline_bounds<P: CurvePoint>(segments: impl Iterator<Item = CubicBezier<P>>) -> P::Bounds {
+    let points = segments.flat_map(|segment| {
+        [segment.start, segment.end]
+            .into_iter()
+            .chain(segment.extremes())
+    });
+    P::bounds(points).unwrap_or_else(|| unreachable!("splines have at least one segment"))
+}
+
+/// Uniform Catmull-Rom spline passing through every point
+///
+/// The parameter runs from `0.0` at the first point to `segments()` at the last one,
+/// every whole number lands on a point. The ends are extrapolated by repeating the outer points.
+#[derive(Clone, PartialEq, Debug)]
+pub struct CatmullRom<P> {
+    points: Vec<P>,
+}
+
+impl<P: CurvePoint> CatmullRom<P> {
+    /// Returns `None` if there are less than two points
+    pub fn new(points: Vec<P>) -> Option<Self> {
+        (points.len() >= 2).then_some(Self { points })
+    }
+
+    pub fn points(&self) -> &[P] {
+        &self.points
+    }
+
+    pub fn segments(&self) -> usize {
+        self.points.len().saturating_sub(1)
+    }
+
+    /// The segment between point `index` and `index + 1` as an identical cubic Bezier curve,
+    /// `None` if there is no such segment
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "the inner control points are offset by a sixth of the neighbouring chords"
+    )]
+    pub fn segment(&self, index: usize) -> Option<CubicBezier<P>> {
+        let point = |i: usize| self.points[i.min(self.segments())];
+        let [p1, p2] = *self.points.get(index..)?.first_chunk()?;
+        let p0 = point(index.saturating_sub(1));
+        let p3 = point(index.saturating_add(2));
+        Some(CubicBezier::new(
+            p1,
+            p1 + (p2 - p0) * (1.0 / 6.0),
+            p2 - (p3 - p1) * (1.0 / 6.0),
+            p2,
+        ))
+    }
+
+    /// Every segment as cubic Bezier curves
+    pub fn to_beziers(&self) -> Vec<CubicBezier<P>> {
+        (0..self.segments())
+            .filter_map(|i| self.segment(i))
+            .collect()
+    }
+
+    fn segment_for(&self, t: f32) -> (CubicBezier<P>, f32) {
+        let (index, t) = segment_at(t, self.segments());
+        let segment = self
+            .segment(index)
+            .unwrap_or_else(|| unreachable!("`segment_at` stays within the segments"));
+        (segment, t)
+    }
+}
+
+impl<P: CurvePoint> Curve<P> for CatmullRom<P> {
+    fn domain(&self) -> f32 {
+        self.segments() as f32
+    }
+
+    fn point(&self, t: f32) -> P {
+        let (segment, t) = self.segment_for(t);
+        segment.point(t)
+    }
+
+    fn derivative(&self, t: f32) -> P {
+        let (segment, t) = self.segment_for(t);
+        segment.derivative(t)
+    }
+
+    fn bounds(&self) -> P::Bounds {
+        spline_bounds((0..self.segments()).filter_map(|i| self.segment(i)))
+    }
+}
+
+/// Uniform cubic B-spline, smoother than [`CatmullRom`] but it doesn't pass through its points
+///
+/// The parameter runs from `0.0` to `segments()`, one segment per four consecutive points.
+#[derive(Clone, PartialEq, Debug)]
+pub struct BSpline<P> {
+    points: Vec<P>,
+}
+
+impl<P: CurvePoint> BSpline<P> {
+    /// Returns `None` if there are less than four points
+    pub fn new(points: Vec<P>) -> Option<Self> {
+        (points.len() >= 4).then_some(Self { points })
+    }
+
+    pub fn points(&self) -> &[P] {
+        &self.points
+    }
+
+    pub fn segments(&self) -> usize {
+        self.points.len().saturating_sub(3)
+    }
+
+    /// Segment `index` as an identical cubic Bezier curve, `None` if there is no such segment
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "the B-spline to Bezier basis change only takes float weighted sums of four points"
+    )]
+    pub fn segment(&self, index: usize) -> Option<CubicBezier<P>> {
+        let [p0, p1, p2, p3] = *self.points.get(index..)?.first_chunk()?;
+        Some(CubicBezier::new(
+            (p0 + p1 * 4.0 + p2) * (1.0 / 6.0),
+            (p1 * 2.0 + p2) * (1.0 / 3.0),
+            (p1 + p2 * 2.0) * (1.0 / 3.0),
+            (p1 + p2 * 4.0 + p3) * (1.0 / 6.0),
+        ))
+    }
+
+    /// Every segment as cubic Bezier curves
+    pub fn to_beziers(&self) -> Vec<CubicBezier<P>> {
+        (0..self.segments())
+            .filter_map(|i| self.segment(i))
+            .collect()
+    }
+
+    fn segment_for(&self, t: f32) -> (CubicBezier<P>, f32) {
+        let (index, t) = segment_at(t, self.segments());
+        let segment = self
+            .segment(index)
+            .unwrap_or_else(|| unreachable!("`segment_at` stays within the segments"));
+        (segment, t)
+    }
+}
+
+impl<P: CurvePoint> Curve<P> for BSpline<P> {
+    fn domain(&self) -> f32 {
+        self.segments() as f32
+    }
+
+    fn point(&self, t: f32) -> P {
+        let (segment, t) = self.segment_for(t);
+        segment.point(t)
+    }
+
+    fn derivative(&self, t: f32) -> P {
+        let (segment, t) = self.segment_for(t);
+        segment.derivative(t)
+    }
+
+    fn bounds(&self) -> P::Bounds {
+        spline_bounds((0..self.segments()).filter_map(|i| self.segment(i)))
+    }
+}
+
+/// Cumulative lengths along a curve, for moving along it at constant speed
+///
+/// The curve is approximated by straight lines between `samples` evenly spaced parameters,
+/// more samples trade memory for accuracy on tight bends.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ArcLengthTable {
+    domain: f32,
+    lengths: Vec<f32>,
+}
+
+impl ArcLengthTable {
+    /// Returns `None` if `samples` is zero
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "chord lengths are differences of consecutive finite samples"
+    )]
+    pub fn new<P: CurvePoint>(curve: &impl Curve<P>, samples: usize) -> Option<Self> {
+        if samples == 0 {
+            return None;
+        }
+
+        let domain = curve.domain();
+        let step = domain / samples as f32;
+        let mut lengths = Vec::with_capacity(samples.saturating_add(1));
+        let mut previous = curve.point(0.0);
+        let mut total = 0.0;
+        lengths.push(total);
+        for i in 1..=samples {
+            let point = curve.point(step * i as f32);
+            total += (point - previous).length();
+            lengths.push(total);
+            previous = point;
+        }
+
+        Some(Self { domain, lengths })
+    }
+
+    /// Total length of the curve
+    pub fn length(&self) -> f32 {
+        self.lengths.last().copied().unwrap_or(0.0)
+    }
+
+    /// The curve parameter `distance` along the curve, clamped to the ends
+    pub fn parameter(&self, distance: f32) -> f32 {
+        let distance = distance.clamp(0.0, self.length());
+        let samples = self.lengths.len().saturating_sub(1);
+
+        // index of the first sample at or beyond `distance`
+        let next = self
+            .lengths
+            .partition_point(|length| *length < distance)
+            .clamp(1, samples);
+        let (start, end) = (self.lengths[next.saturating_sub(1)], self.lengths[next]);
+        let within = if end > start {
+            (distance - start) / (end - start)
+        } else {
+            0.0
+        };
+
+        (next as f32 - 1.0 + within) * self.domain / samples as f32
+    }
+
+    /// The point `distance` along the curve, clamped to the ends
+    pub fn point<P: CurvePoint>(&self, curve: &impl Curve<P>, distance: f32) -> P {
+        curve.point(self.parameter(distance))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{WorldPosition, WorldRect, WorldVec3};
+
+    fn close(a: WorldPosition, b: WorldPosition) -> bool {
+        a.distance(b) < 1e-4
+    }
+
+    #[test]
+    fn bezier_evaluation_and_split() {
+        let curve = CubicBezier::new(
+            WorldPosition::new(0.0, 0.0),
+            WorldPosition::new(0.0, 2.0),
+            WorldPosition::new(2.0, 2.0),
+            WorldPosition::new(2.0, 0.0),
+        );
+        assert!(close(curve.point(0.5), WorldPosition::new(1.0, 1.5)));
+        assert!(close(curve.derivative(0.5), WorldPosition::new(3.0, 0.0)));
+        assert!(close(
+            curve.second_derivative(0.0),
+            WorldPosition::new(12.0, -12.0)
+        ));
+
+        let (left, right) = curve.split(0.25);
+        assert!(close(left.point(1.0), curve.point(0.25)));
+        assert!(close(right.point(0.5), curve.point(0.625)));
+
+        let pieces = curve.subdivide(4);
+        assert_eq!(pieces.len(), 4);
+        assert!(close(pieces[2].point(0.0), curve.point(0.5)));
+        assert!(close(pieces[3].end, curve.end));
+
+        let bounds = curve.bounds();
+        let expected =
+            WorldRect::new(WorldPosition::new(0.0, 0.0), WorldPosition::new(2.0, 1.5)).unwrap();
+        assert!(close(bounds.min(), expected.min()) && close(bounds.max(), expected.max()));
+
+        let quadratic = QuadraticBezier::new(
+            WorldPosition::new(0.0, 0.0),
+            WorldPosition::new(1.0, 2.0),
+            WorldPosition::new(2.0, 0.0),
+        );
+        assert!(close(
+            quadratic.bounds().max(),
+            WorldPosition::new(2.0, 1.0)
+        ));
+        let cubic = quadratic.to_cubic();
+        for t in [0.1, 0.5, 0.8] {
+            assert!(close(quadratic.point(t), cubic.point(t)));
+        }
+    }
+
+    #[test]
+    fn splines_match_their_bezier_segments() {
+        let points = vec![
+            WorldVec3::new(0.0, 0.0, 0.0),
+            WorldVec3::new(1.0, 2.0, 0.0),
+            WorldVec3::new(3.0, 2.0, 1.0),
+            WorldVec3::new(4.0, 0.0, -1.0),
+        ];
+
+        let catmull_rom = CatmullRom::new(points.clone()).unwrap();
+        assert_eq!(catmull_rom.domain(), 3.0);
+        for (i, point) in points.iter().enumerate() {
+            assert!((catmull_rom.point(i as f32) - *point).length() < 1e-5);
+        }
+        let bounds = catmull_rom.bounds();
+        assert!(bounds.contains(points[2]) && bounds.max().z >= 1.0);
+
+        let b_spline = BSpline::new(points.clone()).unwrap();
+        assert_eq!(b_spline.segments(), 1);
+        let start = (points[0] + points[1] * 4.0 + points[2]) * (1.0 / 6.0);
+        assert!((b_spline.point(0.0) - start).length() < 1e-5);
+        assert!(BSpline::new(points[..3].to_vec()).is_none());
+        assert!(CatmullRom::new(points[..1].to_vec()).is_none());
+    }
+
+    #[test]
+    fn constant_speed_lookup() {
+        // a quarter of the way along a straight but unevenly parameterized curve
+        let curve = CubicBezier::new(
+            WorldPosition::new(0.0, 0.0),
+            WorldPosition::new(0.1, 0.0),
+            WorldPosition::new(0.2, 0.0),
+            WorldPosition::new(4.0, 0.0),
+        );
+        let table = ArcLengthTable::new(&curve, 256).unwrap();
+        assert!((table.length() - 4.0).abs() < 1e-4);
+        assert!(close(
+            table.point(&curve, 1.0),
+            WorldPosition::new(1.0, 0.0)
+        ));
+        assert!(close(table.point(&curve, 10.0), curve.end));
+        assert_eq!(table.parameter(0.0), 0.0);
+        assert!(ArcLengthTable::new(&curve, 0).is_none());
+    }
+}
@@ -1,6 +1,6 @@
 use core::marker::PhantomData;
 
-use crate::space;
+use crate::{Fixed, space};
 
 mod tag {
     #[derive(Copy, Clone, PartialEq, Eq, Debug)]
@@ -69,6 +69,19 @@ impl<Space, Tag> Dimension<Space, Tag, f32> {
     }
 }
 
+impl<Space, Tag> Dimension<Space, Tag, Fixed> {
+    pub const fn new(value: Fixed) -> Option<Self> {
+        if value.to_bits() > 0 {
+            Some(Self {
+                value,
+                phantom: PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+}
+
 impl<Space, Kind> Dimensions2D<Space, Kind> {
     pub fn width(self) -> Width<Space, Kind> {
         self.width
@@ -96,6 +109,20 @@ impl<Space> Dimensions2D<Space, u32> {
     }
 }
 
+impl<Space> Dimensions2D<Space, Fixed> {
+    pub fn new(width: Fixed, height: Fixed) -> Option<Self> {
+        Some(Self {
+            width: Width::<Space, Fixed>::new(width)?,
+            height: Height::<Space, Fixed>::new(height)?,
+        })
+    }
+
+    /// For rendering, the simulation should stay in fixed-point
+    pub fn to_f32(self) -> Option<Dimensions2D<Space, f32>> {
+        Dimensions2D::<Space, f32>::new(self.width.get().to_f32(), self.height.get().to_f32())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
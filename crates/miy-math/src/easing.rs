@@ -0,0 +1,193 @@
+use core::f32::consts::{FRAC_PI_2, PI};
+
+/// Robert Penner's easing functions, mapping linear progress in `[0, 1]` to eased progress.
+///
+/// `Back` and `Elastic` overshoot, so their output leaves `[0, 1]`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum Easing {
+    #[default]
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    QuartIn,
+    QuartOut,
+    QuartInOut,
+    QuintIn,
+    QuintOut,
+    QuintInOut,
+    SineIn,
+    SineOut,
+    SineInOut,
+    ExpoIn,
+    ExpoOut,
+    ExpoInOut,
+    CircIn,
+    CircOut,
+    CircInOut,
+    BackIn,
+    BackOut,
+    BackInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+}
+
+const BACK: f32 = 1.70158;
+const BACK_IN_OUT: f32 = BACK * 1.525;
+const ELASTIC: f32 = 2.0 * PI / 3.0;
+const ELASTIC_IN_OUT: f32 = 2.0 * PI / 4.5;
+
+impl Easing {
+    /// Eases `t`, which is clamped to `[0, 1]`
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Easing::QuadInOut => in_out(t, |t| t * t),
+            Easing::CubicIn => t.powi(3),
+            Easing::CubicOut => 1.0 - (1.0 - t).powi(3),
+            Easing::CubicInOut => in_out(t, |t| t.powi(3)),
+            Easing::QuartIn => t.powi(4),
+            Easing::QuartOut => 1.0 - (1.0 - t).powi(4),
+            Easing::QuartInOut => in_out(t, |t| t.powi(4)),
+            Easing::QuintIn => t.powi(5),
+            Easing::QuintOut => 1.0 - (1.0 - t).powi(5),
+            Easing::QuintInOut => in_out(t, |t| t.powi(5)),
+            Easing::SineIn => 1.0 - (t * FRAC_PI_2).cos(),
+            Easing::SineOut => (t * FRAC_PI_2).sin(),
+            Easing::SineInOut => -((PI * t).cos() - 1.0) / 2.0,
+            Easing::ExpoIn => expo_in(t),
+            Easing::ExpoOut => 1.0 - expo_in(1.0 - t),
+            Easing::ExpoInOut => in_out(t, expo_in),
+            Easing::CircIn => circ_in(t),
+            Easing::CircOut => 1.0 - circ_in(1.0 - t),
+            Easing::CircInOut => in_out(t, circ_in),
+            Easing::BackIn => back_in(t, BACK),
+            Easing::BackOut => 1.0 - back_in(1.0 - t, BACK),
+            Easing::BackInOut => in_out(t, |t| back_in(t, BACK_IN_OUT)),
+            Easing::ElasticIn => elastic_in(t, ELASTIC),
+            Easing::ElasticOut => 1.0 - elastic_in(1.0 - t, ELASTIC),
+            Easing::ElasticInOut => in_out(t, |t| elastic_in(t, ELASTIC_IN_OUT)),
+            Easing::BounceIn => 1.0 - bounce_out(1.0 - t),
+            Easing::BounceOut => bounce_out(t),
+            Easing::BounceInOut => in_out(t, |t| 1.0 - bounce_out(1.0 - t)),
+        }
+    }
+}
+
+/// Plays `ease_in` on the first half and its mirror image on the second half
+fn in_out(t: f32, ease_in: impl Fn(f32) -> f32) -> f32 {
+    if t < 0.5 {
+        ease_in(t * 2.0) / 2.0
+    } else {
+        1.0 - ease_in((1.0 - t) * 2.0) / 2.0
+    }
+}
+
+fn expo_in(t: f32) -> f32 {
+    if t == 0.0 {
+        0.0
+    } else {
+        2.0_f32.powf(10.0 * t - 10.0)
+    }
+}
+
+fn circ_in(t: f32) -> f32 {
+    1.0 - (1.0 - t * t).sqrt()
+}
+
+fn back_in(t: f32, overshoot: f32) -> f32 {
+    (overshoot + 1.0) * t.powi(3) - overshoot * t * t
+}
+
+fn elastic_in(t: f32, period: f32) -> f32 {
+    if t == 0.0 || t == 1.0 {
+        t
+    } else {
+        -(2.0_f32.powf(10.0 * t - 10.0)) * ((t * 10.0 - 10.75) * period).sin()
+    }
+}
+
+fn bounce_out(t: f32) -> f32 {
+    const N: f32 = 7.5625;
+    const D: f32 = 2.75;
+
+    if t < 1.0 / D {
+        N * t * t
+    } else if t < 2.0 / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984375
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ALL: [Easing; 31] = [
+        Easing::Linear,
+        Easing::QuadIn,
+        Easing::QuadOut,
+        Easing::QuadInOut,
+        Easing::CubicIn,
+        Easing::CubicOut,
+        Easing::CubicInOut,
+        Easing::QuartIn,
+        Easing::QuartOut,
+        Easing::QuartInOut,
+        Easing::QuintIn,
+        Easing::QuintOut,
+        Easing::QuintInOut,
+        Easing::SineIn,
+        Easing::SineOut,
+        Easing::SineInOut,
+        Easing::ExpoIn,
+        Easing::ExpoOut,
+        Easing::ExpoInOut,
+        Easing::CircIn,
+        Easing::CircOut,
+        Easing::CircInOut,
+        Easing::BackIn,
+        Easing::BackOut,
+        Easing::BackInOut,
+        Easing::ElasticIn,
+        Easing::ElasticOut,
+        Easing::ElasticInOut,
+        Easing::BounceIn,
+        Easing::BounceOut,
+        Easing::BounceInOut,
+    ];
+
+    #[test]
+    fn easing_endpoints() {
+        for easing in ALL {
+            assert!(easing.apply(0.0).abs() < 1e-3, "{easing:?}");
+            assert!((easing.apply(1.0) - 1.0).abs() < 1e-3, "{easing:?}");
+        }
+
+        // reference values from easings.net
+        assert_eq!(Easing::QuadIn.apply(0.5), 0.25);
+        assert_eq!(Easing::CubicOut.apply(0.5), 0.875);
+        assert_eq!(Easing::QuartInOut.apply(0.25), 0.03125);
+        assert!((Easing::BackIn.apply(0.5) + 0.0876975).abs() < 1e-5);
+        assert!((Easing::BounceOut.apply(0.5) - 0.765625).abs() < 1e-5);
+        assert!((Easing::ExpoOut.apply(0.5) - 0.96875).abs() < 1e-5);
+        assert!((Easing::SineInOut.apply(0.5) - 0.5).abs() < 1e-5);
+    }
+}
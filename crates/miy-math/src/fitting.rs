@@ -0,0 +1,236 @@
+use crate::{
+    Camera, Orthographic, OrthographicAnchor, ProjectionMatrix, ScreenDimensions, Viewport,
+    WorldDimensions2D,
+};
+
+/// How fixed world dimensions are fitted into a surface of arbitrary size
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum FitMode {
+    /// Shows the whole world across the whole surface, distorting the aspect ratio
+    Stretch,
+    /// Shows the whole world as large as possible with bars on the sides (letterbox/pillarbox)
+    Fit,
+    /// Covers the whole surface, cropping the parts of the world that don't fit
+    Fill,
+    /// Like [`FitMode::Fit`], but only scales by whole numbers so one world unit
+    /// is always the same amount of pixels. Falls back to [`FitMode::Fit`] if the surface is
+    /// smaller than the world.
+    PixelPerfect,
+}
+
+/// Viewport and projection of a [`WorldDimensions2D`] fitted into a surface.
+///
+/// Call [`ViewportFit::resize`] whenever the surface changes size,
+/// e.g. together with `Context::resize_surface`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct ViewportFit {
+    world: WorldDimensions2D,
+    world_orthographic: Orthographic,
+    mode: FitMode,
+    surface: ScreenDimensions,
+    viewport: Viewport,
+    orthographic: Orthographic,
+}
+
+impl ViewportFit {
+    /// Returns `None` if the world dimensions are not finite
+    pub fn new(
+        world: WorldDimensions2D,
+        anchor: OrthographicAnchor,
+        mode: FitMode,
+        surface: ScreenDimensions,
+    ) -> Option<Self> {
+        let world_orthographic = Orthographic::from_anchor(world, anchor)?;
+        Some(Self::fitted(world, world_orthographic, mode, surface))
+    }
+
+    fn fitted(
+        world: WorldDimensions2D,
+        world_orthographic: Orthographic,
+        mode: FitMode,
+        surface: ScreenDimensions,
+    ) -> Self {
+        let (viewport, orthographic) = fit(world, world_orthographic, mode, surface);
+
+        Self {
+            world,
+            world_orthographic,
+            mode,
+            surface,
+            viewport,
+            orthographic,
+        }
+    }
+
+    pub fn resize(&mut self, surface: ScreenDimensions) {
+        if self.surface == surface {
+            return;
+        }
+
+        *self = Self::fitted(self.world, self.world_orthographic, self.mode, surface);
+    }
+
+    pub fn set_mode(&mut self, mode: FitMode) {
+        *self = Self::fitted(self.world, self.world_orthographic, mode, self.surface);
+    }
+
+    pub fn mode(&self) -> FitMode {
+        self.mode
+    }
+
+    pub fn surface(&self) -> ScreenDimensions {
+        self.surface
+    }
+
+    /// The part of the surface that is rendered into, in screen space
+    pub fn viewport(&self) -> Viewport {
+        self.viewport
+    }
+
+    /// The `x`, `y`, `width` and `height` arguments for `glViewport`,
+    /// which has its origin in the bottom left corner of the surface
+    pub fn gl_viewport(&self) -> (i32, i32, i32, i32) {
+        let (x, y) = self.viewport.offset();
+        let dimensions = self.viewport.dimensions();
+        let width = dimensions.width().get();
+        let height = dimensions.height().get();
+
+        let gl_y = self
+            .surface
+            .height()
+            .get()
+            .saturating_sub(y)
+            .saturating_sub(height);
+
+        (x as i32, gl_y as i32, width as i32, height as i32)
+    }
+
+    /// The visible part of the world
+    pub fn orthographic(&self) -> Orthographic {
+        self.orthographic
+    }
+
+    pub fn projection(&self) -> ProjectionMatrix {
+        self.orthographic.projection()
+    }
+
+    /// Camera for converting cursor positions into the world and back
+    pub fn camera(&self) -> Camera {
+        Camera::new(self.viewport, self.projection())
+            .expect("orthographic projections are invertible")
+    }
+}
+
+fn fit(
+    world: WorldDimensions2D,
+    world_ortho: Orthographic,
+    mode: FitMode,
+    surface: ScreenDimensions,
+) -> (Viewport, Orthographic) {
+    let whole_surface = Viewport::new(surface);
+
+    let surface_width = surface.width().get() as f32;
+    let surface_height = surface.height().get() as f32;
+    let scale_x = surface_width / world.width().get();
+    let scale_y = surface_height / world.height().get();
+
+    let letterbox = |scale: f32| {
+        let width = ((world.width().get() * scale).round() as u32).clamp(1, surface.width().get());
+        let height =
+            ((world.height().get() * scale).round() as u32).clamp(1, surface.height().get());
+
+        let x = surface.width().get().saturating_sub(width) / 2;
+        let y = surface.height().get().saturating_sub(height) / 2;
+
+        let dimensions = ScreenDimensions::new(width, height).expect("clamped to at least 1");
+        Viewport::new(dimensions).with_offset(x, y)
+    };
+
+    match mode {
+        FitMode::Stretch => (whole_surface, world_ortho),
+        FitMode::Fit => (letterbox(scale_x.min(scale_y)), world_ortho),
+        FitMode::PixelPerfect => {
+            let scale = scale_x.min(scale_y);
+            let scale = if scale >= 1.0 { scale.floor() } else { scale };
+            (letterbox(scale), world_ortho)
+        }
+        FitMode::Fill => {
+            let scale = scale_x.max(scale_y);
+            let visible_x = surface_width / scale / world.width().get();
+            let visible_y = surface_height / scale / world.height().get();
+
+            let center_x = (world_ortho.left() + world_ortho.right()) / 2.0;
+            let center_y = (world_ortho.bottom() + world_ortho.top()) / 2.0;
+            let half_width = (world_ortho.right() - world_ortho.left()) / 2.0 * visible_x;
+            let half_height = (world_ortho.top() - world_ortho.bottom()) / 2.0 * visible_y;
+
+            let cropped = Orthographic::new(
+                center_x - half_width,
+                center_x + half_width,
+                center_y - half_height,
+                center_y + half_height,
+            )
+            .expect("visible part of the world is never empty");
+
+            (whole_surface, cropped)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ScreenPosition, WorldPosition};
+
+    #[test]
+    fn fit_modes() {
+        let world = WorldDimensions2D::new(320.0, 180.0).unwrap();
+        let surface = ScreenDimensions::new(1280, 800).unwrap();
+        let anchor = OrthographicAnchor::Center;
+
+        let stretch = ViewportFit::new(world, anchor, FitMode::Stretch, surface).unwrap();
+        assert_eq!(stretch.gl_viewport(), (0, 0, 1280, 800));
+
+        let mut letterbox = ViewportFit::new(world, anchor, FitMode::Fit, surface).unwrap();
+        assert_eq!(letterbox.gl_viewport(), (0, 40, 1280, 720));
+
+        letterbox.resize(ScreenDimensions::new(1000, 1000).unwrap());
+        assert_eq!(letterbox.gl_viewport(), (0, 219, 1000, 563));
+
+        let pixel_perfect = ViewportFit::new(
+            world,
+            anchor,
+            FitMode::PixelPerfect,
+            ScreenDimensions::new(1000, 700).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(pixel_perfect.viewport().offset(), (20, 80));
+        assert_eq!(pixel_perfect.gl_viewport(), (20, 80, 960, 540));
+
+        let fill = ViewportFit::new(world, anchor, FitMode::Fill, surface).unwrap();
+        assert_eq!(fill.gl_viewport(), (0, 0, 1280, 800));
+        assert_eq!(fill.orthographic().left(), -144.0);
+        assert!((fill.orthographic().top() - 90.0).abs() < 1e-4);
+
+        let endless = WorldDimensions2D::new(f32::INFINITY, 180.0).unwrap();
+        assert_eq!(
+            ViewportFit::new(endless, anchor, FitMode::Fit, surface),
+            None
+        );
+    }
+
+    #[test]
+    fn fit_picking() {
+        let world = WorldDimensions2D::new(320.0, 180.0).unwrap();
+        let surface = ScreenDimensions::new(1280, 800).unwrap();
+        let fit =
+            ViewportFit::new(world, OrthographicAnchor::TopLeft, FitMode::Fit, surface).unwrap();
+
+        let camera = fit.camera();
+        let top_left = camera.screen_to_world(ScreenPosition::new(0.0, 40.0));
+        assert_eq!(top_left, WorldPosition::new(0.0, 0.0));
+
+        let bottom_right = camera.screen_to_world(ScreenPosition::new(1280.0, 760.0));
+        assert_eq!(bottom_right, WorldPosition::new(320.0, 180.0));
+    }
+}
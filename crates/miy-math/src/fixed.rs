@@ -0,0 +1,490 @@
+use core::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::Vec2;
+
+/// Q16.16 fixed-point number for simulations that have to run bit-identically everywhere
+///
+/// Every operation is integer arithmetic, including the [`Fixed::sqrt`] and trigonometry
+/// approximations. Operators saturate at [`Fixed::MIN`] and [`Fixed::MAX`] instead of wrapping,
+/// dividing by zero saturates towards the sign of the dividend.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Fixed(i32);
+
+impl Fixed {
+    /// Bits after the binary point
+    pub const FRAC_BITS: u32 = 16;
+
+    pub const ZERO: Self = Self(0);
+    pub const ONE: Self = Self(1 << Self::FRAC_BITS);
+    pub const HALF: Self = Self(1 << (Self::FRAC_BITS - 1));
+    pub const MIN: Self = Self(i32::MIN);
+    pub const MAX: Self = Self(i32::MAX);
+    /// Smallest positive value, `1 / 65536`
+    pub const EPSILON: Self = Self(1);
+
+    pub const FRAC_PI_2: Self = Self(102_944);
+    pub const PI: Self = Self(205_887);
+    pub const TAU: Self = Self(411_775);
+
+    pub const fn from_bits(bits: i32) -> Self {
+        Self(bits)
+    }
+
+    pub const fn to_bits(self) -> i32 {
+        self.0
+    }
+
+    /// Returns `None` if `value` is outside of `[-32768, 32767]`
+    pub const fn from_int(value: i32) -> Option<Self> {
+        match value.checked_mul(1 << Self::FRAC_BITS) {
+            Some(bits) => Some(Self(bits)),
+            None => None,
+        }
+    }
+
+    /// Rounds to the closest representable value, returns `None` if `value` is out of range
+    /// or not finite
+    pub fn from_f32(value: f32) -> Option<Self> {
+        Self::from_f64(f64::from(value))
+    }
+
+    /// Rounds to the closest representable value, returns `None` if `value` is out of range
+    /// or not finite
+    pub fn from_f64(value: f64) -> Option<Self> {
+        let bits = (value * f64::from(Self::ONE.0)).round();
+        if bits >= f64::from(i32::MIN) && bits <= f64::from(i32::MAX) {
+            Some(Self(bits as i32))
+        } else {
+            None
+        }
+    }
+
+    pub fn to_f32(self) -> f32 {
+        self.to_f64() as f32
+    }
+
+    /// Exact, every fixed-point value is representable as `f64`
+    pub fn to_f64(self) -> f64 {
+        f64::from(self.0) / f64::from(Self::ONE.0)
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Self)
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        i32::try_from(mul_bits(i64::from(self.0), i64::from(other.0)))
+            .ok()
+            .map(Self)
+    }
+
+    /// Returns `None` if `other` is zero or the result is out of range
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        let quotient = div_bits(self.0, other.0)?;
+        i32::try_from(quotient).ok().map(Self)
+    }
+
+    pub fn abs(self) -> Self {
+        Self(self.0.saturating_abs())
+    }
+
+    pub fn signum(self) -> Self {
+        match self.0.cmp(&0) {
+            Ordering::Less => -Self::ONE,
+            Ordering::Equal => Self::ZERO,
+            Ordering::Greater => Self::ONE,
+        }
+    }
+
+    pub fn floor(self) -> Self {
+        Self(self.0 & !(Self::ONE.0 - 1))
+    }
+
+    pub fn ceil(self) -> Self {
+        Self(self.0.saturating_add(Self::ONE.0 - 1)).floor()
+    }
+
+    /// Rounds half-way cases up
+    pub fn round(self) -> Self {
+        Self(self.0.saturating_add(Self::HALF.0)).floor()
+    }
+
+    /// Always positive, `self - self.floor()`
+    pub fn fract(self) -> Self {
+        Self(self.0 & (Self::ONE.0 - 1))
+    }
+
+    /// Linear interpolation, `t = 0` returns `self`, `t = 1` returns `other`
+    #[expect(clippy::arithmetic_side_effects, reason = "Fixed operators saturate")]
+    pub fn lerp(self, other: Self, t: Self) -> Self {
+        self + (other - self) * t
+    }
+
+    /// Rounded down to the closest representable value, `None` for negative values
+    pub fn sqrt(self) -> Option<Self> {
+        let bits = u64::try_from(self.0).ok()?;
+        let root = (bits << Self::FRAC_BITS).isqrt();
+        i32::try_from(root).ok().map(Self)
+    }
+
+    /// Sine of an angle in radians, accurate to about `1e-5`
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "the angle is reduced to [-π/2, π/2] first, the series stays far from the i64 limits"
+    )]
+    pub fn sin(self) -> Self {
+        let (pi, frac_pi_2) = (i64::from(Self::PI.0), i64::from(Self::FRAC_PI_2.0));
+
+        // reduce to [-π, π] and fold into [-π/2, π/2], where the series converges quickly
+        let mut x = i64::from(self.0).rem_euclid(i64::from(Self::TAU.0));
+        if x > pi {
+            x -= i64::from(Self::TAU.0);
+        }
+        if x > frac_pi_2 {
+            x = pi - x;
+        } else if x < -frac_pi_2 {
+            x = -pi - x;
+        }
+
+        // Taylor series up to x⁹ in Horner form
+        let one = i64::from(Self::ONE.0);
+        let x2 = mul_bits(x, x);
+        let mut series = one - x2 / 72;
+        series = one - mul_bits(x2, series) / 42;
+        series = one - mul_bits(x2, series) / 20;
+        series = one - mul_bits(x2, series) / 6;
+        Self(mul_bits(x, series) as i32)
+    }
+
+    /// Cosine of an angle in radians, accurate to about `1e-5`
+    pub fn cos(self) -> Self {
+        // reduce first, a quarter turn past angles close to `MAX` would leave the range
+        let angle = self.0.rem_euclid(Self::TAU.0);
+        Self(angle.saturating_add(Self::FRAC_PI_2.0)).sin()
+    }
+
+    pub fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+
+    /// Angle of the point `(x, y)` in `[-π, π]`, accurate to about `2e-5`.
+    /// Returns zero for the origin.
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "ratios are within [0, 1] and angles within [-π, π], both tiny next to i64"
+    )]
+    pub fn atan2(self, x: Self) -> Self {
+        let y = self;
+        let (ax, ay) = (i64::from(x.0).abs(), i64::from(y.0).abs());
+        if ax == 0 && ay == 0 {
+            return Self::ZERO;
+        }
+
+        // the polynomial only holds within [0, 1], larger ratios use atan(z) = π/2 - atan(1/z)
+        let mut angle = if ax >= ay {
+            atan_bits((ay << Self::FRAC_BITS) / ax)
+        } else {
+            i64::from(Self::FRAC_PI_2.0) - atan_bits((ax << Self::FRAC_BITS) / ay)
+        };
+        if x.0 < 0 {
+            angle = i64::from(Self::PI.0) - angle;
+        }
+        if y.0 < 0 {
+            angle = -angle;
+        }
+        Self(angle as i32)
+    }
+}
+
+/// Product of two Q16.16 values held in an `i64`, rounded to nearest
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "both factors come from i32, so the product and rounding bias fit in i64"
+)]
+fn mul_bits(a: i64, b: i64) -> i64 {
+    (a * b + (1 << (Fixed::FRAC_BITS - 1))) >> Fixed::FRAC_BITS
+}
+
+/// Quotient of two Q16.16 values held in an `i64`, `None` if `b` is zero
+fn div_bits(a: i32, b: i32) -> Option<i64> {
+    (i64::from(a) << Fixed::FRAC_BITS).checked_div(i64::from(b))
+}
+
+/// Minimax polynomial for `atan(z)` with `z` within `[0, 1]`
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "z is within [0, 1], every partial sum stays small"
+)]
+fn atan_bits(z: i64) -> i64 {
+    // 0.9998660, -0.3302995, 0.1801410, -0.0851330, 0.0208351 in Q16.16
+    const COEFFICIENTS: [i64; 5] = [1365, -5579, 11806, -21646, 65527];
+
+    let z2 = mul_bits(z, z);
+    let series = COEFFICIENTS
+        .into_iter()
+        .reduce(|acc, coefficient| coefficient + mul_bits(z2, acc))
+        .unwrap_or(0);
+    mul_bits(z, series)
+}
+
+fn saturate(bits: i64) -> Fixed {
+    Fixed(i32::try_from(bits).unwrap_or(if bits < 0 { i32::MIN } else { i32::MAX }))
+}
+
+impl From<i16> for Fixed {
+    fn from(value: i16) -> Self {
+        Self(i32::from(value) << Fixed::FRAC_BITS)
+    }
+}
+
+impl From<Fixed> for f32 {
+    fn from(value: Fixed) -> Self {
+        value.to_f32()
+    }
+}
+
+impl From<Fixed> for f64 {
+    fn from(value: Fixed) -> Self {
+        value.to_f64()
+    }
+}
+
+impl fmt::Debug for Fixed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_f64(), f)
+    }
+}
+
+impl fmt::Display for Fixed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f64(), f)
+    }
+}
+
+impl Add for Fixed {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0))
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        saturate(mul_bits(i64::from(self.0), i64::from(other.0)))
+    }
+}
+
+impl Div for Fixed {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        match div_bits(self.0, other.0) {
+            Some(quotient) => saturate(quotient),
+            None => saturate(i64::from(self.0.signum()).saturating_mul(i64::MAX)),
+        }
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.saturating_neg())
+    }
+}
+
+impl AddAssign for Fixed {
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "forwards to the saturating `Add`"
+    )]
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Fixed {
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "forwards to the saturating `Sub`"
+    )]
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for Fixed {
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "forwards to the saturating `Mul`"
+    )]
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign for Fixed {
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "forwards to the saturating `Div`"
+    )]
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl<Space> Vec2<Space, Fixed> {
+    pub const ZERO: Self = Self::new(Fixed::ZERO, Fixed::ZERO);
+
+    /// Returns `None` if a component is out of range or not finite
+    pub fn from_f32(v: Vec2<Space, f32>) -> Option<Self> {
+        Some(Self::new(Fixed::from_f32(v.x)?, Fixed::from_f32(v.y)?))
+    }
+
+    /// For rendering, the simulation should stay in fixed-point
+    pub fn to_f32(self) -> Vec2<Space, f32> {
+        self.map(Fixed::to_f32)
+    }
+
+    pub fn length_squared(self) -> Fixed {
+        self.dot(self)
+    }
+
+    /// Saturates for vectors longer than [`Fixed::MAX`]
+    pub fn length(self) -> Fixed {
+        let x = u64::from(self.x.0.unsigned_abs());
+        let y = u64::from(self.y.0.unsigned_abs());
+        // squares in a u64 so lengths above 181 don't saturate like `length_squared` does
+        let squared = x.saturating_mul(x).saturating_add(y.saturating_mul(y));
+        saturate(squared.isqrt() as i64)
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "the `Sub` for Fixed saturates"
+    )]
+    pub fn distance(self, other: Self) -> Fixed {
+        (other - self).length()
+    }
+
+    /// Returns `None` if the vector has a length of zero
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "the length was checked to be positive and `Div` saturates"
+    )]
+    pub fn normalize(self) -> Option<Self> {
+        let length = self.length();
+        if length > Fixed::ZERO {
+            Some(Self::new(self.x / length, self.y / length))
+        } else {
+            None
+        }
+    }
+
+    /// Linear interpolation, `t = 0` returns `self`, `t = 1` returns `other`
+    pub fn lerp(self, other: Self, t: Fixed) -> Self {
+        Self::new(self.x.lerp(other.x, t), self.y.lerp(other.y, t))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Dimensions2D, WorldDimensions2D, WorldPosition, space};
+
+    fn fixed(value: f32) -> Fixed {
+        Fixed::from_f32(value).unwrap()
+    }
+
+    #[test]
+    fn fixed_arithmetic() {
+        assert_eq!(fixed(1.5) + fixed(2.25), fixed(3.75));
+        assert_eq!(fixed(1.5) * fixed(-2.0), fixed(-3.0));
+        assert_eq!(fixed(7.0) / fixed(2.0), fixed(3.5));
+        assert_eq!(fixed(-1.25).floor(), fixed(-2.0));
+        assert_eq!(fixed(-1.25).fract(), fixed(0.75));
+        assert_eq!(fixed(2.5).round(), fixed(3.0));
+        assert_eq!(fixed(2.25).ceil(), fixed(3.0));
+
+        assert_eq!(Fixed::MAX + Fixed::ONE, Fixed::MAX);
+        assert_eq!(Fixed::MIN * fixed(2.0), Fixed::MIN);
+        assert_eq!(fixed(3.0) / Fixed::ZERO, Fixed::MAX);
+        assert_eq!(fixed(-3.0) / Fixed::ZERO, Fixed::MIN);
+        assert_eq!(Fixed::ONE.checked_div(Fixed::ZERO), None);
+        assert_eq!(Fixed::from_int(40_000), None);
+        assert_eq!(Fixed::from_f32(f32::NAN), None);
+        assert_eq!(Fixed::from(-3_i16).to_f32(), -3.0);
+    }
+
+    #[test]
+    fn fixed_approximations() {
+        assert_eq!(fixed(16.0).sqrt(), Some(fixed(4.0)));
+        assert!((fixed(2.0).sqrt().unwrap().to_f32() - 2.0_f32.sqrt()).abs() < 2e-5);
+        assert_eq!(fixed(-1.0).sqrt(), None);
+
+        for i in -100..100 {
+            let angle = i as f32 * 0.1;
+            let (sin, cos) = fixed(angle).sin_cos();
+            assert!((sin.to_f32() - angle.sin()).abs() < 1e-4, "sin {angle}");
+            assert!((cos.to_f32() - angle.cos()).abs() < 1e-4, "cos {angle}");
+
+            let (x, y) = (angle.cos() * 3.0, angle.sin() * 3.0);
+            let atan = fixed(y).atan2(fixed(x)).to_f32();
+            assert!((atan - y.atan2(x)).abs() < 1e-4, "atan2 {angle}");
+        }
+
+        // bit-exact results, these must not change between platforms or releases
+        assert_eq!(fixed(1.0).sin().to_bits(), 55_147);
+        assert_eq!(fixed(0.5).cos().to_bits(), 57_513);
+        assert_eq!(fixed(1.0).atan2(fixed(2.0)).to_bits(), 30_386);
+
+        // the quarter turn is added after reducing, so angles near the limits don't wrap
+        for angle in [Fixed::MAX, Fixed::MIN, Fixed::MAX - fixed(1.0)] {
+            let reduced = Fixed::from_bits(angle.to_bits().rem_euclid(Fixed::TAU.to_bits()));
+            assert_eq!(angle.cos(), reduced.cos());
+            assert!((reduced.cos().to_f64() - reduced.to_f64().cos()).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn fixed_positions_and_dimensions() {
+        let a = WorldPosition::<Fixed>::new(fixed(1.0), fixed(2.0));
+        let b = WorldPosition::<Fixed>::new(fixed(4.0), fixed(6.0));
+        assert_eq!(a.distance(b), fixed(5.0));
+        assert_eq!(a + b, WorldPosition::<Fixed>::new(fixed(5.0), fixed(8.0)));
+        assert_eq!(
+            a.lerp(b, Fixed::HALF).to_f32(),
+            WorldPosition::new(2.5, 4.0)
+        );
+        assert_eq!(
+            WorldPosition::<Fixed>::new(fixed(300.0), fixed(400.0)).length(),
+            fixed(500.0)
+        );
+
+        let dimensions = Dimensions2D::<space::World, Fixed>::new(fixed(2.0), fixed(0.5));
+        assert_eq!(
+            dimensions.unwrap().to_f32(),
+            WorldDimensions2D::new(2.0, 0.5)
+        );
+        assert!(Dimensions2D::<space::World, Fixed>::new(Fixed::ZERO, fixed(1.0)).is_none());
+    }
+}
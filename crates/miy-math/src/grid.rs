@@ -0,0 +1,695 @@
+//! Square and hexagonal tile coordinates and their mapping to positions.
+//!
+//! Coordinates are plain cell indices, a [`SquareGrid`] or [`HexGrid`] places them in a space
+//! with a cell size given as [`Dimensions2D`]. Hexagons use axial coordinates, the third cube
+//! coordinate is derived where needed. See <https://www.redblobgames.com/grids/hexagons/>.
+
+use alloc::vec::Vec;
+
+use crate::{Dimensions2D, Rect, Vec2};
+
+const SQRT_3: f32 = 1.732_050_8;
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct SquareCoord {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Which cells count as adjacent on a square grid
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Neighborhood {
+    /// Orthogonal neighbours only
+    Four,
+    /// Orthogonal and diagonal neighbours
+    Eight,
+}
+
+impl SquareCoord {
+    pub const ORTHOGONAL: [Self; 4] = [
+        Self::new(1, 0),
+        Self::new(0, 1),
+        Self::new(-1, 0),
+        Self::new(0, -1),
+    ];
+    pub const DIAGONAL: [Self; 4] = [
+        Self::new(1, 1),
+        Self::new(-1, 1),
+        Self::new(-1, -1),
+        Self::new(1, -1),
+    ];
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "offsetting past the `i32` range is a caller bug, debug builds catch it"
+    )]
+    pub fn offset(self, dx: i32, dy: i32) -> Self {
+        Self::new(self.x + dx, self.y + dy)
+    }
+
+    pub fn neighbors(self, neighborhood: Neighborhood) -> impl Iterator<Item = Self> {
+        let diagonal: &[Self] = match neighborhood {
+            Neighborhood::Four => &[],
+            Neighborhood::Eight => &Self::DIAGONAL,
+        };
+        Self::ORTHOGONAL
+            .iter()
+            .chain(diagonal)
+            .map(move |direction| self.offset(direction.x, direction.y))
+    }
+
+    /// Steps needed with [`Neighborhood::Four`]
+    pub fn manhattan_distance(self, other: Self) -> u32 {
+        self.x
+            .abs_diff(other.x)
+            .saturating_add(self.y.abs_diff(other.y))
+    }
+
+    /// Steps needed with [`Neighborhood::Eight`]
+    pub fn chebyshev_distance(self, other: Self) -> u32 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Bresenham line from `self` to `other`, both included, every step moves to one of the
+    /// [`Neighborhood::Eight`] neighbours
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "the doubled error term overflows only for lines longer than `i32::MAX / 2` cells"
+    )]
+    pub fn line(self, other: Self) -> Vec<Self> {
+        let (dx, dy) = ((other.x - self.x).abs(), -(other.y - self.y).abs());
+        let (step_x, step_y) = ((other.x - self.x).signum(), (other.y - self.y).signum());
+
+        let mut cells = Vec::with_capacity((dx.max(-dy) as usize).saturating_add(1));
+        let mut current = self;
+        let mut error = dx + dy;
+        loop {
+            cells.push(current);
+            if current == other {
+                return cells;
+            }
+            let doubled = 2 * error;
+            if doubled >= dy {
+                error += dy;
+                current.x += step_x;
+            }
+            if doubled <= dx {
+                error += dx;
+                current.y += step_y;
+            }
+        }
+    }
+
+    /// Cells at a [`SquareCoord::chebyshev_distance`] of exactly `radius`, counter-clockwise.
+    /// Empty if `radius` doesn't fit in an `i32`.
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "ring corners lie `radius` cells from the center, a radius checked to fit in `i32`"
+    )]
+    pub fn ring(self, radius: u32) -> Vec<Self> {
+        if radius == 0 {
+            return alloc::vec![self];
+        }
+
+        let Ok(radius) = i32::try_from(radius) else {
+            return Vec::new();
+        };
+        let mut cells = Vec::with_capacity((radius as usize).saturating_mul(8));
+        let mut current = self.offset(-radius, -radius);
+        for direction in Self::ORTHOGONAL {
+            for _ in 0..2 * radius {
+                cells.push(current);
+                current = current.offset(direction.x, direction.y);
+            }
+        }
+        cells
+    }
+
+    /// Cells visible from `self` within a euclidean `radius`, using recursive shadowcasting.
+    /// Opaque cells are visible themselves but hide what lies behind them. Sorted, `self` included.
+    pub fn field_of_view(self, radius: u32, opaque: impl Fn(Self) -> bool) -> Vec<Self> {
+        // maps the first octant onto the other seven, (xx, xy, yx, yy)
+        const OCTANTS: [[i32; 4]; 8] = [
+            [1, 0, 0, 1],
+            [0, 1, 1, 0],
+            [0, -1, 1, 0],
+            [-1, 0, 0, 1],
+            [-1, 0, 0, -1],
+            [0, -1, -1, 0],
+            [0, 1, -1, 0],
+            [1, 0, 0, -1],
+        ];
+
+        let mut visible = alloc::vec![self];
+        let shadowcast = Shadowcast {
+            origin: self,
+            radius: i32::try_from(radius).unwrap_or(i32::MAX),
+            radius_squared: u64::from(radius).pow(2),
+            opaque: &opaque,
+        };
+        for octant in OCTANTS {
+            shadowcast.scan(1, 1.0, 0.0, octant, &mut visible);
+        }
+
+        // cells on the borders between octants are found twice
+        visible.sort();
+        visible.dedup();
+        visible
+    }
+}
+
+struct Shadowcast<'a, F> {
+    origin: SquareCoord,
+    radius: i32,
+    radius_squared: u64,
+    opaque: &'a F,
+}
+
+impl<F: Fn(SquareCoord) -> bool> Shadowcast<'_, F> {
+    /// Scans rows outwards, `start` and `end` are the slopes of the visible wedge
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "offsets are bounded by the radius, squared distances are summed in `u64`"
+    )]
+    fn scan(
+        &self,
+        row: i32,
+        mut start: f32,
+        end: f32,
+        [xx, xy, yx, yy]: [i32; 4],
+        visible: &mut Vec<SquareCoord>,
+    ) {
+        if start < end {
+            return;
+        }
+
+        let mut next_start = start;
+        for distance in row..=self.radius {
+            let dy = -distance;
+            let mut blocked = false;
+            for dx in -distance..=0 {
+                let left = (dx as f32 - 0.5) / (dy as f32 + 0.5);
+                let right = (dx as f32 + 0.5) / (dy as f32 - 0.5);
+                if start < right {
+                    continue;
+                }
+                if end > left {
+                    break;
+                }
+
+                let cell = self.origin.offset(dx * xx + dy * xy, dx * yx + dy * yy);
+                let (dx_squared, dy_squared) = (
+                    u64::from(dx.unsigned_abs()).pow(2),
+                    u64::from(dy.unsigned_abs()).pow(2),
+                );
+                if dx_squared + dy_squared <= self.radius_squared {
+                    visible.push(cell);
+                }
+
+                let opaque = (self.opaque)(cell);
+                if blocked {
+                    if opaque {
+                        next_start = right;
+                    } else {
+                        blocked = false;
+                        start = next_start;
+                    }
+                } else if opaque && distance < self.radius {
+                    blocked = true;
+                    self.scan(distance + 1, start, left, [xx, xy, yx, yy], visible);
+                    next_start = right;
+                }
+            }
+            if blocked {
+                break;
+            }
+        }
+    }
+}
+
+/// Places square cells of `cell` size in a space, cell `(0, 0)` has its minimum corner at `origin`
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SquareGrid<Space> {
+    cell: Dimensions2D<Space, f32>,
+    origin: Vec2<Space, f32>,
+}
+
+impl<Space: Copy> SquareGrid<Space> {
+    pub fn new(cell: Dimensions2D<Space, f32>) -> Self {
+        Self {
+            cell,
+            origin: Vec2::<Space, f32>::ZERO,
+        }
+    }
+
+    pub fn with_origin(self, origin: Vec2<Space, f32>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn cell(&self) -> Dimensions2D<Space, f32> {
+        self.cell
+    }
+
+    pub fn origin(&self) -> Vec2<Space, f32> {
+        self.origin
+    }
+
+    fn size(&self) -> Vec2<Space, f32> {
+        Vec2::new(self.cell.width().get(), self.cell.height().get())
+    }
+
+    /// The cell containing `position`, positions on an edge belong to the cell above or right
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "the position relative to the grid origin is a plain float difference"
+    )]
+    pub fn to_cell(&self, position: Vec2<Space, f32>) -> SquareCoord {
+        let local = position - self.origin;
+        SquareCoord::new(
+            (local.x / self.cell.width().get()).floor() as i32,
+            (local.y / self.cell.height().get()).floor() as i32,
+        )
+    }
+
+    /// Center of `cell`
+    pub fn to_position(&self, cell: SquareCoord) -> Vec2<Space, f32> {
+        self.cell_rect(cell).center()
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "cell corners are the cell index scaled by the finite cell size"
+    )]
+    pub fn cell_rect(&self, cell: SquareCoord) -> Rect<Space, f32> {
+        let min = self.origin + Vec2::new(cell.x as f32, cell.y as f32).mul_elements(self.size());
+        Rect::from_corners(min, min + self.size())
+    }
+}
+
+/// Axial hex coordinates, `q + r + s = 0` with `s` derived
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct HexCoord {
+    pub q: i32,
+    pub r: i32,
+}
+
+impl HexCoord {
+    /// Counter-clockwise like [`HexGrid::corners`], starting east for pointy tops and north-east
+    /// for flat tops
+    pub const DIRECTIONS: [Self; 6] = [
+        Self::new(1, 0),
+        Self::new(0, 1),
+        Self::new(-1, 1),
+        Self::new(-1, 0),
+        Self::new(0, -1),
+        Self::new(1, -1),
+    ];
+
+    pub const fn new(q: i32, r: i32) -> Self {
+        Self { q, r }
+    }
+
+    /// Returns `None` unless `q + r + s == 0`
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "three `i32` widened to `i64` can not overflow when summed"
+    )]
+    pub fn from_cube(q: i32, r: i32, s: i32) -> Option<Self> {
+        (i64::from(q) + i64::from(r) + i64::from(s) == 0).then_some(Self::new(q, r))
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "`s` leaves the `i32` range only if the hex has no cube representation"
+    )]
+    pub fn s(self) -> i32 {
+        -self.q - self.r
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "neighbours are a single step away, only the outermost hexes overflow"
+    )]
+    pub fn neighbors(self) -> impl Iterator<Item = Self> {
+        Self::DIRECTIONS
+            .into_iter()
+            .map(move |direction| self + direction)
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "the difference overflows only for hexes more than `i32::MAX` apart"
+    )]
+    pub fn distance(self, other: Self) -> u32 {
+        let difference = self - other;
+        difference
+            .q
+            .unsigned_abs()
+            .max(difference.r.unsigned_abs())
+            .max(difference.s().unsigned_abs())
+    }
+
+    /// Rounds fractional cube coordinates to the containing hex
+    pub fn round(q: f32, r: f32) -> Self {
+        let s = -q - r;
+        let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+        let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+        if dq > dr && dq > ds {
+            rq = -rr - rs;
+        } else if dr > ds {
+            rr = -rq - rs;
+        }
+        Self::new(rq as i32, rr as i32)
+    }
+
+    /// Hexes on the straight line from `self` to `other`, both included
+    pub fn line(self, other: Self) -> Vec<Self> {
+        let steps = self.distance(other);
+        if steps == 0 {
+            return alloc::vec![self];
+        }
+
+        // nudged off the exact edges, so ties between two hexes always round the same way
+        let (aq, ar) = (self.q as f32 + 1e-6, self.r as f32 + 2e-6);
+        let (bq, br) = (other.q as f32 + 1e-6, other.r as f32 + 2e-6);
+        (0..=steps)
+            .map(|step| {
+                let t = step as f32 / steps as f32;
+                Self::round(aq + (bq - aq) * t, ar + (br - ar) * t)
+            })
+            .collect()
+    }
+
+    /// Hexes at a distance of exactly `radius`, counter-clockwise. Empty if `radius` doesn't fit
+    /// in an `i32`.
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "the ring starts `radius` steps from the center along one direction"
+    )]
+    pub fn ring(self, radius: u32) -> Vec<Self> {
+        if radius == 0 {
+            return alloc::vec![self];
+        }
+
+        let Ok(radius) = i32::try_from(radius) else {
+            return Vec::new();
+        };
+        let mut cells = Vec::with_capacity((radius as usize).saturating_mul(6));
+        let mut current = self + Self::DIRECTIONS[4] * radius;
+        for direction in Self::DIRECTIONS {
+            for _ in 0..radius {
+                cells.push(current);
+                current = current + direction;
+            }
+        }
+        cells
+    }
+
+    /// Every hex within `radius`, ring by ring starting with `self`
+    pub fn spiral(self, radius: u32) -> Vec<Self> {
+        (0..=radius).flat_map(|ring| self.ring(ring)).collect()
+    }
+
+    /// Hexes within `radius` with an unobstructed [`HexCoord::line`] from `self`.
+    /// Opaque hexes are visible themselves but hide what lies behind them. Sorted, `self` included.
+    pub fn field_of_view(self, radius: u32, opaque: impl Fn(Self) -> bool) -> Vec<Self> {
+        let mut visible: Vec<Self> = self
+            .spiral(radius)
+            .into_iter()
+            .filter(|target| {
+                let line = self.line(*target);
+                let between = &line[1..line.len().saturating_sub(1).max(1)];
+                !between.iter().any(|cell| opaque(*cell))
+            })
+            .collect();
+        visible.sort();
+        visible
+    }
+}
+
+impl core::ops::Add for HexCoord {
+    type Output = Self;
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "component wise sum, overflowing the axial range is a caller bug"
+    )]
+    fn add(self, other: Self) -> Self {
+        Self::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl core::ops::Sub for HexCoord {
+    type Output = Self;
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "component wise difference, overflowing the axial range is a caller bug"
+    )]
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl core::ops::Mul<i32> for HexCoord {
+    type Output = Self;
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "scaling a direction by a ring radius, overflowing the axial range is a caller bug"
+    )]
+    fn mul(self, factor: i32) -> Self {
+        Self::new(self.q * factor, self.r * factor)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum HexOrientation {
+    /// Rows of hexes with a corner at the top
+    #[default]
+    Pointy,
+    /// Columns of hexes with an edge at the top
+    Flat,
+}
+
+/// Places hexes in a space, hex `(0, 0)` is centered on `origin`
+///
+/// `cell` is the bounding box of one hex, so hexes can be squashed to match tile art.
+/// For regular hexagons a pointy hex is `√3` times its radius wide and two radii high.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct HexGrid<Space> {
+    orientation: HexOrientation,
+    cell: Dimensions2D<Space, f32>,
+    origin: Vec2<Space, f32>,
+}
+
+impl<Space: Copy> HexGrid<Space> {
+    pub fn new(orientation: HexOrientation, cell: Dimensions2D<Space, f32>) -> Self {
+        Self {
+            orientation,
+            cell,
+            origin: Vec2::<Space, f32>::ZERO,
+        }
+    }
+
+    pub fn with_origin(self, origin: Vec2<Space, f32>) -> Self {
+        Self { origin, ..self }
+    }
+
+    pub fn orientation(&self) -> HexOrientation {
+        self.orientation
+    }
+
+    pub fn cell(&self) -> Dimensions2D<Space, f32> {
+        self.cell
+    }
+
+    /// Radius of the hex along each axis
+    fn radius(&self) -> (f32, f32) {
+        let (width, height) = (self.cell.width().get(), self.cell.height().get());
+        match self.orientation {
+            HexOrientation::Pointy => (width / SQRT_3, height / 2.0),
+            HexOrientation::Flat => (width / 2.0, height / SQRT_3),
+        }
+    }
+
+    /// Center of `hex`
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "hex centers are float combinations of the axial coordinates and the radius"
+    )]
+    pub fn to_position(&self, hex: HexCoord) -> Vec2<Space, f32> {
+        let (rx, ry) = self.radius();
+        let (q, r) = (hex.q as f32, hex.r as f32);
+        let (x, y) = match self.orientation {
+            HexOrientation::Pointy => (SQRT_3 * q + SQRT_3 / 2.0 * r, 1.5 * r),
+            HexOrientation::Flat => (1.5 * q, SQRT_3 / 2.0 * q + SQRT_3 * r),
+        };
+        self.origin + Vec2::new(x * rx, y * ry)
+    }
+
+    /// The hex containing `position`
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "undoing `to_position` subtracts the origin and divides by the finite radius"
+    )]
+    pub fn to_cell(&self, position: Vec2<Space, f32>) -> HexCoord {
+        let (rx, ry) = self.radius();
+        let local = position - self.origin;
+        let (x, y) = (local.x / rx, local.y / ry);
+        let (q, r) = match self.orientation {
+            HexOrientation::Pointy => (SQRT_3 / 3.0 * x - y / 3.0, 2.0 / 3.0 * y),
+            HexOrientation::Flat => (2.0 / 3.0 * x, -x / 3.0 + SQRT_3 / 3.0 * y),
+        };
+        HexCoord::round(q, r)
+    }
+
+    /// The six corners of `hex`, counter-clockwise
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "corners are the center offset by the unit corners scaled by the radius"
+    )]
+    pub fn corners(&self, hex: HexCoord) -> [Vec2<Space, f32>; 6] {
+        let center = self.to_position(hex);
+        let (rx, ry) = self.radius();
+        // unit corners, pointy hexes start at the lower right corner and flat ones at the right
+        let unit: [(f32, f32); 6] = match self.orientation {
+            HexOrientation::Pointy => [
+                (SQRT_3 / 2.0, -0.5),
+                (SQRT_3 / 2.0, 0.5),
+                (0.0, 1.0),
+                (-SQRT_3 / 2.0, 0.5),
+                (-SQRT_3 / 2.0, -0.5),
+                (0.0, -1.0),
+            ],
+            HexOrientation::Flat => [
+                (1.0, 0.0),
+                (0.5, SQRT_3 / 2.0),
+                (-0.5, SQRT_3 / 2.0),
+                (-1.0, 0.0),
+                (-0.5, -SQRT_3 / 2.0),
+                (0.5, -SQRT_3 / 2.0),
+            ],
+        };
+        unit.map(|(x, y)| center + Vec2::new(x * rx, y * ry))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{WorldDimensions2D, WorldPosition};
+
+    #[test]
+    fn square_coordinates() {
+        let a = SquareCoord::new(0, 0);
+        let b = SquareCoord::new(5, -2);
+        assert_eq!(a.manhattan_distance(b), 7);
+        assert_eq!(a.chebyshev_distance(b), 5);
+        assert_eq!(a.neighbors(Neighborhood::Four).count(), 4);
+        assert_eq!(a.neighbors(Neighborhood::Eight).count(), 8);
+
+        let line = a.line(b);
+        assert_eq!((line.len(), line[0], line[5]), (6, a, b));
+        assert!(line.windows(2).all(|w| w[0].chebyshev_distance(w[1]) == 1));
+
+        let ring = a.ring(2);
+        assert_eq!(ring.len(), 16);
+        assert!(ring.iter().all(|cell| a.chebyshev_distance(*cell) == 2));
+
+        let grid = SquareGrid::new(WorldDimensions2D::new(2.0, 1.0).unwrap())
+            .with_origin(WorldPosition::new(-1.0, 0.0));
+        assert_eq!(
+            grid.to_cell(WorldPosition::new(2.5, -0.5)),
+            SquareCoord::new(1, -1)
+        );
+        assert_eq!(
+            grid.to_position(SquareCoord::new(1, -1)),
+            WorldPosition::new(2.0, -0.5)
+        );
+    }
+
+    #[test]
+    fn square_field_of_view() {
+        // a wall at x = 2 from y = -1 to 1
+        let wall = |cell: SquareCoord| cell.x == 2 && (-1..=1).contains(&cell.y);
+        let visible = SquareCoord::new(0, 0).field_of_view(5, wall);
+
+        assert!(visible.contains(&SquareCoord::new(0, 0)));
+        assert!(visible.contains(&SquareCoord::new(2, 0)));
+        assert!(!visible.contains(&SquareCoord::new(4, 0)));
+        assert!(visible.contains(&SquareCoord::new(-5, 0)));
+        assert!(!visible.contains(&SquareCoord::new(-5, -5)));
+
+        // the squared radius is past `i32::MAX`, walls right around the center end the scan
+        let origin = SquareCoord::new(0, 0);
+        let enclosed = origin.field_of_view(50_000, |cell| cell != origin);
+        assert_eq!(enclosed.len(), 9);
+    }
+
+    #[test]
+    fn hex_coordinates() {
+        let a = HexCoord::new(0, 0);
+        let b = HexCoord::new(3, -1);
+        assert_eq!(a.distance(b), 3);
+        assert_eq!(b.s(), -2);
+        assert_eq!(HexCoord::from_cube(1, 1, 1), None);
+        assert!(a.neighbors().all(|n| n.distance(a) == 1));
+
+        let line = a.line(b);
+        assert_eq!((line.len(), line[0], line[3]), (4, a, b));
+        assert!(line.windows(2).all(|w| w[0].distance(w[1]) == 1));
+
+        assert_eq!(a.ring(2).len(), 12);
+        assert!(a.ring(2).iter().all(|hex| hex.distance(a) == 2));
+        assert_eq!(a.spiral(2).len(), 19);
+
+        let blocked = a.field_of_view(3, |hex| hex == HexCoord::new(1, 0));
+        assert!(blocked.contains(&HexCoord::new(1, 0)));
+        assert!(!blocked.contains(&HexCoord::new(3, 0)));
+        assert!(blocked.contains(&HexCoord::new(-3, 0)));
+    }
+
+    #[test]
+    fn hex_positions() {
+        let cell = WorldDimensions2D::new(SQRT_3, 2.0).unwrap();
+        for orientation in [HexOrientation::Pointy, HexOrientation::Flat] {
+            let grid = HexGrid::new(orientation, cell).with_origin(WorldPosition::new(5.0, -3.0));
+            for hex in HexCoord::new(1, -2).spiral(3) {
+                let center = grid.to_position(hex);
+                assert_eq!(grid.to_cell(center), hex);
+                for corner in grid.corners(hex) {
+                    let inside = center + (corner - center) * 0.9;
+                    assert_eq!(grid.to_cell(inside), hex);
+                }
+            }
+        }
+
+        let pointy = HexGrid::new(HexOrientation::Pointy, cell);
+        let east = pointy.to_position(HexCoord::new(1, 0));
+        assert!((east - WorldPosition::new(SQRT_3, 0.0)).length() < 1e-5);
+
+        // directions and rings turn the same way as the corners
+        for orientation in [HexOrientation::Pointy, HexOrientation::Flat] {
+            let grid = HexGrid::new(orientation, cell);
+            let turns = |hexes: &[HexCoord]| {
+                let positions: Vec<_> = hexes.iter().map(|hex| grid.to_position(*hex)).collect();
+                (0..positions.len())
+                    .map(|i| positions[i].perp_dot(positions[(i + 1) % positions.len()]))
+                    .collect::<Vec<_>>()
+            };
+            assert!(turns(&HexCoord::DIRECTIONS).iter().all(|turn| *turn > 0.0));
+            assert!(
+                turns(&HexCoord::new(0, 0).ring(2))
+                    .iter()
+                    .all(|turn| *turn > 0.0)
+            );
+            let corners = grid.corners(HexCoord::new(0, 0));
+            assert!((0..6).all(|i| corners[i].perp_dot(corners[(i + 1) % 6]) > 0.0));
+        }
+    }
+}
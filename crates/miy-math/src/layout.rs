@@ -0,0 +1,347 @@
+//! Byte layouts of uniform and storage blocks as GLSL expects them.
+//!
+//! [`AsStd140`] writes a value with the `std140` rules of uniform blocks, [`AsStd430`] with the
+//! tighter `std430` rules of shader storage blocks. Both are implemented for scalars, vectors,
+//! matrices and arrays of them, and can be derived for structs of such fields:
+//!
+//! ```
+//! use miy_math::{AsStd140, Mat4, WorldVec3};
+//!
+//! #[derive(AsStd140)]
+//! struct Globals {
+//!     view: Mat4,
+//!     light: WorldVec3,
+//!     time: f32,
+//! }
+//!
+//! let globals = Globals { view: Mat4::IDENTITY, light: WorldVec3::new(0.0, 1.0, 0.0), time: 0.5 };
+//! // `time` is packed into the padding after the vec3
+//! assert_eq!(Globals::STD140_SIZE, 80);
+//! assert_eq!(globals.as_std140()[76..], 0.5f32.to_ne_bytes());
+//! ```
+//!
+//! The derives refer to `::miy_math`, through the `miy` facade add `#[layout(crate = miy)]`.
+
+use alloc::vec::Vec;
+
+pub use miy_math_derive::{AsStd140, AsStd430};
+
+use crate::{Mat3, Mat4, ProjectionMatrix, Vec2, Vec3, Vec4};
+
+/// A value that can be written into a uniform block with the `std140` layout
+pub trait AsStd140 {
+    /// Base alignment in bytes
+    const STD140_ALIGN: usize;
+    /// Size in bytes, including the padding at the end of structs
+    const STD140_SIZE: usize;
+
+    /// Writes the value to the start of `out`, which must be at least `STD140_SIZE` long.
+    /// Padding bytes are left untouched.
+    fn write_std140(&self, out: &mut [u8]);
+
+    /// The value as bytes ready to upload, padding is zeroed
+    fn as_std140(&self) -> Vec<u8> {
+        let mut bytes = alloc::vec![0; Self::STD140_SIZE];
+        self.write_std140(&mut bytes);
+        bytes
+    }
+}
+
+/// A value that can be written into a shader storage block with the `std430` layout
+pub trait AsStd430 {
+    /// Base alignment in bytes
+    const STD430_ALIGN: usize;
+    /// Size in bytes, including the padding at the end of structs
+    const STD430_SIZE: usize;
+
+    /// Writes the value to the start of `out`, which must be at least `STD430_SIZE` long.
+    /// Padding bytes are left untouched.
+    fn write_std430(&self, out: &mut [u8]);
+
+    /// The value as bytes ready to upload, padding is zeroed
+    fn as_std430(&self) -> Vec<u8> {
+        let mut bytes = alloc::vec![0; Self::STD430_SIZE];
+        self.write_std430(&mut bytes);
+        bytes
+    }
+}
+
+/// Places the members of a struct one after another, used by the derives
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Layout {
+    offset: usize,
+    align: usize,
+}
+
+impl Layout {
+    /// Structs in `std140` are aligned to at least 16 bytes, like a vec4
+    pub const fn std140() -> Self {
+        Self {
+            offset: 0,
+            align: 16,
+        }
+    }
+
+    pub const fn std430() -> Self {
+        Self {
+            offset: 0,
+            align: 1,
+        }
+    }
+
+    /// Offset of the next member with the given alignment
+    pub const fn offset(self, align: usize) -> usize {
+        align_to(self.offset, align)
+    }
+
+    /// Adds a member
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "member sizes come from GLSL types, a struct can't get near `usize::MAX` bytes"
+    )]
+    pub const fn field(self, align: usize, size: usize) -> Self {
+        Self {
+            offset: self.offset(align) + size,
+            align: max(self.align, align),
+        }
+    }
+
+    pub const fn align(self) -> usize {
+        self.align
+    }
+
+    /// Size of the whole struct, padded to its alignment
+    pub const fn size(self) -> usize {
+        align_to(self.offset, self.align)
+    }
+}
+
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "alignments are GLSL base alignments, never zero, and offsets stay far from the limit"
+)]
+const fn align_to(offset: usize, align: usize) -> usize {
+    offset.div_ceil(align) * align
+}
+
+const fn max(a: usize, b: usize) -> usize {
+    if a > b { a } else { b }
+}
+
+macro_rules! scalar {
+    ($($t:ty),+) => {
+        $(
+            impl AsStd140 for $t {
+                const STD140_ALIGN: usize = 4;
+                const STD140_SIZE: usize = 4;
+
+                fn write_std140(&self, out: &mut [u8]) {
+                    out[..4].copy_from_slice(&self.to_ne_bytes());
+                }
+            }
+
+            impl AsStd430 for $t {
+                const STD430_ALIGN: usize = 4;
+                const STD430_SIZE: usize = 4;
+
+                fn write_std430(&self, out: &mut [u8]) {
+                    out[..4].copy_from_slice(&self.to_ne_bytes());
+                }
+            }
+
+            vector!($t, Vec2, 8, 8, x, y);
+            vector!($t, Vec3, 16, 12, x, y, z);
+            vector!($t, Vec4, 16, 16, x, y, z, w);
+        )+
+    };
+}
+
+macro_rules! vector {
+    ($t:ty, $name:ident, $align:literal, $size:literal, $($component:ident),+) => {
+        impl<Space> AsStd140 for $name<Space, $t> {
+            const STD140_ALIGN: usize = $align;
+            const STD140_SIZE: usize = $size;
+
+            #[expect(
+                clippy::arithmetic_side_effects,
+                reason = "at most four components, the offset stays below 16"
+            )]
+            fn write_std140(&self, out: &mut [u8]) {
+                let mut offset = 0;
+                $(
+                    self.$component.write_std140(&mut out[offset..]);
+                    offset += 4;
+                )+
+                let _ = offset;
+            }
+        }
+
+        impl<Space> AsStd430 for $name<Space, $t> {
+            const STD430_ALIGN: usize = $align;
+            const STD430_SIZE: usize = $size;
+
+            fn write_std430(&self, out: &mut [u8]) {
+                self.write_std140(out);
+            }
+        }
+    };
+}
+
+scalar!(f32, i32, u32);
+
+/// GLSL `bool`, four bytes holding zero or one
+impl AsStd140 for bool {
+    const STD140_ALIGN: usize = 4;
+    const STD140_SIZE: usize = 4;
+
+    fn write_std140(&self, out: &mut [u8]) {
+        u32::from(*self).write_std140(out);
+    }
+}
+
+impl AsStd430 for bool {
+    const STD430_ALIGN: usize = 4;
+    const STD430_SIZE: usize = 4;
+
+    fn write_std430(&self, out: &mut [u8]) {
+        u32::from(*self).write_std430(out);
+    }
+}
+
+/// Array elements are padded to a multiple of 16 bytes
+impl<T: AsStd140, const N: usize> AsStd140 for [T; N] {
+    const STD140_ALIGN: usize = max(T::STD140_ALIGN, 16);
+    const STD140_SIZE: usize = align_to(T::STD140_SIZE, Self::STD140_ALIGN) * N;
+
+    fn write_std140(&self, out: &mut [u8]) {
+        let stride = align_to(T::STD140_SIZE, Self::STD140_ALIGN);
+        for (index, element) in self.iter().enumerate() {
+            element.write_std140(&mut out[index.saturating_mul(stride)..]);
+        }
+    }
+}
+
+impl<T: AsStd430, const N: usize> AsStd430 for [T; N] {
+    const STD430_ALIGN: usize = T::STD430_ALIGN;
+    const STD430_SIZE: usize = align_to(T::STD430_SIZE, T::STD430_ALIGN) * N;
+
+    fn write_std430(&self, out: &mut [u8]) {
+        let stride = align_to(T::STD430_SIZE, T::STD430_ALIGN);
+        for (index, element) in self.iter().enumerate() {
+            element.write_std430(&mut out[index.saturating_mul(stride)..]);
+        }
+    }
+}
+
+/// Matrices are laid out as an array of their column vectors
+macro_rules! matrix {
+    ($name:ty, $rows:literal, $columns:literal) => {
+        impl AsStd140 for $name {
+            const STD140_ALIGN: usize = 16;
+            const STD140_SIZE: usize = 16 * $columns;
+
+            fn write_std140(&self, out: &mut [u8]) {
+                for (index, value) in self.to_array().iter().enumerate() {
+                    let offset = index / $rows * 16 + index % $rows * 4;
+                    value.write_std140(&mut out[offset..]);
+                }
+            }
+        }
+
+        impl AsStd430 for $name {
+            const STD430_ALIGN: usize = 16;
+            const STD430_SIZE: usize = 16 * $columns;
+
+            fn write_std430(&self, out: &mut [u8]) {
+                self.write_std140(out);
+            }
+        }
+    };
+}
+
+matrix!(Mat3, 3, 3);
+matrix!(Mat4, 4, 4);
+matrix!(ProjectionMatrix, 4, 4);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{WorldVec2, WorldVec3, WorldVec4};
+
+    #[derive(AsStd140, AsStd430)]
+    struct Light {
+        position: WorldVec3,
+        intensity: f32,
+        color: WorldVec3,
+    }
+
+    #[derive(AsStd140, AsStd430)]
+    struct Scene {
+        ambient: f32,
+        offset: WorldVec2,
+        lights: [Light; 2],
+        weights: [f32; 3],
+        transform: Mat3,
+        enabled: bool,
+    }
+
+    #[derive(AsStd140, AsStd430)]
+    struct Wrapper(f32, WorldVec4);
+
+    #[test]
+    fn offsets_follow_the_spec() {
+        assert_eq!((Light::STD140_ALIGN, Light::STD140_SIZE), (16, 32));
+        assert_eq!((Light::STD430_ALIGN, Light::STD430_SIZE), (16, 32));
+
+        // ambient 0, offset 8, lights 16, weights 80 with a stride of 16, transform 128, enabled 176
+        assert_eq!(Scene::STD140_SIZE, 192);
+        // weights 80 with a stride of 4, transform 96, enabled 144
+        assert_eq!(Scene::STD430_SIZE, 160);
+        assert_eq!((Wrapper::STD140_SIZE, Wrapper::STD430_SIZE), (32, 32));
+        assert_eq!(<[f32; 4]>::STD430_SIZE, 16);
+        assert_eq!(<[WorldVec3; 2]>::STD430_SIZE, 32);
+
+        let light = |x| Light {
+            position: WorldVec3::new(x, 0.0, 0.0),
+            intensity: 2.0,
+            color: WorldVec3::new(1.0, 1.0, 1.0),
+        };
+        let scene = Scene {
+            ambient: 0.1,
+            offset: WorldVec2::new(3.0, 4.0),
+            lights: [light(5.0), light(6.0)],
+            weights: [7.0, 8.0, 9.0],
+            transform: Mat3::from_cols_array([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]),
+            enabled: true,
+        };
+
+        let read = |bytes: &[u8], offset: usize| {
+            f32::from_ne_bytes(bytes[offset..offset + 4].try_into().unwrap())
+        };
+        let std140 = scene.as_std140();
+        let expected = [
+            (0, 0.1),
+            (8, 3.0),
+            (12, 4.0),
+            (16, 5.0),
+            (28, 2.0),
+            (48, 6.0),
+            (80, 7.0),
+            (96, 8.0),
+            (112, 9.0),
+            (128, 1.0),
+            (144, 4.0),
+            (168, 9.0),
+        ];
+        for (offset, value) in expected {
+            assert_eq!(read(&std140, offset), value, "std140 offset {offset}");
+        }
+        assert_eq!(std140[176..180], 1u32.to_ne_bytes());
+
+        let std430 = scene.as_std430();
+        for (offset, value) in [(80, 7.0), (84, 8.0), (88, 9.0), (96, 1.0), (132, 8.0)] {
+            assert_eq!(read(&std430, offset), value, "std430 offset {offset}");
+        }
+        assert_eq!(std430[144..148], 1u32.to_ne_bytes());
+    }
+}
@@ -1,12 +1,57 @@
+extern crate alloc;
+// lets the derives refer to `::miy_math` inside this crate too
+extern crate self as miy_math;
+
+mod angle;
+mod camera;
+mod curve;
 mod dimensions;
+mod easing;
+mod fitting;
+mod fixed;
 mod matrix;
+mod noise;
+mod orthographic;
 mod position;
+mod quat;
+mod random;
+mod rect;
+#[cfg(feature = "serde")]
+mod serialization;
+mod transform;
+mod tween;
+mod vector;
 
+pub use angle::{Angle, Degrees, Radians};
+pub use camera::{Camera, ClipPosition, ScreenPosition, Viewport};
+pub use curve::{
+    ArcLengthTable, BSpline, CatmullRom, CubicBezier, Curve, CurvePoint, QuadraticBezier,
+};
 pub use dimensions::{
-    ScreenDimensions, ScreenHeight, ScreenWidth, WorldDimensions2D, WorldHeight, WorldWidth,
+    Dimensions2D, ScreenDimensions, ScreenHeight, ScreenWidth, WorldDimensions2D, WorldHeight,
+    WorldWidth,
 };
-pub use matrix::ProjectionMatrix;
+pub use easing::Easing;
+pub use fitting::{FitMode, ViewportFit};
+pub use fixed::Fixed;
+pub use layout::{AsStd140, AsStd430};
+pub use matrix::{DepthRange, Mat3, Mat4, ProjectionMatrix};
+pub use noise::{Fbm, Noise, NoiseKind};
+pub use orthographic::{Orthographic, OrthographicAnchor};
 pub use position::WorldPosition;
+pub use quat::Quat;
+pub use random::Rng;
+pub use rect::{Aabb, Rect, ScreenRect, WorldAabb, WorldRect};
+pub use transform::{Compose, Hierarchy, NodeId, Transform2D, Transform3D};
+pub use tween::{Interpolate, Repeat, Tween};
+pub use vector::{ClipVec4, ScreenVec2, Vec2, Vec3, Vec4, WorldVec2, WorldVec3, WorldVec4};
+
+pub mod collision;
+pub mod grid;
+pub mod layout;
+pub mod pathfinding;
+pub mod polygon;
+pub mod spatial;
 
 pub mod space {
     #[derive(Copy, Clone, PartialEq, Eq, Debug)]
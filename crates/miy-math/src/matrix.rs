@@ -1,8 +1,22 @@
-use crate::WorldDimensions2D;
+use core::ops::{Mul, MulAssign};
+
+use crate::{Orthographic, OrthographicAnchor, Radians, Vec2, Vec3, Vec4, WorldDimensions2D};
 
 #[derive(Copy, Clone, Debug)]
 pub struct ProjectionMatrix([f32; 16]);
 
+/// The range clip space depth is mapped to
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum DepthRange {
+    /// OpenGL's default, near maps to -1 and far to 1
+    #[default]
+    NegativeOneToOne,
+    /// Near maps to 0 and far to 1, for use with `glClipControl(GL_LOWER_LEFT, GL_ZERO_TO_ONE)`
+    ZeroToOne,
+    /// Near maps to 1 and far to 0, spreads float precision more evenly across the depth range
+    ReversedZeroToOne,
+}
+
 impl ProjectionMatrix {
     pub const fn identity() -> Self {
         let values = [
@@ -24,25 +38,788 @@ impl ProjectionMatrix {
         unsafe { core::slice::from_raw_parts(self.0.as_ptr().cast(), 16 * 4) }
     }
 
-    /// Column-major orthographic projection matrix
+    /// Column-major orthographic projection matrix with x in `[-w/2, w/2]` and y in `[0, h]`.
+    ///
+    /// See [`Orthographic`] for other anchors and depth ranges. Dimensions that are not finite
+    /// give the identity.
     pub fn with_ortho_from_world(world_dimensions: WorldDimensions2D) -> Self {
-        let near = 0.0;
-        let far = 1.0;
+        Orthographic::from_anchor(world_dimensions, OrthographicAnchor::BottomCenter)
+            .map_or_else(Self::identity, |orthographic| orthographic.projection())
+    }
+
+    /// Right-handed perspective projection with OpenGL's default depth range,
+    /// looking down the negative z axis.
+    ///
+    /// Returns `None` unless `0 < near < far` and `fov_y` and `aspect` are positive
+    pub fn perspective(
+        fov_y: impl Into<Radians>,
+        aspect: f32,
+        near: f32,
+        far: f32,
+    ) -> Option<Self> {
+        Self::perspective_with_depth_range(fov_y, aspect, near, far, DepthRange::NegativeOneToOne)
+    }
+
+    /// Right-handed perspective projection, looking down the negative z axis.
+    ///
+    /// Returns `None` unless `0 < near < far` and `fov_y` and `aspect` are positive
+    pub fn perspective_with_depth_range(
+        fov_y: impl Into<Radians>,
+        aspect: f32,
+        near: f32,
+        far: f32,
+        depth_range: DepthRange,
+    ) -> Option<Self> {
+        if far <= near || !far.is_finite() {
+            return None;
+        }
+
+        let (x, y) = perspective_scale(fov_y.into(), aspect, near)?;
+
+        let (z, za) = match depth_range {
+            DepthRange::NegativeOneToOne => {
+                ((far + near) / (near - far), 2.0 * far * near / (near - far))
+            }
+            DepthRange::ZeroToOne => (far / (near - far), far * near / (near - far)),
+            DepthRange::ReversedZeroToOne => (near / (far - near), far * near / (far - near)),
+        };
+
+        #[rustfmt::skip]
+        let values = [
+              x, 0.0, 0.0,  0.0,
+            0.0,   y, 0.0,  0.0,
+            0.0, 0.0,   z, -1.0,
+            0.0, 0.0,  za,  0.0,
+        ];
+
+        Some(Self(values))
+    }
+
+    /// Right-handed perspective projection with the far plane at infinity,
+    /// looking down the negative z axis.
+    ///
+    /// Returns `None` unless `near`, `fov_y` and `aspect` are positive
+    pub fn perspective_infinite(
+        fov_y: impl Into<Radians>,
+        aspect: f32,
+        near: f32,
+        depth_range: DepthRange,
+    ) -> Option<Self> {
+        let (x, y) = perspective_scale(fov_y.into(), aspect, near)?;
+
+        let (z, za) = match depth_range {
+            DepthRange::NegativeOneToOne => (-1.0, -2.0 * near),
+            DepthRange::ZeroToOne => (-1.0, -near),
+            DepthRange::ReversedZeroToOne => (0.0, near),
+        };
+
+        #[rustfmt::skip]
+        let values = [
+              x, 0.0, 0.0,  0.0,
+            0.0,   y, 0.0,  0.0,
+            0.0, 0.0,   z, -1.0,
+            0.0, 0.0,  za,  0.0,
+        ];
+
+        Some(Self(values))
+    }
+}
+
+fn perspective_scale(fov_y: Radians, aspect: f32, near: f32) -> Option<(f32, f32)> {
+    let valid = |v: f32| v > 0.0 && v.is_finite();
+    if fov_y <= Radians::ZERO || fov_y >= Radians::PI || !valid(aspect) || !valid(near) {
+        return None;
+    }
+
+    let y = 1.0 / (fov_y.get() * 0.5).tan();
+    Some((y / aspect, y))
+}
+
+/// Column-major 3x3 matrix, mostly used for 2D transforms in homogeneous coordinates
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Mat3([f32; 9]);
+
+/// Column-major 4x4 matrix
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Mat4([f32; 16]);
+
+impl Mat3 {
+    pub const IDENTITY: Self = Self([1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]);
+
+    pub const fn from_cols_array(values: [f32; 9]) -> Self {
+        Self(values)
+    }
+
+    pub const fn from_cols<Space>(
+        x: Vec3<Space, f32>,
+        y: Vec3<Space, f32>,
+        z: Vec3<Space, f32>,
+    ) -> Self {
+        Self([x.x, x.y, x.z, y.x, y.y, y.z, z.x, z.y, z.z])
+    }
 
-        let x = 2.0 / world_dimensions.width().get();
-        let y = 2.0 / world_dimensions.height().get();
+    pub fn from_translation<Space>(translation: Vec2<Space, f32>) -> Self {
+        #[rustfmt::skip]
+        let values = [
+                      1.0,           0.0, 0.0,
+                      0.0,           1.0, 0.0,
+            translation.x, translation.y, 1.0,
+        ];
+
+        Self(values)
+    }
 
-        let z = -(2.0 / (far - near));
-        let za = -((far + near) / (far - near));
+    /// Counter-clockwise rotation around the origin
+    pub fn from_rotation(angle: impl Into<Radians>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
 
         #[rustfmt::skip]
         let values = [
-              x,  0.0, 0.0, 0.0,
-            0.0,    y, 0.0, 0.0,
-            0.0,  0.0,   z, 0.0,
-            0.0, -1.0,  za, 1.0,
+             cos, sin, 0.0,
+            -sin, cos, 0.0,
+             0.0, 0.0, 1.0,
         ];
 
         Self(values)
     }
+
+    pub fn from_scale<Space>(scale: Vec2<Space, f32>) -> Self {
+        #[rustfmt::skip]
+        let values = [
+            scale.x,     0.0, 0.0,
+                0.0, scale.y, 0.0,
+                0.0,     0.0, 1.0,
+        ];
+
+        Self(values)
+    }
+
+    /// The upper left 3x3 part of `matrix`
+    pub fn from_mat4(matrix: Mat4) -> Self {
+        let m = matrix.0;
+        Self([m[0], m[1], m[2], m[4], m[5], m[6], m[8], m[9], m[10]])
+    }
+
+    pub const fn to_array(self) -> [f32; 9] {
+        self.0
+    }
+
+    pub const fn as_ptr(&self) -> *const f32 {
+        self.0.as_ptr()
+    }
+
+    pub const fn as_bytes(&self) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(self.0.as_ptr().cast(), 9 * 4) }
+    }
+
+    pub fn col<Space>(&self, index: usize) -> Vec3<Space, f32> {
+        let [x, y, z] = self.0.as_chunks::<3>().0[index];
+        Vec3::new(x, y, z)
+    }
+
+    pub fn get(&self, col: usize, row: usize) -> f32 {
+        self.0.as_chunks::<3>().0[col][row]
+    }
+
+    pub fn transpose(self) -> Self {
+        let m = self.0;
+        Self([m[0], m[3], m[6], m[1], m[4], m[7], m[2], m[5], m[8]])
+    }
+
+    pub fn determinant(&self) -> f32 {
+        let m = self.0;
+        m[0] * (m[4] * m[8] - m[7] * m[5]) - m[3] * (m[1] * m[8] - m[7] * m[2])
+            + m[6] * (m[1] * m[5] - m[4] * m[2])
+    }
+
+    /// Returns `None` if the matrix is singular
+    pub fn inverse(&self) -> Option<Self> {
+        let m = self.0;
+        let determinant = self.determinant();
+        if determinant == 0.0 || !determinant.is_finite() {
+            return None;
+        }
+
+        let inv = 1.0 / determinant;
+
+        #[rustfmt::skip]
+        let values = [
+            (m[4] * m[8] - m[7] * m[5]) * inv,
+            (m[7] * m[2] - m[1] * m[8]) * inv,
+            (m[1] * m[5] - m[4] * m[2]) * inv,
+            (m[6] * m[5] - m[3] * m[8]) * inv,
+            (m[0] * m[8] - m[6] * m[2]) * inv,
+            (m[3] * m[2] - m[0] * m[5]) * inv,
+            (m[3] * m[7] - m[6] * m[4]) * inv,
+            (m[6] * m[1] - m[0] * m[7]) * inv,
+            (m[0] * m[4] - m[3] * m[1]) * inv,
+        ];
+
+        Some(Self(values))
+    }
+
+    pub fn transform_vec3<Space>(&self, v: Vec3<Space, f32>) -> Vec3<Space, f32> {
+        let m = self.0;
+        Vec3::new(
+            m[0] * v.x + m[3] * v.y + m[6] * v.z,
+            m[1] * v.x + m[4] * v.y + m[7] * v.z,
+            m[2] * v.x + m[5] * v.y + m[8] * v.z,
+        )
+    }
+
+    /// Transforms a 2D point, applying translation
+    pub fn transform_point<Space>(&self, point: Vec2<Space, f32>) -> Vec2<Space, f32> {
+        self.transform_vec3(point.extend(1.0)).truncate()
+    }
+
+    /// Transforms a 2D direction, ignoring translation
+    pub fn transform_vector<Space>(&self, vector: Vec2<Space, f32>) -> Vec2<Space, f32> {
+        self.transform_vec3(vector.extend(0.0)).truncate()
+    }
+}
+
+impl Mat4 {
+    pub const IDENTITY: Self = Self(ProjectionMatrix::identity().to_array());
+
+    pub const fn from_cols_array(values: [f32; 16]) -> Self {
+        Self(values)
+    }
+
+    pub const fn from_cols<Space>(
+        x: Vec4<Space, f32>,
+        y: Vec4<Space, f32>,
+        z: Vec4<Space, f32>,
+        w: Vec4<Space, f32>,
+    ) -> Self {
+        Self([
+            x.x, x.y, x.z, x.w, y.x, y.y, y.z, y.w, z.x, z.y, z.z, z.w, w.x, w.y, w.z, w.w,
+        ])
+    }
+
+    pub fn from_translation<Space>(translation: Vec3<Space, f32>) -> Self {
+        let Vec3 { x, y, z, .. } = translation;
+
+        #[rustfmt::skip]
+        let values = [
+            1.0, 0.0, 0.0, 0.0,
+            0.0, 1.0, 0.0, 0.0,
+            0.0, 0.0, 1.0, 0.0,
+              x,   y,   z, 1.0,
+        ];
+
+        Self(values)
+    }
+
+    pub fn from_scale<Space>(scale: Vec3<Space, f32>) -> Self {
+        let Vec3 { x, y, z, .. } = scale;
+
+        #[rustfmt::skip]
+        let values = [
+              x, 0.0, 0.0, 0.0,
+            0.0,   y, 0.0, 0.0,
+            0.0, 0.0,   z, 0.0,
+            0.0, 0.0, 0.0, 1.0,
+        ];
+
+        Self(values)
+    }
+
+    /// Counter-clockwise rotation around the x axis
+    pub fn from_rotation_x(angle: impl Into<Radians>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+
+        #[rustfmt::skip]
+        let values = [
+            1.0,  0.0, 0.0, 0.0,
+            0.0,  cos, sin, 0.0,
+            0.0, -sin, cos, 0.0,
+            0.0,  0.0, 0.0, 1.0,
+        ];
+
+        Self(values)
+    }
+
+    /// Counter-clockwise rotation around the y axis
+    pub fn from_rotation_y(angle: impl Into<Radians>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+
+        #[rustfmt::skip]
+        let values = [
+            cos, 0.0, -sin, 0.0,
+            0.0, 1.0,  0.0, 0.0,
+            sin, 0.0,  cos, 0.0,
+            0.0, 0.0,  0.0, 1.0,
+        ];
+
+        Self(values)
+    }
+
+    /// Counter-clockwise rotation around the z axis
+    pub fn from_rotation_z(angle: impl Into<Radians>) -> Self {
+        let (sin, cos) = angle.into().sin_cos();
+
+        #[rustfmt::skip]
+        let values = [
+             cos, sin, 0.0, 0.0,
+            -sin, cos, 0.0, 0.0,
+             0.0, 0.0, 1.0, 0.0,
+             0.0, 0.0, 0.0, 1.0,
+        ];
+
+        Self(values)
+    }
+
+    /// Counter-clockwise rotation around `axis`, returns `None` if the axis can't be normalized
+    pub fn from_axis_angle<Space>(
+        axis: Vec3<Space, f32>,
+        angle: impl Into<Radians>,
+    ) -> Option<Self> {
+        let Vec3 { x, y, z, .. } = axis.normalize()?;
+        let (sin, cos) = angle.into().sin_cos();
+        let t = 1.0 - cos;
+
+        #[rustfmt::skip]
+        let values = [
+            t * x * x + cos,     t * x * y + sin * z, t * x * z - sin * y, 0.0,
+            t * x * y - sin * z, t * y * y + cos,     t * y * z + sin * x, 0.0,
+            t * x * z + sin * y, t * y * z - sin * x, t * z * z + cos,     0.0,
+            0.0,                 0.0,                 0.0,                 1.0,
+        ];
+
+        Some(Self(values))
+    }
+
+    /// Extends a 2D homogeneous transform into 3D, leaving z untouched
+    pub fn from_mat3_2d(matrix: Mat3) -> Self {
+        let m = matrix.0;
+
+        #[rustfmt::skip]
+        let values = [
+            m[0], m[1], 0.0, m[2],
+            m[3], m[4], 0.0, m[5],
+             0.0,  0.0, 1.0,  0.0,
+            m[6], m[7], 0.0, m[8],
+        ];
+
+        Self(values)
+    }
+
+    /// Right-handed view matrix for a camera at `eye` looking at `target`.
+    ///
+    /// Returns `None` if `eye` and `target` are the same point or `up` is parallel to the view direction
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "float vectors, a zero direction is caught"
+    )]
+    pub fn look_at<Space>(
+        eye: Vec3<Space, f32>,
+        target: Vec3<Space, f32>,
+        up: Vec3<Space, f32>,
+    ) -> Option<Self> {
+        let forward = (target - eye).normalize()?;
+        let side = forward.cross(up).normalize()?;
+        let up = side.cross(forward);
+
+        #[rustfmt::skip]
+        let values = [
+                       side.x,           up.x,       -forward.x, 0.0,
+                       side.y,           up.y,       -forward.y, 0.0,
+                       side.z,           up.z,       -forward.z, 0.0,
+            -side.dot(eye), -up.dot(eye), forward.dot(eye), 1.0,
+        ];
+
+        Some(Self(values))
+    }
+
+    pub const fn to_array(self) -> [f32; 16] {
+        self.0
+    }
+
+    pub const fn as_ptr(&self) -> *const f32 {
+        self.0.as_ptr()
+    }
+
+    pub const fn as_bytes(&self) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(self.0.as_ptr().cast(), 16 * 4) }
+    }
+
+    pub fn col<Space>(&self, index: usize) -> Vec4<Space, f32> {
+        let [x, y, z, w] = self.0.as_chunks::<4>().0[index];
+        Vec4::new(x, y, z, w)
+    }
+
+    pub fn get(&self, col: usize, row: usize) -> f32 {
+        self.0.as_chunks::<4>().0[col][row]
+    }
+
+    pub fn transpose(self) -> Self {
+        let m = self.0;
+
+        #[rustfmt::skip]
+        let values = [
+            m[0], m[4], m[8],  m[12],
+            m[1], m[5], m[9],  m[13],
+            m[2], m[6], m[10], m[14],
+            m[3], m[7], m[11], m[15],
+        ];
+
+        Self(values)
+    }
+
+    pub fn determinant(&self) -> f32 {
+        let (cofactors, _) = self.cofactors();
+        let m = self.0;
+        m[0] * cofactors[0] + m[1] * cofactors[4] + m[2] * cofactors[8] + m[3] * cofactors[12]
+    }
+
+    /// Returns `None` if the matrix is singular
+    pub fn inverse(&self) -> Option<Self> {
+        let (mut values, determinant) = self.cofactors();
+        if determinant == 0.0 || !determinant.is_finite() {
+            return None;
+        }
+
+        let inv = 1.0 / determinant;
+        for value in &mut values {
+            *value *= inv;
+        }
+
+        Some(Self(values))
+    }
+
+    /// Adjugate matrix and determinant
+    fn cofactors(&self) -> ([f32; 16], f32) {
+        let m = self.0;
+        let mut inv = [0.0; 16];
+
+        inv[0] = m[5] * m[10] * m[15] - m[5] * m[11] * m[14] - m[9] * m[6] * m[15]
+            + m[9] * m[7] * m[14]
+            + m[13] * m[6] * m[11]
+            - m[13] * m[7] * m[10];
+        inv[4] = -m[4] * m[10] * m[15] + m[4] * m[11] * m[14] + m[8] * m[6] * m[15]
+            - m[8] * m[7] * m[14]
+            - m[12] * m[6] * m[11]
+            + m[12] * m[7] * m[10];
+        inv[8] = m[4] * m[9] * m[15] - m[4] * m[11] * m[13] - m[8] * m[5] * m[15]
+            + m[8] * m[7] * m[13]
+            + m[12] * m[5] * m[11]
+            - m[12] * m[7] * m[9];
+        inv[12] = -m[4] * m[9] * m[14] + m[4] * m[10] * m[13] + m[8] * m[5] * m[14]
+            - m[8] * m[6] * m[13]
+            - m[12] * m[5] * m[10]
+            + m[12] * m[6] * m[9];
+        inv[1] = -m[1] * m[10] * m[15] + m[1] * m[11] * m[14] + m[9] * m[2] * m[15]
+            - m[9] * m[3] * m[14]
+            - m[13] * m[2] * m[11]
+            + m[13] * m[3] * m[10];
+        inv[5] = m[0] * m[10] * m[15] - m[0] * m[11] * m[14] - m[8] * m[2] * m[15]
+            + m[8] * m[3] * m[14]
+            + m[12] * m[2] * m[11]
+            - m[12] * m[3] * m[10];
+        inv[9] = -m[0] * m[9] * m[15] + m[0] * m[11] * m[13] + m[8] * m[1] * m[15]
+            - m[8] * m[3] * m[13]
+            - m[12] * m[1] * m[11]
+            + m[12] * m[3] * m[9];
+        inv[13] = m[0] * m[9] * m[14] - m[0] * m[10] * m[13] - m[8] * m[1] * m[14]
+            + m[8] * m[2] * m[13]
+            + m[12] * m[1] * m[10]
+            - m[12] * m[2] * m[9];
+        inv[2] = m[1] * m[6] * m[15] - m[1] * m[7] * m[14] - m[5] * m[2] * m[15]
+            + m[5] * m[3] * m[14]
+            + m[13] * m[2] * m[7]
+            - m[13] * m[3] * m[6];
+        inv[6] = -m[0] * m[6] * m[15] + m[0] * m[7] * m[14] + m[4] * m[2] * m[15]
+            - m[4] * m[3] * m[14]
+            - m[12] * m[2] * m[7]
+            + m[12] * m[3] * m[6];
+        inv[10] = m[0] * m[5] * m[15] - m[0] * m[7] * m[13] - m[4] * m[1] * m[15]
+            + m[4] * m[3] * m[13]
+            + m[12] * m[1] * m[7]
+            - m[12] * m[3] * m[5];
+        inv[14] = -m[0] * m[5] * m[14] + m[0] * m[6] * m[13] + m[4] * m[1] * m[14]
+            - m[4] * m[2] * m[13]
+            - m[12] * m[1] * m[6]
+            + m[12] * m[2] * m[5];
+        inv[3] = -m[1] * m[6] * m[11] + m[1] * m[7] * m[10] + m[5] * m[2] * m[11]
+            - m[5] * m[3] * m[10]
+            - m[9] * m[2] * m[7]
+            + m[9] * m[3] * m[6];
+        inv[7] = m[0] * m[6] * m[11] - m[0] * m[7] * m[10] - m[4] * m[2] * m[11]
+            + m[4] * m[3] * m[10]
+            + m[8] * m[2] * m[7]
+            - m[8] * m[3] * m[6];
+        inv[11] = -m[0] * m[5] * m[11] + m[0] * m[7] * m[9] + m[4] * m[1] * m[11]
+            - m[4] * m[3] * m[9]
+            - m[8] * m[1] * m[7]
+            + m[8] * m[3] * m[5];
+        inv[15] = m[0] * m[5] * m[10] - m[0] * m[6] * m[9] - m[4] * m[1] * m[10]
+            + m[4] * m[2] * m[9]
+            + m[8] * m[1] * m[6]
+            - m[8] * m[2] * m[5];
+
+        let determinant = m[0] * inv[0] + m[1] * inv[4] + m[2] * inv[8] + m[3] * inv[12];
+
+        (inv, determinant)
+    }
+
+    pub fn transform_vec4<Space>(&self, v: Vec4<Space, f32>) -> Vec4<Space, f32> {
+        let m = self.0;
+        Vec4::new(
+            m[0] * v.x + m[4] * v.y + m[8] * v.z + m[12] * v.w,
+            m[1] * v.x + m[5] * v.y + m[9] * v.z + m[13] * v.w,
+            m[2] * v.x + m[6] * v.y + m[10] * v.z + m[14] * v.w,
+            m[3] * v.x + m[7] * v.y + m[11] * v.z + m[15] * v.w,
+        )
+    }
+
+    /// Transforms a point, applying translation and the perspective divide
+    pub fn transform_point<Space>(&self, point: Vec3<Space, f32>) -> Vec3<Space, f32> {
+        let Vec4 { x, y, z, w, .. } = self.transform_vec4(point.extend(1.0));
+        Vec3::new(x / w, y / w, z / w)
+    }
+
+    /// Transforms a direction, ignoring translation
+    pub fn transform_vector<Space>(&self, vector: Vec3<Space, f32>) -> Vec3<Space, f32> {
+        self.transform_vec4(vector.extend(0.0)).truncate()
+    }
+}
+
+impl Default for Mat3 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Default for Mat4 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Mul for Mat3 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let [x, y, z] = [0, 1, 2].map(|i| self.transform_vec3(rhs.col::<()>(i)));
+        Self::from_cols(x, y, z)
+    }
+}
+
+impl Mul for Mat4 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let [x, y, z, w] = [0, 1, 2, 3].map(|i| self.transform_vec4(rhs.col::<()>(i)));
+        Self::from_cols(x, y, z, w)
+    }
+}
+
+impl MulAssign for Mat3 {
+    #[expect(clippy::arithmetic_side_effects, reason = "forwards to `Mul for Mat3`")]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign for Mat4 {
+    #[expect(clippy::arithmetic_side_effects, reason = "forwards to `Mul for Mat4`")]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<Space> Mul<Vec3<Space, f32>> for Mat3 {
+    type Output = Vec3<Space, f32>;
+
+    fn mul(self, rhs: Vec3<Space, f32>) -> Self::Output {
+        self.transform_vec3(rhs)
+    }
+}
+
+impl<Space> Mul<Vec4<Space, f32>> for Mat4 {
+    type Output = Vec4<Space, f32>;
+
+    fn mul(self, rhs: Vec4<Space, f32>) -> Self::Output {
+        self.transform_vec4(rhs)
+    }
+}
+
+impl Mul<Mat4> for ProjectionMatrix {
+    type Output = Mat4;
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "a projection is an ordinary `Mat4` once converted"
+    )]
+    fn mul(self, rhs: Mat4) -> Mat4 {
+        Mat4::from(self) * rhs
+    }
+}
+
+impl From<ProjectionMatrix> for Mat4 {
+    fn from(value: ProjectionMatrix) -> Self {
+        Self(value.0)
+    }
+}
+
+impl From<Mat4> for ProjectionMatrix {
+    fn from(value: Mat4) -> Self {
+        Self(value.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::WorldVec3;
+
+    fn assert_approx(a: Mat4, b: Mat4) {
+        for (a, b) in a.to_array().into_iter().zip(b.to_array()) {
+            assert!((a - b).abs() < 1e-5, "{a} != {b}");
+        }
+    }
+
+    #[test]
+    fn matrix_composition() {
+        let translation = Mat4::from_translation(WorldVec3::new(1.0, 2.0, 3.0));
+        let scale = Mat4::from_scale(WorldVec3::new(2.0, 2.0, 2.0));
+        let rotation = Mat4::from_rotation_z(Radians::FRAC_PI_2);
+
+        let model = translation * rotation * scale;
+        let point = model.transform_point(WorldVec3::new(1.0, 0.0, 0.0));
+        assert!((point - WorldVec3::new(1.0, 4.0, 3.0)).length() < 1e-5);
+
+        let direction = model.transform_vector(WorldVec3::new(1.0, 0.0, 0.0));
+        assert!((direction - WorldVec3::new(0.0, 2.0, 0.0)).length() < 1e-5);
+
+        assert_eq!(model.transpose().transpose(), model);
+        assert_eq!(Mat4::IDENTITY * model, model);
+    }
+
+    #[test]
+    fn perspective_projection() {
+        let fov = Radians::FRAC_PI_2;
+
+        // reference values from glm::perspectiveRH_NO / perspectiveRH_ZO
+        let gl = ProjectionMatrix::perspective(fov, 2.0, 1.0, 3.0).unwrap();
+        #[rustfmt::skip]
+        assert_approx(gl.into(), Mat4::from_cols_array([
+            0.5, 0.0,  0.0,  0.0,
+            0.0, 1.0,  0.0,  0.0,
+            0.0, 0.0, -2.0, -1.0,
+            0.0, 0.0, -3.0,  0.0,
+        ]));
+
+        let zo = ProjectionMatrix::perspective_with_depth_range(
+            fov,
+            2.0,
+            1.0,
+            3.0,
+            DepthRange::ZeroToOne,
+        )
+        .unwrap();
+        #[rustfmt::skip]
+        assert_approx(zo.into(), Mat4::from_cols_array([
+            0.5, 0.0,  0.0,  0.0,
+            0.0, 1.0,  0.0,  0.0,
+            0.0, 0.0, -1.5, -1.0,
+            0.0, 0.0, -1.5,  0.0,
+        ]));
+
+        let depth = |projection: ProjectionMatrix, z: f32| {
+            Mat4::from(projection)
+                .transform_point(WorldVec3::new(0.0, 0.0, z))
+                .z
+        };
+
+        let reversed = ProjectionMatrix::perspective_with_depth_range(
+            fov,
+            1.0,
+            0.1,
+            100.0,
+            DepthRange::ReversedZeroToOne,
+        )
+        .unwrap();
+        assert!((depth(reversed, -0.1) - 1.0).abs() < 1e-5);
+        assert!(depth(reversed, -100.0).abs() < 1e-5);
+        assert!((depth(gl, -1.0) + 1.0).abs() < 1e-5);
+        assert!((depth(gl, -3.0) - 1.0).abs() < 1e-5);
+
+        let infinite =
+            ProjectionMatrix::perspective_infinite(fov, 1.0, 0.1, DepthRange::NegativeOneToOne)
+                .unwrap();
+        assert!((depth(infinite, -0.1) + 1.0).abs() < 1e-5);
+        assert!((depth(infinite, -1.0e7) - 1.0).abs() < 1e-5);
+
+        let infinite =
+            ProjectionMatrix::perspective_infinite(fov, 1.0, 0.1, DepthRange::ReversedZeroToOne)
+                .unwrap();
+        assert!((depth(infinite, -0.1) - 1.0).abs() < 1e-5);
+        assert!(depth(infinite, -1.0e7).abs() < 1e-5);
+
+        assert!(ProjectionMatrix::perspective(fov, 1.0, 0.0, 1.0).is_none());
+        assert!(ProjectionMatrix::perspective(fov, 1.0, 2.0, 1.0).is_none());
+        assert!(ProjectionMatrix::perspective(Radians::ZERO, 1.0, 0.1, 1.0).is_none());
+    }
+
+    #[test]
+    fn look_at_view() {
+        let eye = WorldVec3::new(1.0, 2.0, 3.0);
+        let view = Mat4::look_at(
+            eye,
+            WorldVec3::new(1.0, 2.0, 0.0),
+            WorldVec3::new(0.0, 1.0, 0.0),
+        )
+        .unwrap();
+
+        // looking down -z from (1, 2, 3) is just a translation
+        assert_approx(view, Mat4::from_translation(-eye));
+
+        // reference values from glm::lookAtRH
+        let view = Mat4::look_at(
+            WorldVec3::ZERO,
+            WorldVec3::new(1.0, 0.0, 0.0),
+            WorldVec3::new(0.0, 1.0, 0.0),
+        )
+        .unwrap();
+        #[rustfmt::skip]
+        assert_approx(view, Mat4::from_cols_array([
+            0.0, 0.0, -1.0, 0.0,
+            0.0, 1.0,  0.0, 0.0,
+            1.0, 0.0,  0.0, 0.0,
+            0.0, 0.0,  0.0, 1.0,
+        ]));
+
+        assert!(Mat4::look_at(eye, eye, WorldVec3::new(0.0, 1.0, 0.0)).is_none());
+        assert!(
+            Mat4::look_at(
+                WorldVec3::ZERO,
+                WorldVec3::new(0.0, 1.0, 0.0),
+                WorldVec3::new(0.0, 1.0, 0.0)
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn matrix_inverse() {
+        let model = Mat4::from_translation(WorldVec3::new(1.0, 2.0, 3.0))
+            * Mat4::from_axis_angle(WorldVec3::new(1.0, 1.0, 0.0), Radians::new(0.7).unwrap())
+                .unwrap()
+            * Mat4::from_scale(WorldVec3::new(2.0, 3.0, 4.0));
+
+        assert!((model.determinant() - 24.0).abs() < 1e-4);
+        assert_approx(model * model.inverse().unwrap(), Mat4::IDENTITY);
+        assert_eq!(
+            Mat4::from_scale(WorldVec3::new(1.0, 0.0, 1.0)).inverse(),
+            None
+        );
+
+        let model_2d = Mat3::from_translation(crate::WorldVec2::new(3.0, -1.0))
+            * Mat3::from_rotation(Radians::new(1.2).unwrap())
+            * Mat3::from_scale(crate::WorldVec2::new(2.0, 0.5));
+        let identity = Mat4::from_mat3_2d(model_2d * model_2d.inverse().unwrap());
+        assert_approx(identity, Mat4::IDENTITY);
+        assert!((model_2d.determinant() - 1.0).abs() < 1e-5);
+    }
 }
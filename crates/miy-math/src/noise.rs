@@ -0,0 +1,494 @@
+use crate::{Vec2, Vec3};
+
+/// Skews the square lattice into triangles, `(sqrt(3) - 1) / 2`
+const SKEW_2D: f32 = 0.366_025_42;
+/// Unskews triangles back into squares, `(3 - sqrt(3)) / 6`
+const UNSKEW_2D: f32 = 0.211_324_87;
+const SKEW_3D: f32 = 1.0 / 3.0;
+const UNSKEW_3D: f32 = 1.0 / 6.0;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum NoiseKind {
+    /// Random values on the lattice, smoothly interpolated
+    Value,
+    /// Random gradients on the lattice, smoothly interpolated
+    #[default]
+    Perlin,
+    /// Gradients on a simplex grid, cheaper in 3D and without axis-aligned artifacts
+    Simplex,
+    /// Distance to the closest of randomly scattered feature points, one per cell
+    Worley,
+}
+
+/// Seeded coherent noise with a lattice spacing of one unit
+///
+/// Every kind returns values within `[-1, 1]`. The lattice is hashed instead of using a
+/// permutation table and only basic float arithmetic, `floor` and `sqrt` are involved,
+/// so the same seed produces bit-exact results on every platform.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Noise {
+    kind: NoiseKind,
+    seed: u32,
+}
+
+impl Noise {
+    pub const fn new(kind: NoiseKind, seed: u32) -> Self {
+        Self { kind, seed }
+    }
+
+    pub const fn kind(&self) -> NoiseKind {
+        self.kind
+    }
+
+    pub const fn seed(&self) -> u32 {
+        self.seed
+    }
+
+    pub fn sample_2d<Space>(&self, point: Vec2<Space, f32>) -> f32 {
+        let (x, y, seed) = (point.x, point.y, self.seed);
+        let value = match self.kind {
+            NoiseKind::Value => value_2d(seed, x, y),
+            NoiseKind::Perlin => perlin_2d(seed, x, y),
+            NoiseKind::Simplex => simplex_2d(seed, x, y),
+            NoiseKind::Worley => worley_2d(seed, x, y),
+        };
+        value.clamp(-1.0, 1.0)
+    }
+
+    pub fn sample_3d<Space>(&self, point: Vec3<Space, f32>) -> f32 {
+        let (x, y, z, seed) = (point.x, point.y, point.z, self.seed);
+        let value = match self.kind {
+            NoiseKind::Value => value_3d(seed, x, y, z),
+            NoiseKind::Perlin => perlin_3d(seed, x, y, z),
+            NoiseKind::Simplex => simplex_3d(seed, x, y, z),
+            NoiseKind::Worley => worley_3d(seed, x, y, z),
+        };
+        value.clamp(-1.0, 1.0)
+    }
+}
+
+/// Fractal Brownian motion, sums octaves of [`Noise`] with rising frequency and falling amplitude
+///
+/// The sum is divided by the total amplitude, so the result stays within `[-1, 1]`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Fbm {
+    noise: Noise,
+    octaves: u32,
+    lacunarity: f32,
+    gain: f32,
+}
+
+impl Fbm {
+    pub const MAX_OCTAVES: u32 = 16;
+
+    /// Returns `None` if `octaves` is zero or above [`Fbm::MAX_OCTAVES`]
+    pub const fn new(noise: Noise, octaves: u32) -> Option<Self> {
+        if octaves == 0 || octaves > Self::MAX_OCTAVES {
+            return None;
+        }
+
+        Some(Self {
+            noise,
+            octaves,
+            lacunarity: 2.0,
+            gain: 0.5,
+        })
+    }
+
+    /// Frequency multiplier per octave, returns `None` if it isn't positive and finite
+    pub fn with_lacunarity(self, lacunarity: f32) -> Option<Self> {
+        (lacunarity > 0.0 && lacunarity.is_finite()).then_some(Self { lacunarity, ..self })
+    }
+
+    /// Amplitude multiplier per octave, returns `None` if it isn't positive and finite
+    pub fn with_gain(self, gain: f32) -> Option<Self> {
+        (gain > 0.0 && gain.is_finite()).then_some(Self { gain, ..self })
+    }
+
+    pub const fn noise(&self) -> Noise {
+        self.noise
+    }
+
+    pub const fn octaves(&self) -> u32 {
+        self.octaves
+    }
+
+    pub fn sample_2d<Space>(&self, point: Vec2<Space, f32>) -> f32 {
+        self.sum(|noise, frequency| {
+            noise.sample_2d(Vec2::<Space, f32>::new(
+                point.x * frequency,
+                point.y * frequency,
+            ))
+        })
+    }
+
+    pub fn sample_3d<Space>(&self, point: Vec3<Space, f32>) -> f32 {
+        self.sum(|noise, frequency| {
+            noise.sample_3d(Vec3::<Space, f32>::new(
+                point.x * frequency,
+                point.y * frequency,
+                point.z * frequency,
+            ))
+        })
+    }
+
+    fn sum(&self, sample: impl Fn(Noise, f32) -> f32) -> f32 {
+        let (mut total, mut amplitudes) = (0.0, 0.0);
+        let (mut frequency, mut amplitude) = (1.0, 1.0);
+
+        for octave in 0..self.octaves {
+            // every octave gets its own seed, otherwise they line up at the origin
+            let noise = Noise::new(self.noise.kind, self.noise.seed.wrapping_add(octave));
+            total += sample(noise, frequency) * amplitude;
+            amplitudes += amplitude;
+            frequency *= self.lacunarity;
+            amplitude *= self.gain;
+        }
+
+        (total / amplitudes).clamp(-1.0, 1.0)
+    }
+}
+
+/// Integer hash of a lattice cell, see "lowbias32" by Chris Wellons
+fn hash<const N: usize>(seed: u32, cell: [i32; N]) -> u32 {
+    const PRIMES: [u32; 3] = [0x8da6_b343, 0xd816_3841, 0xcb1a_b31f];
+
+    cell.iter().zip(PRIMES).fold(mix(seed), |h, (c, prime)| {
+        mix(h ^ (*c as u32).wrapping_mul(prime))
+    })
+}
+
+fn mix(mut h: u32) -> u32 {
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x7feb_352d);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x846c_a68b);
+    h ^ (h >> 16)
+}
+
+/// Maps the upper 24 bits of a hash to `[0, 1)`
+fn unit(h: u32) -> f32 {
+    (h >> 8) as f32 * (1.0 / 16_777_216.0)
+}
+
+fn signed(h: u32) -> f32 {
+    unit(h) * 2.0 - 1.0
+}
+
+/// Quintic smoothstep, its first and second derivatives are zero at the lattice
+fn fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+/// Lattice cell containing `value` and the position within it
+fn cell(value: f32) -> (i32, f32) {
+    let floor = value.floor();
+    (floor as i32, value - floor)
+}
+
+fn gradient_2d(h: u32, x: f32, y: f32) -> f32 {
+    match h & 7 {
+        0 => x + y,
+        1 => -x + y,
+        2 => x - y,
+        3 => -x - y,
+        4 => x,
+        5 => -x,
+        6 => y,
+        _ => -y,
+    }
+}
+
+/// The twelve edge directions of a cube, padded to sixteen with a tetrahedron
+fn gradient_3d(h: u32, x: f32, y: f32, z: f32) -> f32 {
+    let h = h & 15;
+    let u = if h < 8 { x } else { y };
+    let v = match h {
+        0..4 => y,
+        12 | 14 => x,
+        _ => z,
+    };
+    let u = if h & 1 == 0 { u } else { -u };
+    let v = if h & 2 == 0 { v } else { -v };
+    u + v
+}
+
+fn value_2d(seed: u32, x: f32, y: f32) -> f32 {
+    let ((x0, fx), (y0, fy)) = (cell(x), cell(y));
+    let (x1, y1) = (x0.wrapping_add(1), y0.wrapping_add(1));
+    let corner = |x, y| signed(hash(seed, [x, y]));
+
+    let (u, v) = (fade(fx), fade(fy));
+    lerp(
+        lerp(corner(x0, y0), corner(x1, y0), u),
+        lerp(corner(x0, y1), corner(x1, y1), u),
+        v,
+    )
+}
+
+fn value_3d(seed: u32, x: f32, y: f32, z: f32) -> f32 {
+    let ((x0, fx), (y0, fy), (z0, fz)) = (cell(x), cell(y), cell(z));
+    let (x1, y1, z1) = (x0.wrapping_add(1), y0.wrapping_add(1), z0.wrapping_add(1));
+    let corner = |x, y, z| signed(hash(seed, [x, y, z]));
+
+    let (u, v, w) = (fade(fx), fade(fy), fade(fz));
+    let near = lerp(
+        lerp(corner(x0, y0, z0), corner(x1, y0, z0), u),
+        lerp(corner(x0, y1, z0), corner(x1, y1, z0), u),
+        v,
+    );
+    let far = lerp(
+        lerp(corner(x0, y0, z1), corner(x1, y0, z1), u),
+        lerp(corner(x0, y1, z1), corner(x1, y1, z1), u),
+        v,
+    );
+    lerp(near, far, w)
+}
+
+fn perlin_2d(seed: u32, x: f32, y: f32) -> f32 {
+    let ((x0, fx), (y0, fy)) = (cell(x), cell(y));
+    let (x1, y1) = (x0.wrapping_add(1), y0.wrapping_add(1));
+    let corner = |cx, cy, dx, dy| gradient_2d(hash(seed, [cx, cy]), dx, dy);
+
+    let (u, v) = (fade(fx), fade(fy));
+    lerp(
+        lerp(corner(x0, y0, fx, fy), corner(x1, y0, fx - 1.0, fy), u),
+        lerp(
+            corner(x0, y1, fx, fy - 1.0),
+            corner(x1, y1, fx - 1.0, fy - 1.0),
+            u,
+        ),
+        v,
+    )
+}
+
+fn perlin_3d(seed: u32, x: f32, y: f32, z: f32) -> f32 {
+    let ((x0, fx), (y0, fy), (z0, fz)) = (cell(x), cell(y), cell(z));
+    let (x1, y1, z1) = (x0.wrapping_add(1), y0.wrapping_add(1), z0.wrapping_add(1));
+    let (gx, gy, gz) = (fx - 1.0, fy - 1.0, fz - 1.0);
+    let corner = |cx, cy, cz, dx, dy, dz| gradient_3d(hash(seed, [cx, cy, cz]), dx, dy, dz);
+
+    let (u, v, w) = (fade(fx), fade(fy), fade(fz));
+    let near = lerp(
+        lerp(
+            corner(x0, y0, z0, fx, fy, fz),
+            corner(x1, y0, z0, gx, fy, fz),
+            u,
+        ),
+        lerp(
+            corner(x0, y1, z0, fx, gy, fz),
+            corner(x1, y1, z0, gx, gy, fz),
+            u,
+        ),
+        v,
+    );
+    let far = lerp(
+        lerp(
+            corner(x0, y0, z1, fx, fy, gz),
+            corner(x1, y0, z1, gx, fy, gz),
+            u,
+        ),
+        lerp(
+            corner(x0, y1, z1, fx, gy, gz),
+            corner(x1, y1, z1, gx, gy, gz),
+            u,
+        ),
+        v,
+    );
+    lerp(near, far, w)
+}
+
+fn simplex_2d(seed: u32, x: f32, y: f32) -> f32 {
+    let skew = (x + y) * SKEW_2D;
+    let (i, j) = ((x + skew).floor(), (y + skew).floor());
+    let unskew = (i + j) * UNSKEW_2D;
+    let (x0, y0) = (x - (i - unskew), y - (j - unskew));
+
+    // the lower triangle of the skewed cell is visited x first, the upper one y first
+    let (i1, j1) = if x0 > y0 { (1, 0) } else { (0, 1) };
+    let (x1, y1) = (x0 - i1 as f32 + UNSKEW_2D, y0 - j1 as f32 + UNSKEW_2D);
+    let (x2, y2) = (x0 - 1.0 + 2.0 * UNSKEW_2D, y0 - 1.0 + 2.0 * UNSKEW_2D);
+
+    let (i, j) = (i as i32, j as i32);
+    let corner = |ci: i32, cj: i32, x: f32, y: f32| {
+        let t = 0.5 - x * x - y * y;
+        if t < 0.0 {
+            0.0
+        } else {
+            let t = t * t;
+            t * t * gradient_2d(hash(seed, [ci, cj]), x, y)
+        }
+    };
+
+    let n0 = corner(i, j, x0, y0);
+    let n1 = corner(i.wrapping_add(i1), j.wrapping_add(j1), x1, y1);
+    let n2 = corner(i.wrapping_add(1), j.wrapping_add(1), x2, y2);
+    70.0 * (n0 + n1 + n2)
+}
+
+fn simplex_3d(seed: u32, x: f32, y: f32, z: f32) -> f32 {
+    let skew = (x + y + z) * SKEW_3D;
+    let (i, j, k) = ((x + skew).floor(), (y + skew).floor(), (z + skew).floor());
+    let unskew = (i + j + k) * UNSKEW_3D;
+    let (x0, y0, z0) = (x - (i - unskew), y - (j - unskew), z - (k - unskew));
+
+    // offsets of the second and third corner of the tetrahedron the point falls into
+    let ((i1, j1, k1), (i2, j2, k2)) = if x0 >= y0 {
+        if y0 >= z0 {
+            ((1, 0, 0), (1, 1, 0))
+        } else if x0 >= z0 {
+            ((1, 0, 0), (1, 0, 1))
+        } else {
+            ((0, 0, 1), (1, 0, 1))
+        }
+    } else if y0 < z0 {
+        ((0, 0, 1), (0, 1, 1))
+    } else if x0 < z0 {
+        ((0, 1, 0), (0, 1, 1))
+    } else {
+        ((0, 1, 0), (1, 1, 0))
+    };
+
+    let offset = |x: f32, corner: i32, steps: f32| x - corner as f32 + steps * UNSKEW_3D;
+    let (x1, y1, z1) = (
+        offset(x0, i1, 1.0),
+        offset(y0, j1, 1.0),
+        offset(z0, k1, 1.0),
+    );
+    let (x2, y2, z2) = (
+        offset(x0, i2, 2.0),
+        offset(y0, j2, 2.0),
+        offset(z0, k2, 2.0),
+    );
+    let (x3, y3, z3) = (offset(x0, 1, 3.0), offset(y0, 1, 3.0), offset(z0, 1, 3.0));
+
+    let (i, j, k) = (i as i32, j as i32, k as i32);
+    let corner = |[ci, cj, ck]: [i32; 3], x: f32, y: f32, z: f32| {
+        let t = 0.6 - x * x - y * y - z * z;
+        if t < 0.0 {
+            0.0
+        } else {
+            let t = t * t;
+            t * t * gradient_3d(hash(seed, [ci, cj, ck]), x, y, z)
+        }
+    };
+    let at =
+        |di: i32, dj: i32, dk: i32| [i.wrapping_add(di), j.wrapping_add(dj), k.wrapping_add(dk)];
+
+    let n0 = corner(at(0, 0, 0), x0, y0, z0);
+    let n1 = corner(at(i1, j1, k1), x1, y1, z1);
+    let n2 = corner(at(i2, j2, k2), x2, y2, z2);
+    let n3 = corner(at(1, 1, 1), x3, y3, z3);
+    32.0 * (n0 + n1 + n2 + n3)
+}
+
+fn worley_2d(seed: u32, x: f32, y: f32) -> f32 {
+    let ((x0, fx), (y0, fy)) = (cell(x), cell(y));
+
+    let mut closest = f32::INFINITY;
+    for dy in -1..=1 {
+        for dx in -1..=1 {
+            let h = hash(seed, [x0.wrapping_add(dx), y0.wrapping_add(dy)]);
+            let px = dx as f32 + unit(h) - fx;
+            let py = dy as f32 + unit(mix(h)) - fy;
+            closest = closest.min(px * px + py * py);
+        }
+    }
+
+    closest.sqrt().min(1.0) * 2.0 - 1.0
+}
+
+fn worley_3d(seed: u32, x: f32, y: f32, z: f32) -> f32 {
+    let ((x0, fx), (y0, fy), (z0, fz)) = (cell(x), cell(y), cell(z));
+
+    let mut closest = f32::INFINITY;
+    for dz in -1..=1 {
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                let cell = [
+                    x0.wrapping_add(dx),
+                    y0.wrapping_add(dy),
+                    z0.wrapping_add(dz),
+                ];
+                let h = hash(seed, cell);
+                let px = dx as f32 + unit(h) - fx;
+                let py = dy as f32 + unit(mix(h)) - fy;
+                let pz = dz as f32 + unit(mix(mix(h))) - fz;
+                closest = closest.min(px * px + py * py + pz * pz);
+            }
+        }
+    }
+
+    closest.sqrt().min(1.0) * 2.0 - 1.0
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{WorldPosition, WorldVec3};
+
+    const KINDS: [NoiseKind; 4] = [
+        NoiseKind::Value,
+        NoiseKind::Perlin,
+        NoiseKind::Simplex,
+        NoiseKind::Worley,
+    ];
+
+    #[test]
+    fn bit_exact_output() {
+        // (2D, 3D, 2D fBm, 3D fBm) for seed 42, these must never change
+        let expected = [
+            [0xbcce6a00, 0x3e85905e, 0xbd8c8b53, 0x3d1c53dd],
+            [0x3eae06f4, 0x3ec937af, 0x3dead995, 0x3e379e89],
+            [0x3e8ecb43, 0x3ea1bbdd, 0x3d9ee3c5, 0x3d2c99f2],
+            [0xbe66543c, 0x3e8234d0, 0xbe5b4e9b, 0x3dfe7304],
+        ];
+
+        let p2 = WorldPosition::new(1.37, -4.21);
+        let p3 = WorldVec3::new(1.37, -4.21, 0.58);
+        for (kind, expected) in KINDS.into_iter().zip(expected) {
+            let noise = Noise::new(kind, 42);
+            let fbm = Fbm::new(noise, 5).unwrap();
+            let actual = [
+                noise.sample_2d(p2).to_bits(),
+                noise.sample_3d(p3).to_bits(),
+                fbm.sample_2d(p2).to_bits(),
+                fbm.sample_3d(p3).to_bits(),
+            ];
+            assert_eq!(actual, expected, "{kind:?}");
+        }
+    }
+
+    #[test]
+    fn noise_is_coherent() {
+        for kind in KINDS {
+            let noise = Noise::new(kind, 7);
+            let (mut low, mut high) = (f32::INFINITY, f32::NEG_INFINITY);
+
+            for i in 0..2000 {
+                let p = WorldPosition::new(i as f32 * 0.173, i as f32 * -0.091);
+                let value = noise.sample_2d(p);
+                let nearby = noise.sample_2d(p + WorldPosition::new(1e-3, 0.0));
+                assert!((value - nearby).abs() < 0.05, "{kind:?}");
+
+                low = low.min(value);
+                high = high.max(value);
+            }
+            assert!(low < -0.3 && high > 0.3, "{kind:?} {low} {high}");
+        }
+
+        let perlin = Noise::new(NoiseKind::Perlin, 1);
+        assert_eq!(perlin.sample_2d(WorldPosition::new(3.0, -2.0)), 0.0);
+        assert_ne!(
+            Noise::new(NoiseKind::Value, 1).sample_2d(WorldPosition::new(0.5, 0.5)),
+            Noise::new(NoiseKind::Value, 2).sample_2d(WorldPosition::new(0.5, 0.5)),
+        );
+
+        assert!(Fbm::new(perlin, 0).is_none());
+        assert!(Fbm::new(perlin, 4).unwrap().with_gain(f32::NAN).is_none());
+    }
+}
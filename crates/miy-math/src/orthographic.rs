@@ -0,0 +1,267 @@
+use crate::{DepthRange, Mat4, ProjectionMatrix, WorldDimensions2D, WorldPosition};
+
+/// Where the world origin sits inside an [`Orthographic`] view
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum OrthographicAnchor {
+    /// x in `[-w/2, w/2]`, y in `[-h/2, h/2]`
+    Center,
+    /// x in `[0, w]`, y in `[0, h]`
+    BottomLeft,
+    /// x in `[-w/2, w/2]`, y in `[0, h]`
+    BottomCenter,
+    /// x in `[0, w]`, y in `[0, h]` growing downwards, like most UI and screen coordinates.
+    /// This flips the winding order of triangles.
+    TopLeft,
+}
+
+/// Orthographic view volume, turned into a [`ProjectionMatrix`] with [`Orthographic::projection`]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Orthographic {
+    left: f32,
+    right: f32,
+    bottom: f32,
+    top: f32,
+    near: f32,
+    far: f32,
+    depth_range: DepthRange,
+}
+
+impl Orthographic {
+    /// View of the world rectangle spanning from `left` to `right` and `bottom` to `top`.
+    /// `bottom` may be larger than `top` for a y-down view.
+    ///
+    /// Returns `None` if the rectangle is empty or not finite
+    pub fn new(left: f32, right: f32, bottom: f32, top: f32) -> Option<Self> {
+        let valid = [left, right, bottom, top].iter().all(|v| v.is_finite());
+        if !valid || left == right || bottom == top {
+            return None;
+        }
+
+        Some(Self {
+            left,
+            right,
+            bottom,
+            top,
+            near: 0.0,
+            far: 1.0,
+            depth_range: DepthRange::NegativeOneToOne,
+        })
+    }
+
+    /// View of the world rectangle with its bottom left corner at `origin`
+    ///
+    /// Returns `None` if the rectangle is not finite or too small to tell its edges apart at
+    /// `origin`
+    pub fn from_world_rect(origin: WorldPosition, dimensions: WorldDimensions2D) -> Option<Self> {
+        let width = dimensions.width().get();
+        let height = dimensions.height().get();
+
+        Self::new(origin.x, origin.x + width, origin.y, origin.y + height)
+    }
+
+    /// Returns `None` if the dimensions are not finite
+    pub fn from_anchor(dimensions: WorldDimensions2D, anchor: OrthographicAnchor) -> Option<Self> {
+        let width = dimensions.width().get();
+        let height = dimensions.height().get();
+
+        let (left, right, bottom, top) = match anchor {
+            OrthographicAnchor::Center => (-width / 2.0, width / 2.0, -height / 2.0, height / 2.0),
+            OrthographicAnchor::BottomLeft => (0.0, width, 0.0, height),
+            OrthographicAnchor::BottomCenter => (-width / 2.0, width / 2.0, 0.0, height),
+            OrthographicAnchor::TopLeft => (0.0, width, height, 0.0),
+        };
+
+        Self::new(left, right, bottom, top)
+    }
+
+    /// Sets the distance to the near and far clipping planes, defaults to `0.0` and `1.0`.
+    ///
+    /// Returns `None` if they are equal or not finite
+    pub fn with_near_far(self, near: f32, far: f32) -> Option<Self> {
+        if near == far || !near.is_finite() || !far.is_finite() {
+            return None;
+        }
+
+        Some(Self { near, far, ..self })
+    }
+
+    pub fn with_depth_range(self, depth_range: DepthRange) -> Self {
+        Self {
+            depth_range,
+            ..self
+        }
+    }
+
+    pub fn left(&self) -> f32 {
+        self.left
+    }
+
+    pub fn right(&self) -> f32 {
+        self.right
+    }
+
+    pub fn bottom(&self) -> f32 {
+        self.bottom
+    }
+
+    pub fn top(&self) -> f32 {
+        self.top
+    }
+
+    pub fn near(&self) -> f32 {
+        self.near
+    }
+
+    pub fn far(&self) -> f32 {
+        self.far
+    }
+
+    /// Column-major orthographic projection matrix, mapping world to clip space
+    pub fn projection(&self) -> ProjectionMatrix {
+        let (x, y, z, xa, ya, za) = self.scale_and_offset();
+
+        #[rustfmt::skip]
+        let values = [
+              x, 0.0, 0.0, 0.0,
+            0.0,   y, 0.0, 0.0,
+            0.0, 0.0,   z, 0.0,
+             xa,  ya,  za, 1.0,
+        ];
+
+        Mat4::from_cols_array(values).into()
+    }
+
+    /// The inverse of [`Orthographic::projection`], mapping clip back to world space
+    pub fn inverse_projection(&self) -> Mat4 {
+        let (x, y, z, xa, ya, za) = self.scale_and_offset();
+
+        #[rustfmt::skip]
+        let values = [
+             1.0 / x,      0.0,      0.0, 0.0,
+                 0.0,  1.0 / y,      0.0, 0.0,
+                 0.0,      0.0,  1.0 / z, 0.0,
+            -xa / x,  -ya / y,  -za / z, 1.0,
+        ];
+
+        Mat4::from_cols_array(values)
+    }
+
+    fn scale_and_offset(&self) -> (f32, f32, f32, f32, f32, f32) {
+        let Self {
+            left,
+            right,
+            bottom,
+            top,
+            near,
+            far,
+            depth_range,
+        } = *self;
+
+        let x = 2.0 / (right - left);
+        let y = 2.0 / (top - bottom);
+        let xa = -(right + left) / (right - left);
+        let ya = -(top + bottom) / (top - bottom);
+
+        let (z, za) = match depth_range {
+            DepthRange::NegativeOneToOne => (-2.0 / (far - near), -(far + near) / (far - near)),
+            DepthRange::ZeroToOne => (-1.0 / (far - near), -near / (far - near)),
+            DepthRange::ReversedZeroToOne => (1.0 / (far - near), far / (far - near)),
+        };
+
+        (x, y, z, xa, ya, za)
+    }
+}
+
+impl From<Orthographic> for ProjectionMatrix {
+    fn from(value: Orthographic) -> Self {
+        value.projection()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::WorldVec3;
+
+    fn to_clip(ortho: Orthographic, x: f32, y: f32, z: f32) -> [f32; 3] {
+        Mat4::from(ortho.projection())
+            .transform_point(WorldVec3::new(x, y, z))
+            .to_array()
+    }
+
+    #[test]
+    fn orthographic_anchors() {
+        let dimensions = WorldDimensions2D::new(200.0, 100.0).unwrap();
+
+        let center = Orthographic::from_anchor(dimensions, OrthographicAnchor::Center).unwrap();
+        assert_eq!(to_clip(center, 100.0, -50.0, 0.0), [1.0, -1.0, -1.0]);
+
+        let bottom_left =
+            Orthographic::from_anchor(dimensions, OrthographicAnchor::BottomLeft).unwrap();
+        assert_eq!(to_clip(bottom_left, 0.0, 0.0, 0.0), [-1.0, -1.0, -1.0]);
+
+        let top_left = Orthographic::from_anchor(dimensions, OrthographicAnchor::TopLeft).unwrap();
+        assert_eq!(to_clip(top_left, 0.0, 0.0, 0.0), [-1.0, 1.0, -1.0]);
+        assert_eq!(to_clip(top_left, 200.0, 100.0, 0.0), [1.0, -1.0, -1.0]);
+
+        let bottom_center =
+            Orthographic::from_anchor(dimensions, OrthographicAnchor::BottomCenter).unwrap();
+        #[rustfmt::skip]
+        assert_eq!(bottom_center.projection().to_array(), [
+            0.01,  0.0,  0.0, 0.0,
+             0.0, 0.02,  0.0, 0.0,
+             0.0,  0.0, -2.0, 0.0,
+             0.0, -1.0, -1.0, 1.0,
+        ]);
+
+        let rect = Orthographic::from_world_rect(WorldPosition::new(10.0, 20.0), dimensions)
+            .unwrap()
+            .with_near_far(-1.0, 1.0)
+            .unwrap()
+            .with_depth_range(DepthRange::ZeroToOne);
+        assert_eq!(to_clip(rect, 110.0, 70.0, 1.0), [0.0, 0.0, 0.0]);
+        assert_eq!(to_clip(rect, 110.0, 70.0, -1.0), [0.0, 0.0, 1.0]);
+
+        assert_eq!(Orthographic::new(0.0, 0.0, 0.0, 1.0), None);
+        let far_away = WorldPosition::new(1e20, 0.0);
+        assert_eq!(Orthographic::from_world_rect(far_away, dimensions), None);
+        let infinite = WorldDimensions2D::new(f32::INFINITY, 1.0).unwrap();
+        assert_eq!(
+            Orthographic::from_anchor(infinite, OrthographicAnchor::Center),
+            None
+        );
+        assert_eq!(
+            ProjectionMatrix::with_ortho_from_world(infinite).to_array(),
+            ProjectionMatrix::identity().to_array()
+        );
+        assert_eq!(center.with_near_far(1.0, 1.0), None);
+    }
+
+    #[test]
+    fn orthographic_inverse() {
+        let dimensions = WorldDimensions2D::new(320.0, 180.0).unwrap();
+        for anchor in [
+            OrthographicAnchor::Center,
+            OrthographicAnchor::BottomLeft,
+            OrthographicAnchor::BottomCenter,
+            OrthographicAnchor::TopLeft,
+        ] {
+            for depth_range in [
+                DepthRange::NegativeOneToOne,
+                DepthRange::ZeroToOne,
+                DepthRange::ReversedZeroToOne,
+            ] {
+                let ortho = Orthographic::from_anchor(dimensions, anchor)
+                    .unwrap()
+                    .with_near_far(-10.0, 10.0)
+                    .unwrap()
+                    .with_depth_range(depth_range);
+
+                let point = WorldVec3::new(12.0, -34.0, 5.0);
+                let clip = Mat4::from(ortho.projection()).transform_point(point);
+                let world = ortho.inverse_projection().transform_point(clip);
+                assert!((world - point).length() < 1e-4);
+            }
+        }
+    }
+}
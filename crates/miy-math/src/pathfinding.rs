@@ -0,0 +1,253 @@
+//! Shortest paths over tile grids and arbitrary graphs.
+//!
+//! Searches run over anything implementing [`Graph`]. [`SquareGraph`] and [`HexGraph`] adapt
+//! the [`grid`](crate::grid) coordinates with a cost function, other graphs implement the trait
+//! directly.
+//!
+//! - [`AStar`] finds a single path between two nodes.
+//! - [`DijkstraMap`] measures the cost of reaching the nearest of a set of goals from every node.
+//! - [`FlowField`] turns a [`DijkstraMap`] into the next step for every node, so any number of
+//!   agents can head for the goals without searching on their own.
+//!
+//! All searches are incremental, [`AStar::step`] and [`DijkstraMap::step`] expand a bounded
+//! number of nodes so long searches can be spread over several frames.
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::grid::{HexCoord, SquareCoord};
+
+mod astar;
+mod flow_field;
+
+pub use astar::{AStar, astar};
+pub use flow_field::{DijkstraMap, FlowField};
+
+/// Nodes connected by weighted, directed edges
+pub trait Graph {
+    type Node: Copy + Ord;
+
+    /// Calls `visit` with every node reachable from `node` in one step and the cost of that step.
+    /// Costs must be positive, edges with a cost that isn't are skipped by the searches.
+    fn neighbors(&self, node: Self::Node, visit: impl FnMut(Self::Node, f32));
+
+    /// Calls `visit` with every node that reaches `node` in one step and the cost of that step,
+    /// used by [`DijkstraMap`] to search backwards from the goals. Defaults to
+    /// [`Graph::neighbors`], which is only right if every edge costs the same in both directions.
+    fn predecessors(&self, node: Self::Node, visit: impl FnMut(Self::Node, f32)) {
+        self.neighbors(node, visit);
+    }
+
+    /// Estimated cost from `from` to `to`, used by [`AStar`] to search towards the goal.
+    /// Paths are only guaranteed to be the cheapest if this never overestimates.
+    fn heuristic(&self, from: Self::Node, to: Self::Node) -> f32 {
+        let _ = (from, to);
+        0.0
+    }
+}
+
+/// The nodes from start to goal, both included, and the sum of the step costs
+#[derive(Clone, PartialEq, Debug)]
+pub struct Path<Node> {
+    pub nodes: Vec<Node>,
+    pub cost: f32,
+}
+
+/// State of an incremental search
+#[derive(Clone, PartialEq, Debug)]
+pub enum Search<T> {
+    /// Not done yet, call `step` again
+    Pending,
+    Found(T),
+    /// Every reachable node has been expanded
+    Exhausted,
+}
+
+/// When a [`SquareGraph`] allows diagonal steps
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum Diagonal {
+    /// Orthogonal steps only
+    #[default]
+    Never,
+    /// Diagonal steps regardless of the cells next to them
+    Always,
+    /// Unless both orthogonal cells next to the step are blocked
+    NoSqueezing,
+    /// Only if both orthogonal cells next to the step are passable
+    NoCornerCutting,
+}
+
+/// A [`Graph`] over a square grid, `cost` gives the cost of entering a cell or `None` if it is
+/// blocked. Diagonal steps cost `√2` times as much.
+pub struct SquareGraph<Cost> {
+    diagonal: Diagonal,
+    cost: Cost,
+    heuristic_weight: f32,
+}
+
+impl<Cost: Fn(SquareCoord) -> Option<f32>> SquareGraph<Cost> {
+    pub fn new(diagonal: Diagonal, cost: Cost) -> Self {
+        Self {
+            diagonal,
+            cost,
+            heuristic_weight: 1.0,
+        }
+    }
+
+    /// The heuristic assumes every cell costs at least one to enter, scale it down if cheaper
+    /// cells exist or up to trade optimal paths for faster searches. Returns `None` unless
+    /// `weight` is finite and not negative.
+    pub fn with_heuristic_weight(self, weight: f32) -> Option<Self> {
+        (weight.is_finite() && weight >= 0.0).then_some(Self {
+            heuristic_weight: weight,
+            ..self
+        })
+    }
+
+    pub fn diagonal(&self) -> Diagonal {
+        self.diagonal
+    }
+
+    /// Calls `visit` with every passable cell one step from `node`, its cost and the factor of
+    /// the step length. Diagonals depend on the same two cells whichever way they are taken.
+    fn moves(&self, node: SquareCoord, mut visit: impl FnMut(SquareCoord, f32, f32)) {
+        for direction in SquareCoord::ORTHOGONAL {
+            let next = node.offset(direction.x, direction.y);
+            if let Some(cost) = (self.cost)(next) {
+                visit(next, cost, 1.0);
+            }
+        }
+
+        if self.diagonal == Diagonal::Never {
+            return;
+        }
+        for direction in SquareCoord::DIAGONAL {
+            let next = node.offset(direction.x, direction.y);
+            let Some(cost) = (self.cost)(next) else {
+                continue;
+            };
+            let beside = [node.offset(direction.x, 0), node.offset(0, direction.y)];
+            let open = beside
+                .into_iter()
+                .filter(|cell| (self.cost)(*cell).is_some())
+                .count();
+            let allowed = match self.diagonal {
+                Diagonal::Never => false,
+                Diagonal::Always => true,
+                Diagonal::NoSqueezing => open > 0,
+                Diagonal::NoCornerCutting => open == 2,
+            };
+            if allowed {
+                visit(next, cost, core::f32::consts::SQRT_2);
+            }
+        }
+    }
+}
+
+impl<Cost: Fn(SquareCoord) -> Option<f32>> Graph for SquareGraph<Cost> {
+    type Node = SquareCoord;
+
+    fn neighbors(&self, node: SquareCoord, mut visit: impl FnMut(SquareCoord, f32)) {
+        self.moves(node, |next, cost, length| visit(next, cost * length));
+    }
+
+    fn predecessors(&self, node: SquareCoord, mut visit: impl FnMut(SquareCoord, f32)) {
+        let Some(cost) = (self.cost)(node) else {
+            return;
+        };
+        self.moves(node, |previous, _, length| visit(previous, cost * length));
+    }
+
+    fn heuristic(&self, from: SquareCoord, to: SquareCoord) -> f32 {
+        let (dx, dy) = (from.x.abs_diff(to.x) as f32, from.y.abs_diff(to.y) as f32);
+        let distance = match self.diagonal {
+            Diagonal::Never => dx + dy,
+            // octile distance
+            _ => dx.max(dy) + (core::f32::consts::SQRT_2 - 1.0) * dx.min(dy),
+        };
+        distance * self.heuristic_weight
+    }
+}
+
+/// A [`Graph`] over a hex grid, `cost` gives the cost of entering a hex or `None` if it is blocked
+pub struct HexGraph<Cost> {
+    cost: Cost,
+    heuristic_weight: f32,
+}
+
+impl<Cost: Fn(HexCoord) -> Option<f32>> HexGraph<Cost> {
+    pub fn new(cost: Cost) -> Self {
+        Self {
+            cost,
+            heuristic_weight: 1.0,
+        }
+    }
+
+    /// See [`SquareGraph::with_heuristic_weight`]
+    pub fn with_heuristic_weight(self, weight: f32) -> Option<Self> {
+        (weight.is_finite() && weight >= 0.0).then_some(Self {
+            heuristic_weight: weight,
+            ..self
+        })
+    }
+}
+
+impl<Cost: Fn(HexCoord) -> Option<f32>> Graph for HexGraph<Cost> {
+    type Node = HexCoord;
+
+    fn neighbors(&self, node: HexCoord, mut visit: impl FnMut(HexCoord, f32)) {
+        for next in node.neighbors() {
+            if let Some(cost) = (self.cost)(next) {
+                visit(next, cost);
+            }
+        }
+    }
+
+    fn predecessors(&self, node: HexCoord, mut visit: impl FnMut(HexCoord, f32)) {
+        let Some(cost) = (self.cost)(node) else {
+            return;
+        };
+        for previous in node.neighbors() {
+            if (self.cost)(previous).is_some() {
+                visit(previous, cost);
+            }
+        }
+    }
+
+    fn heuristic(&self, from: HexCoord, to: HexCoord) -> f32 {
+        from.distance(to) as f32 * self.heuristic_weight
+    }
+}
+
+/// Entry of the open set, ordered so a `BinaryHeap` pops the lowest priority first
+struct Candidate<Node> {
+    priority: f32,
+    node: Node,
+}
+
+impl<Node: Ord> PartialEq for Candidate<Node> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<Node: Ord> Eq for Candidate<Node> {}
+
+impl<Node: Ord> PartialOrd for Candidate<Node> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Node: Ord> Ord for Candidate<Node> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .priority
+            .total_cmp(&self.priority)
+            .then_with(|| other.node.cmp(&self.node))
+    }
+}
+
+fn valid_cost(cost: f32) -> bool {
+    cost > 0.0 && cost.is_finite()
+}
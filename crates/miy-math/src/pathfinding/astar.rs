@@ -0,0 +1,196 @@
+use alloc::collections::{BTreeMap, BinaryHeap};
+use alloc::vec::Vec;
+
+use super::{Candidate, Graph, Path, Search, valid_cost};
+
+/// Incremental A* search from one node to another
+pub struct AStar<Node> {
+    goal: Node,
+    open: BinaryHeap<Candidate<Node>>,
+    /// Cheapest known cost to reach a node and the node it was reached from
+    visited: BTreeMap<Node, (f32, Option<Node>)>,
+    result: Search<Path<Node>>,
+}
+
+impl<Node: Copy + Ord> AStar<Node> {
+    pub fn new(start: Node, goal: Node) -> Self {
+        let mut visited = BTreeMap::new();
+        visited.insert(start, (0.0, None));
+        let mut open = BinaryHeap::new();
+        open.push(Candidate {
+            priority: 0.0,
+            node: start,
+        });
+        Self {
+            goal,
+            open,
+            visited,
+            result: Search::Pending,
+        }
+    }
+
+    pub fn goal(&self) -> Node {
+        self.goal
+    }
+
+    /// Expands at most `max_expansions` nodes. Once the search is done further calls return the
+    /// same result, `graph` should be the same for every call.
+    pub fn step<G: Graph<Node = Node>>(
+        &mut self,
+        graph: &G,
+        max_expansions: usize,
+    ) -> &Search<Path<Node>> {
+        for _ in 0..max_expansions {
+            if self.result != Search::Pending {
+                break;
+            }
+            self.result = self.expand(graph);
+        }
+        &self.result
+    }
+
+    /// Runs the search to the end
+    pub fn finish<G: Graph<Node = Node>>(mut self, graph: &G) -> Option<Path<Node>> {
+        while self.result == Search::Pending {
+            self.result = self.expand(graph);
+        }
+        match self.result {
+            Search::Found(path) => Some(path),
+            Search::Pending | Search::Exhausted => None,
+        }
+    }
+
+    pub fn result(&self) -> &Search<Path<Node>> {
+        &self.result
+    }
+
+    /// Number of nodes reached so far
+    pub fn visited(&self) -> usize {
+        self.visited.len()
+    }
+
+    fn expand<G: Graph<Node = Node>>(&mut self, graph: &G) -> Search<Path<Node>> {
+        let Some(Candidate { priority, node }) = self.open.pop() else {
+            return Search::Exhausted;
+        };
+        let Some(&(cost, _)) = self.visited.get(&node) else {
+            return Search::Pending;
+        };
+        // stale entry, the node was queued again with a lower cost
+        if priority > cost + graph.heuristic(node, self.goal) {
+            return Search::Pending;
+        }
+        if node == self.goal {
+            return Search::Found(self.path(node, cost));
+        }
+
+        graph.neighbors(node, |next, step| {
+            if !valid_cost(step) {
+                return;
+            }
+            let next_cost = cost + step;
+            let better = self
+                .visited
+                .get(&next)
+                .is_none_or(|(known, _)| next_cost < *known);
+            if better {
+                self.visited.insert(next, (next_cost, Some(node)));
+                self.open.push(Candidate {
+                    priority: next_cost + graph.heuristic(next, self.goal),
+                    node: next,
+                });
+            }
+        });
+        Search::Pending
+    }
+
+    fn path(&self, goal: Node, cost: f32) -> Path<Node> {
+        let mut nodes = Vec::new();
+        let mut current = Some(goal);
+        while let Some(node) = current {
+            nodes.push(node);
+            current = self.visited.get(&node).and_then(|(_, parent)| *parent);
+        }
+        nodes.reverse();
+        Path { nodes, cost }
+    }
+}
+
+/// Cheapest path from `start` to `goal`, `None` if `goal` can't be reached
+pub fn astar<G: Graph>(graph: &G, start: G::Node, goal: G::Node) -> Option<Path<G::Node>> {
+    AStar::new(start, goal).finish(graph)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::grid::{HexCoord, SquareCoord};
+    use crate::pathfinding::{Diagonal, HexGraph, SquareGraph};
+
+    /// Directed graph over `0..n` from an edge list
+    struct Edges(Vec<(u32, u32, f32)>);
+
+    impl Graph for Edges {
+        type Node = u32;
+
+        fn neighbors(&self, node: u32, mut visit: impl FnMut(u32, f32)) {
+            self.0
+                .iter()
+                .filter(|(from, _, _)| *from == node)
+                .for_each(|(_, to, cost)| visit(*to, *cost));
+        }
+    }
+
+    #[test]
+    fn graph_paths() {
+        let graph = Edges(vec![
+            (0, 1, 1.0),
+            (1, 3, 5.0),
+            (0, 2, 2.0),
+            (2, 3, 1.0),
+            (3, 4, 1.0),
+        ]);
+        let path = astar(&graph, 0, 4).unwrap();
+        assert_eq!(path.nodes, [0, 2, 3, 4]);
+        assert_eq!(path.cost, 4.0);
+        assert_eq!(astar(&graph, 4, 0), None);
+        assert_eq!(astar(&graph, 2, 2).unwrap().nodes, [2]);
+    }
+
+    #[test]
+    fn grid_paths() {
+        // a wall at x = 2 with a gap at y = 4
+        let open = |cell: SquareCoord| {
+            let inside = (0..8).contains(&cell.x) && (0..8).contains(&cell.y);
+            let wall = cell.x == 2 && cell.y != 4;
+            (inside && !wall).then_some(1.0)
+        };
+        let (start, goal) = (SquareCoord::new(0, 0), SquareCoord::new(4, 0));
+
+        let orthogonal = SquareGraph::new(Diagonal::Never, open);
+        let path = astar(&orthogonal, start, goal).unwrap();
+        assert_eq!(path.cost, 12.0);
+        assert!(
+            path.nodes
+                .windows(2)
+                .all(|w| w[0].manhattan_distance(w[1]) == 1)
+        );
+
+        let diagonal = SquareGraph::new(Diagonal::Always, open);
+        let path = astar(&diagonal, start, goal).unwrap();
+        assert!(path.cost < 12.0);
+
+        // searching a few nodes per frame ends with the same path
+        let mut search = AStar::new(start, goal);
+        let mut frames = 0;
+        while *search.step(&diagonal, 3) == Search::Pending {
+            frames += 1;
+        }
+        assert!(frames > 1);
+        assert_eq!(*search.result(), Search::Found(path));
+
+        let hex = HexGraph::new(|hex: HexCoord| (hex != HexCoord::new(1, 0)).then_some(1.0));
+        let path = astar(&hex, HexCoord::new(0, 0), HexCoord::new(2, 0)).unwrap();
+        assert_eq!((path.nodes.len(), path.cost), (4, 3.0));
+    }
+}
@@ -0,0 +1,248 @@
+use alloc::collections::{BTreeMap, BinaryHeap};
+use alloc::vec::Vec;
+
+use super::{Candidate, Graph, Search, valid_cost};
+
+/// Cost from the nearest of a set of goals to every node reachable from them, built
+/// incrementally with Dijkstra's algorithm.
+///
+/// The search runs backwards from the goals over [`Graph::predecessors`], so the costs are those
+/// of reaching the nearest goal even where edges cost more one way than the other.
+pub struct DijkstraMap<Node> {
+    open: BinaryHeap<Candidate<Node>>,
+    costs: BTreeMap<Node, f32>,
+    max_cost: f32,
+    done: bool,
+}
+
+impl<Node: Copy + Ord> DijkstraMap<Node> {
+    pub fn new(goals: impl IntoIterator<Item = Node>) -> Self {
+        let mut costs = BTreeMap::new();
+        let mut open = BinaryHeap::new();
+        for goal in goals {
+            costs.insert(goal, 0.0);
+            open.push(Candidate {
+                priority: 0.0,
+                node: goal,
+            });
+        }
+        Self {
+            open,
+            costs,
+            max_cost: f32::INFINITY,
+            done: false,
+        }
+    }
+
+    /// Stops at nodes further than `max_cost` from the goals, needed on unbounded graphs.
+    /// Returns `None` if `max_cost` is negative or NaN.
+    pub fn with_max_cost(self, max_cost: f32) -> Option<Self> {
+        (max_cost >= 0.0).then_some(Self { max_cost, ..self })
+    }
+
+    /// Builds the whole map
+    pub fn build<G: Graph<Node = Node>>(mut self, graph: &G) -> Self {
+        while !self.done {
+            self.expand(graph);
+        }
+        self
+    }
+
+    /// Expands at most `max_expansions` nodes, returns [`Search::Found`] once the map is complete
+    pub fn step<G: Graph<Node = Node>>(&mut self, graph: &G, max_expansions: usize) -> Search<()> {
+        for _ in 0..max_expansions {
+            if self.done {
+                break;
+            }
+            self.expand(graph);
+        }
+        match self.done {
+            true => Search::Found(()),
+            false => Search::Pending,
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.done
+    }
+
+    /// Cost of `node`, `None` if it hasn't been reached. Only final once the map is complete.
+    pub fn cost(&self, node: Node) -> Option<f32> {
+        self.costs.get(&node).copied()
+    }
+
+    /// All reached nodes and their costs
+    pub fn costs(&self) -> impl Iterator<Item = (Node, f32)> + '_ {
+        self.costs.iter().map(|(node, cost)| (*node, *cost))
+    }
+
+    /// The neighbour of `node` on a cheapest path to a goal, the one minimizing the cost of the
+    /// step plus its own cost. `None` at a goal or if no step matches the cost of `node`.
+    pub fn downhill<G: Graph<Node = Node>>(&self, graph: &G, node: Node) -> Option<Node> {
+        let cost = self.cost(node)?;
+        let mut best: Option<(f32, Node)> = None;
+        graph.neighbors(node, |next, step| {
+            if let Some(through) = self
+                .cost(next)
+                .filter(|_| valid_cost(step))
+                .map(|next| step + next)
+                && best.is_none_or(|(cheapest, _)| through < cheapest)
+            {
+                best = Some((through, next));
+            }
+        });
+        // the step that set the cost of `node` sums to exactly that cost
+        best.filter(|(through, _)| *through <= cost)
+            .map(|(_, next)| next)
+    }
+
+    fn expand<G: Graph<Node = Node>>(&mut self, graph: &G) {
+        let Some(Candidate { priority, node }) = self.open.pop() else {
+            self.done = true;
+            return;
+        };
+        if self.costs.get(&node).is_some_and(|cost| priority > *cost) {
+            return;
+        }
+
+        graph.predecessors(node, |previous, step| {
+            let previous_cost = step + priority;
+            if !valid_cost(step) || previous_cost > self.max_cost {
+                return;
+            }
+            if self
+                .costs
+                .get(&previous)
+                .is_none_or(|known| previous_cost < *known)
+            {
+                self.costs.insert(previous, previous_cost);
+                self.open.push(Candidate {
+                    priority: previous_cost,
+                    node: previous,
+                });
+            }
+        });
+    }
+}
+
+/// The next step towards the nearest goal for every node of a [`DijkstraMap`]
+pub struct FlowField<Node> {
+    next: BTreeMap<Node, Node>,
+}
+
+impl<Node: Copy + Ord> FlowField<Node> {
+    pub fn new<G: Graph<Node = Node>>(graph: &G, map: &DijkstraMap<Node>) -> Self {
+        let next = map
+            .costs
+            .keys()
+            .filter_map(|node| Some((*node, map.downhill(graph, *node)?)))
+            .collect();
+        Self { next }
+    }
+
+    /// Where to go from `node`, `None` at a goal or if `node` can't reach one
+    pub fn next(&self, node: Node) -> Option<Node> {
+        self.next.get(&node).copied()
+    }
+
+    /// Follows the field from `node` to a goal, `node` included
+    pub fn path(&self, node: Node) -> Vec<Node> {
+        let mut nodes = alloc::vec![node];
+        let mut current = node;
+        while let Some(next) = self.next(current) {
+            nodes.push(next);
+            current = next;
+        }
+        nodes
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Rng;
+    use crate::grid::SquareCoord;
+    use crate::pathfinding::{Diagonal, SquareGraph, astar};
+
+    #[test]
+    fn flows_to_nearest_goal() {
+        let open = |cell: SquareCoord| {
+            let inside = (0..10).contains(&cell.x) && (0..10).contains(&cell.y);
+            let wall = cell.y == 5 && cell.x < 9;
+            (inside && !wall).then_some(1.0)
+        };
+        let graph = SquareGraph::new(Diagonal::NoCornerCutting, open);
+        let goals = [SquareCoord::new(0, 0), SquareCoord::new(9, 9)];
+
+        let mut map = DijkstraMap::new(goals);
+        let mut frames = 0;
+        while map.step(&graph, 10) == Search::Pending {
+            frames += 1;
+        }
+        assert!(frames > 1);
+
+        // matches the cheaper of the two A* searches everywhere
+        for (node, cost) in map.costs() {
+            let nearest = goals
+                .iter()
+                .filter_map(|goal| astar(&graph, node, *goal))
+                .map(|path| path.cost)
+                .fold(f32::INFINITY, f32::min);
+            assert!((cost - nearest).abs() < 1e-4);
+        }
+
+        let field = FlowField::new(&graph, &map);
+        assert_eq!(field.next(goals[0]), None);
+        let path = field.path(SquareCoord::new(8, 6));
+        assert_eq!(path.last(), Some(&goals[1]));
+        let path = field.path(SquareCoord::new(3, 4));
+        assert_eq!(path.last(), Some(&goals[0]));
+
+        let limited = DijkstraMap::new([goals[0]])
+            .with_max_cost(2.0)
+            .unwrap()
+            .build(&graph);
+        assert_eq!(limited.cost(SquareCoord::new(2, 0)), Some(2.0));
+        assert_eq!(limited.cost(SquareCoord::new(3, 0)), None);
+    }
+
+    #[test]
+    fn weighted_paths_match_astar() {
+        let mut rng = Rng::new(18);
+        for _ in 0..20 {
+            // entering a cell costs between 1 and 4.5, a fifth of the cells are walls
+            let mut cells = [[None; 10]; 10];
+            for cell in cells.iter_mut().flatten() {
+                let weight = 1.0 + rng.range_u32(0..8).unwrap() as f32 * 0.5;
+                *cell = (!rng.bool(0.2)).then_some(weight);
+            }
+            cells[0][0] = Some(1.0);
+            let cost = |cell: SquareCoord| {
+                let x = usize::try_from(cell.x).ok()?;
+                let y = usize::try_from(cell.y).ok()?;
+                *cells.get(y)?.get(x)?
+            };
+            let graph = SquareGraph::new(Diagonal::Always, cost);
+            let goal = SquareCoord::new(0, 0);
+            let map = DijkstraMap::new([goal]).build(&graph);
+            let field = FlowField::new(&graph, &map);
+
+            for (node, expected) in map.costs() {
+                let optimal = astar(&graph, node, goal).unwrap().cost;
+                assert!((expected - optimal).abs() < 1e-3, "{node:?}");
+
+                let path = field.path(node);
+                assert_eq!(path.last(), Some(&goal));
+                let mut travelled = 0.0;
+                for step in path.windows(2) {
+                    graph.neighbors(step[0], |next, cost| {
+                        if next == step[1] {
+                            travelled += cost;
+                        }
+                    });
+                }
+                assert!((travelled - optimal).abs() < 1e-3, "{node:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,522 @@
+//! Polygon measurements, hulls, simplification and triangulation.
+//!
+//! Polygons are slices of points with an implied edge from the last back to the first point.
+//! Counter-clockwise winding is positive, like everywhere else in the crate.
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::Vec2;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Winding {
+    CounterClockwise,
+    Clockwise,
+}
+
+/// Twice the area of the triangle `a`, `b`, `c`, positive if it winds counter-clockwise
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "the orientation is the cross product of two finite edge vectors"
+)]
+fn orient<Space>(a: Vec2<Space, f32>, b: Vec2<Space, f32>, c: Vec2<Space, f32>) -> f32 {
+    (b - a).perp_dot(c - a)
+}
+
+fn edges<Space>(
+    points: &[Vec2<Space, f32>],
+) -> impl Iterator<Item = (Vec2<Space, f32>, Vec2<Space, f32>)> {
+    points
+        .iter()
+        .copied()
+        .zip(points.iter().copied().cycle().skip(1))
+}
+
+/// Area with a sign, positive for counter-clockwise polygons
+pub fn signed_area<Space>(points: &[Vec2<Space, f32>]) -> f32 {
+    edges(points).map(|(a, b)| a.perp_dot(b)).sum::<f32>() * 0.5
+}
+
+pub fn area<Space>(points: &[Vec2<Space, f32>]) -> f32 {
+    signed_area(points).abs()
+}
+
+/// `None` if the polygon has no area
+pub fn winding<Space>(points: &[Vec2<Space, f32>]) -> Option<Winding> {
+    let area = signed_area(points);
+    if area > 0.0 {
+        Some(Winding::CounterClockwise)
+    } else if area < 0.0 {
+        Some(Winding::Clockwise)
+    } else {
+        None
+    }
+}
+
+/// Center of mass of the enclosed area, `None` if the polygon has no area
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "the centroid sums area weighted corner pairs, infinite only for degenerate huge input"
+)]
+pub fn centroid<Space>(points: &[Vec2<Space, f32>]) -> Option<Vec2<Space, f32>> {
+    let area = signed_area(points);
+    if area == 0.0 || !area.is_finite() {
+        return None;
+    }
+
+    let sum = edges(points).fold(Vec2::<Space, f32>::ZERO, |sum, (a, b)| {
+        sum + (a + b) * a.perp_dot(b)
+    });
+    Some(sum / (6.0 * area))
+}
+
+/// Even-odd test, works for any winding and for self-intersecting polygons
+pub fn contains<Space>(points: &[Vec2<Space, f32>], point: Vec2<Space, f32>) -> bool {
+    let mut inside = false;
+    for (a, b) in edges(points) {
+        if (a.y > point.y) != (b.y > point.y) {
+            let x = a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x);
+            if point.x < x {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+/// Smallest convex polygon containing all `points`, counter-clockwise without collinear points
+pub fn convex_hull<Space>(points: &[Vec2<Space, f32>]) -> Vec<Vec2<Space, f32>> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    // Andrew's monotone chain, the lower half left to right and the upper half back
+    let mut hull: Vec<Vec2<Space, f32>> = Vec::with_capacity(sorted.len().saturating_add(1));
+    let push = |hull: &mut Vec<Vec2<Space, f32>>, floor: usize, point: Vec2<Space, f32>| {
+        while hull.len() >= floor
+            && let [.., a, b] = hull[..]
+            && orient(a, b, point) <= 0.0
+        {
+            hull.pop();
+        }
+        hull.push(point);
+    };
+    for point in &sorted {
+        push(&mut hull, 2, *point);
+    }
+    let lower = hull.len().saturating_add(1);
+    for point in sorted.iter().rev().skip(1) {
+        push(&mut hull, lower, *point);
+    }
+    // the upper chain ends at the first point again
+    hull.pop();
+    hull
+}
+
+/// Douglas-Peucker simplification of an open polyline, keeps both ends and every point further
+/// than `tolerance` from the simplified line. Repeat the first point at the end to simplify a
+/// closed outline.
+pub fn simplify<Space>(points: &[Vec2<Space, f32>], tolerance: f32) -> Vec<Vec2<Space, f32>> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+
+    let last = points.len().saturating_sub(1);
+    let mut keep = alloc::vec![false; points.len()];
+    keep[0] = true;
+    keep[last] = true;
+
+    let mut ranges = alloc::vec![(0, last)];
+    while let Some((first, last)) = ranges.pop() {
+        let (a, b) = (points[first], points[last]);
+        let farthest = (first..last)
+            .skip(1)
+            .map(|i| (i, distance_to_segment(points[i], a, b)))
+            .max_by(|(_, x), (_, y)| x.total_cmp(y));
+
+        if let Some((index, distance)) = farthest
+            && distance > tolerance
+        {
+            keep[index] = true;
+            ranges.push((first, index));
+            ranges.push((index, last));
+        }
+    }
+
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(point, keep)| keep.then_some(*point))
+        .collect()
+}
+
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "projecting onto the segment clamps the parameter, the closest point stays on it"
+)]
+fn distance_to_segment<Space>(
+    point: Vec2<Space, f32>,
+    a: Vec2<Space, f32>,
+    b: Vec2<Space, f32>,
+) -> f32 {
+    let edge = b - a;
+    let length_squared = edge.length_squared();
+    let t = if length_squared > 0.0 {
+        ((point - a).dot(edge) / length_squared).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    point.distance(a + edge * t)
+}
+
+/// Triangles covering a polygon, ready to upload as vertex and index buffers
+///
+/// Indices are `u32`, draw them with `ElementKind::UNSIGNED_INT` and [`Triangulation::index_count`].
+/// Every triangle winds counter-clockwise.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Triangulation<Space> {
+    vertices: Vec<Vec2<Space, f32>>,
+    indices: Vec<u32>,
+}
+
+impl<Space> Triangulation<Space> {
+    /// The outline followed by every hole, in the order they were passed in
+    pub fn vertices(&self) -> &[Vec2<Space, f32>] {
+        &self.vertices
+    }
+
+    /// Three indices into [`Triangulation::vertices`] per triangle
+    pub fn indices(&self) -> &[u32] {
+        &self.indices
+    }
+
+    pub fn index_count(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// The index buffer in native byte order
+    pub fn index_bytes(&self) -> &[u8] {
+        // SAFETY: `u32` has no padding and any byte is a valid `u8`, the slice covers exactly the
+        // initialized indices and borrows `self` for as long as they can't change
+        unsafe {
+            core::slice::from_raw_parts(
+                self.indices.as_ptr().cast(),
+                core::mem::size_of_val(self.indices.as_slice()),
+            )
+        }
+    }
+
+    pub fn triangles(&self) -> impl Iterator<Item = [Vec2<Space, f32>; 3]> + '_ {
+        self.indices
+            .as_chunks::<3>()
+            .0
+            .iter()
+            .map(|triangle| triangle.map(|index| self.vertices[index as usize]))
+    }
+}
+
+/// Ear-clipping triangulation of a simple polygon with holes
+///
+/// Outline and holes can wind either way, holes must lie inside the outline and must not touch
+/// each other. Returns `None` if a polygon has less than three points, a hole lies outside of
+/// the outline, or the outline intersects itself so that no ear can be found.
+pub fn triangulate<Space>(
+    outline: &[Vec2<Space, f32>],
+    holes: &[&[Vec2<Space, f32>]],
+) -> Option<Triangulation<Space>> {
+    if outline.len() < 3 || holes.iter().any(|hole| hole.len() < 3) {
+        return None;
+    }
+
+    let mut vertices = outline.to_vec();
+    for hole in holes {
+        vertices.extend_from_slice(hole);
+    }
+    u32::try_from(vertices.len()).ok()?;
+
+    let ring_of = |range: Range<usize>, winding: Winding| {
+        let actual = self::winding(&vertices[range.clone()]);
+        let mut ring: Vec<u32> = range.map(|i| i as u32).collect();
+        if actual.is_some_and(|actual| actual != winding) {
+            ring.reverse();
+        }
+        ring
+    };
+
+    let mut ring = ring_of(0..outline.len(), Winding::CounterClockwise);
+    let mut hole_rings = Vec::with_capacity(holes.len());
+    let mut start = outline.len();
+    for hole in holes {
+        // the total was checked to fit a `u32` above
+        let end = start.saturating_add(hole.len());
+        hole_rings.push(ring_of(start..end, Winding::Clockwise));
+        start = end;
+    }
+
+    // holes are bridged right to left, so later bridges never cross earlier ones
+    let rightmost = |ring: &[u32]| {
+        ring.iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| {
+                let (a, b) = (vertices[**a as usize], vertices[**b as usize]);
+                a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y))
+            })
+            .map(|(position, _)| position)
+            .unwrap_or(0)
+    };
+    hole_rings.sort_by(|a, b| {
+        let (a, b) = (
+            vertices[a[rightmost(a)] as usize],
+            vertices[b[rightmost(b)] as usize],
+        );
+        b.x.total_cmp(&a.x)
+    });
+    for hole in hole_rings {
+        let start = rightmost(&hole);
+        let bridge = find_bridge(&vertices, &ring, vertices[hole[start] as usize])?;
+
+        let mut merged =
+            Vec::with_capacity(ring.len().saturating_add(hole.len()).saturating_add(2));
+        merged.extend_from_slice(&ring[..=bridge]);
+        merged.extend(hole[start..].iter().chain(&hole[..=start]));
+        merged.extend_from_slice(&ring[bridge..]);
+        ring = merged;
+    }
+
+    let indices = clip_ears(&vertices, ring)?;
+    Some(Triangulation { vertices, indices })
+}
+
+/// Position in `ring` of a vertex that can be connected to `point` without crossing an edge
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "the directions towards the candidates are differences of finite vertices"
+)]
+fn find_bridge<Space>(
+    vertices: &[Vec2<Space, f32>],
+    ring: &[u32],
+    point: Vec2<Space, f32>,
+) -> Option<usize> {
+    let at = |position: usize| vertices[ring[position] as usize];
+    let next = |position: usize| neighbours(position, ring.len()).1;
+
+    // closest edge hit by a ray going right from `point`
+    let mut hit: Option<(f32, usize)> = None;
+    for position in 0..ring.len() {
+        let (a, b) = (at(position), at(next(position)));
+        if (a.y > point.y) == (b.y > point.y) || a.y == b.y {
+            continue;
+        }
+        let x = a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x);
+        if x >= point.x && hit.is_none_or(|(closest, _)| x < closest) {
+            // the endpoint further right is the candidate, it lies beyond `point`
+            let candidate = if a.x > b.x { position } else { next(position) };
+            hit = Some((x, candidate));
+        }
+    }
+    let (x, mut best) = hit?;
+
+    // reflex vertices inside the triangle between point, hit and candidate would block the
+    // bridge, the one closest in angle to the ray is visible
+    let hit_point = Vec2::new(x, point.y);
+    let candidate = at(best);
+    let mut best_angle = angle_to_ray(candidate - point);
+    for position in 0..ring.len() {
+        let vertex = at(position);
+        if position == best || vertex == candidate || vertex.x < point.x {
+            continue;
+        }
+        let previous = at(neighbours(position, ring.len()).0);
+        let reflex = orient(previous, vertex, at(next(position))) <= 0.0;
+        if reflex && in_triangle(vertex, point, hit_point, candidate) {
+            let angle = angle_to_ray(vertex - point);
+            if angle > best_angle {
+                best_angle = angle;
+                best = position;
+            }
+        }
+    }
+
+    Some(best)
+}
+
+/// Positions before and after `position` in a ring of `len` vertices
+fn neighbours(position: usize, len: usize) -> (usize, usize) {
+    let previous = position.checked_sub(1).unwrap_or(len.saturating_sub(1));
+    let next = position.saturating_add(1);
+    (previous, if next < len { next } else { 0 })
+}
+
+/// Cosine between `direction` and the x axis, larger is closer
+fn angle_to_ray<Space>(direction: Vec2<Space, f32>) -> f32 {
+    direction
+        .normalize()
+        .map_or(f32::NEG_INFINITY, |direction| direction.x)
+}
+
+/// Inclusive test, works for both windings
+fn in_triangle<Space>(
+    point: Vec2<Space, f32>,
+    a: Vec2<Space, f32>,
+    b: Vec2<Space, f32>,
+    c: Vec2<Space, f32>,
+) -> bool {
+    let (ab, bc, ca) = (
+        orient(a, b, point),
+        orient(b, c, point),
+        orient(c, a, point),
+    );
+    (ab >= 0.0 && bc >= 0.0 && ca >= 0.0) || (ab <= 0.0 && bc <= 0.0 && ca <= 0.0)
+}
+
+#[expect(
+    clippy::arithmetic_side_effects,
+    reason = "straight corners are detected from the dot product of their two edges"
+)]
+fn clip_ears<Space>(vertices: &[Vec2<Space, f32>], mut ring: Vec<u32>) -> Option<Vec<u32>> {
+    let mut indices = Vec::with_capacity(ring.len().saturating_sub(2).saturating_mul(3));
+    let at = |index: u32| vertices[index as usize];
+
+    while ring.len() > 3 {
+        let len = ring.len();
+        let corners = |i: usize| {
+            let (previous, next) = neighbours(i, len);
+            (ring[previous], ring[i], ring[next])
+        };
+
+        let ear = (0..len).find(|&i| {
+            let (a, b, c) = corners(i);
+            let (pa, pb, pc) = (at(a), at(b), at(c));
+            orient(pa, pb, pc) > 0.0
+                && ring.iter().all(|&other| {
+                    // bridge vertices appear twice, copies of the corners never block an ear
+                    let point = at(other);
+                    point == pa || point == pb || point == pc || !in_triangle(point, pa, pb, pc)
+                })
+        });
+
+        match ear {
+            Some(i) => {
+                let (a, b, c) = corners(i);
+                indices.extend([a, b, c]);
+                ring.remove(i);
+            }
+            None => {
+                // a vertex in the middle of a straight edge is not an ear, but it can go
+                let straight = (0..len).find(|&i| {
+                    let (a, b, c) = corners(i);
+                    let (pa, pb, pc) = (at(a), at(b), at(c));
+                    orient(pa, pb, pc) == 0.0 && (pa - pb).dot(pc - pb) <= 0.0
+                })?;
+                ring.remove(straight);
+            }
+        }
+    }
+
+    if let [a, b, c] = ring[..]
+        && orient(at(a), at(b), at(c)) > 0.0
+    {
+        indices.extend([a, b, c]);
+    }
+    Some(indices)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::WorldPosition;
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "test squares sit at small offsets around their center"
+    )]
+    fn square(center: WorldPosition, half: f32) -> Vec<WorldPosition> {
+        vec![
+            center + WorldPosition::new(-half, -half),
+            center + WorldPosition::new(half, -half),
+            center + WorldPosition::new(half, half),
+            center + WorldPosition::new(-half, half),
+        ]
+    }
+
+    fn triangulated_area(triangulation: &Triangulation<crate::space::World>) -> f32 {
+        triangulation
+            .triangles()
+            .map(|[a, b, c]| {
+                let area = orient(a, b, c) * 0.5;
+                assert!(area > 0.0);
+                area
+            })
+            .sum()
+    }
+
+    #[test]
+    fn measurements() {
+        let mut points = square(WorldPosition::new(2.0, 1.0), 1.0);
+        assert_eq!(signed_area(&points), 4.0);
+        assert_eq!(winding(&points), Some(Winding::CounterClockwise));
+        assert_eq!(centroid(&points), Some(WorldPosition::new(2.0, 1.0)));
+        assert!(contains(&points, WorldPosition::new(2.5, 1.5)));
+        assert!(!contains(&points, WorldPosition::new(3.5, 1.5)));
+
+        points.reverse();
+        assert_eq!(signed_area(&points), -4.0);
+        assert_eq!(winding(&points), Some(Winding::Clockwise));
+        assert_eq!(centroid(&points), Some(WorldPosition::new(2.0, 1.0)));
+        assert_eq!(winding(&points[..2]), None);
+    }
+
+    #[test]
+    fn hull_and_simplify() {
+        let mut points = square(WorldPosition::new(0.0, 0.0), 2.0);
+        points.extend([
+            WorldPosition::new(0.0, 0.0),
+            WorldPosition::new(1.0, -1.5),
+            WorldPosition::new(0.0, -2.0),
+        ]);
+        let hull = convex_hull(&points);
+        assert_eq!(hull, square(WorldPosition::new(0.0, 0.0), 2.0));
+
+        let line = [
+            WorldPosition::new(0.0, 0.0),
+            WorldPosition::new(1.0, 0.05),
+            WorldPosition::new(2.0, -0.05),
+            WorldPosition::new(3.0, 2.0),
+            WorldPosition::new(4.0, 2.0),
+        ];
+        assert_eq!(simplify(&line, 0.1), [line[0], line[2], line[3], line[4]]);
+        assert_eq!(simplify(&line, 10.0), [line[0], line[4]]);
+    }
+
+    #[test]
+    fn ear_clipping() {
+        // an L shape, concave and clockwise
+        let mut outline = vec![
+            WorldPosition::new(0.0, 0.0),
+            WorldPosition::new(2.0, 0.0),
+            WorldPosition::new(2.0, 1.0),
+            WorldPosition::new(1.0, 1.0),
+            WorldPosition::new(1.0, 2.0),
+            WorldPosition::new(0.0, 2.0),
+        ];
+        outline.reverse();
+        let triangulation = triangulate(&outline, &[]).unwrap();
+        assert_eq!(triangulation.index_count(), 12);
+        assert!((triangulated_area(&triangulation) - 3.0).abs() < 1e-5);
+        assert_eq!(triangulation.index_bytes().len(), 48);
+
+        let outer = square(WorldPosition::new(0.0, 0.0), 4.0);
+        let left = square(WorldPosition::new(-2.0, 0.0), 1.0);
+        let right = square(WorldPosition::new(2.0, 1.0), 1.0);
+        let triangulation = triangulate(&outer, &[&left, &right]).unwrap();
+        assert_eq!(triangulation.vertices().len(), 12);
+        assert!((triangulated_area(&triangulation) - (64.0 - 8.0)).abs() < 1e-4);
+
+        let outside = square(WorldPosition::new(10.0, 0.0), 1.0);
+        assert!(triangulate(&outer, &[&outside]).is_none());
+        assert!(triangulate(&outer[..2], &[]).is_none());
+    }
+}
@@ -1,11 +1,3 @@
-#[derive(Copy, Clone, Debug, PartialEq)]
-pub struct WorldPosition {
-    pub x: f32,
-    pub y: f32,
-}
+use crate::{space, vector::Vec2};
 
-impl WorldPosition {
-    pub fn new(x: f32, y: f32) -> Self {
-        Self { x, y }
-    }
-}
+pub type WorldPosition<Kind = f32> = Vec2<space::World, Kind>;
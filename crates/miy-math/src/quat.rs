@@ -0,0 +1,307 @@
+use core::ops::{Mul, MulAssign};
+
+use crate::{Mat4, Radians, Vec3};
+
+/// Unit quaternion describing a rotation in 3D
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Quat {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Quat {
+    pub const IDENTITY: Self = Self::from_xyzw(0.0, 0.0, 0.0, 1.0);
+
+    /// Takes the components as they are, make sure they are normalized
+    pub const fn from_xyzw(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Counter-clockwise rotation around `axis`, returns `None` if the axis can't be normalized
+    pub fn from_axis_angle<Space>(
+        axis: Vec3<Space, f32>,
+        angle: impl Into<Radians>,
+    ) -> Option<Self> {
+        let axis = axis.normalize()?;
+        let (sin, cos) = half_sin_cos(angle.into());
+        Some(Self::from_xyzw(
+            axis.x * sin,
+            axis.y * sin,
+            axis.z * sin,
+            cos,
+        ))
+    }
+
+    pub fn from_rotation_x(angle: impl Into<Radians>) -> Self {
+        let (sin, cos) = half_sin_cos(angle.into());
+        Self::from_xyzw(sin, 0.0, 0.0, cos)
+    }
+
+    pub fn from_rotation_y(angle: impl Into<Radians>) -> Self {
+        let (sin, cos) = half_sin_cos(angle.into());
+        Self::from_xyzw(0.0, sin, 0.0, cos)
+    }
+
+    pub fn from_rotation_z(angle: impl Into<Radians>) -> Self {
+        let (sin, cos) = half_sin_cos(angle.into());
+        Self::from_xyzw(0.0, 0.0, sin, cos)
+    }
+
+    /// Rotates by `roll` around z first, then by `pitch` around x and by `yaw` around y last,
+    /// the usual order for a camera or character looking down the negative z axis
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "chains three unit quaternions"
+    )]
+    pub fn from_euler(
+        yaw: impl Into<Radians>,
+        pitch: impl Into<Radians>,
+        roll: impl Into<Radians>,
+    ) -> Self {
+        Self::from_rotation_y(yaw) * Self::from_rotation_x(pitch) * Self::from_rotation_z(roll)
+    }
+
+    /// The `yaw`, `pitch` and `roll` that [`Quat::from_euler`] turns into this rotation.
+    /// Pitch is within `[-π/2, π/2]`, at the poles roll is folded into yaw.
+    pub fn to_euler(self) -> (Radians, Radians, Radians) {
+        let Self { x, y, z, w } = self;
+
+        let sin_pitch = (2.0 * (w * x - y * z)).clamp(-1.0, 1.0);
+        let pitch = sin_pitch.asin();
+
+        let (yaw, roll) = if sin_pitch.abs() > 0.9999 {
+            (
+                (2.0 * (w * y - x * z)).atan2(1.0 - 2.0 * (y * y + z * z)),
+                0.0,
+            )
+        } else {
+            (
+                (2.0 * (x * z + w * y)).atan2(1.0 - 2.0 * (x * x + y * y)),
+                (2.0 * (x * y + w * z)).atan2(1.0 - 2.0 * (x * x + z * z)),
+            )
+        };
+
+        let radians = |value: f32| Radians::new(value).unwrap_or(Radians::ZERO);
+        (radians(yaw), radians(pitch), radians(roll))
+    }
+
+    /// Axis and angle of the rotation, the axis is `None` for the identity
+    pub fn to_axis_angle<Space>(self) -> (Option<Vec3<Space, f32>>, Radians) {
+        let w = self.w.clamp(-1.0, 1.0);
+        let angle = Radians::new(2.0 * w.acos()).unwrap_or(Radians::ZERO);
+        let axis = Vec3::new(self.x, self.y, self.z).normalize();
+        (axis, angle)
+    }
+
+    /// The smallest angle needed to rotate from `self` to `other`
+    pub fn angle_between(self, other: Self) -> Radians {
+        let cos = self.dot(other).abs().min(1.0);
+        Radians::new(2.0 * cos.acos()).unwrap_or(Radians::ZERO)
+    }
+
+    pub fn to_array(self) -> [f32; 4] {
+        [self.x, self.y, self.z, self.w]
+    }
+
+    pub fn dot(self, other: Self) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    pub fn length(self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    /// Returns `None` if the quaternion has a length of zero or is not finite
+    pub fn normalize(self) -> Option<Self> {
+        let length = self.length();
+        if length > 0.0 && length.is_finite() {
+            Some(self.scale(1.0 / length))
+        } else {
+            None
+        }
+    }
+
+    /// The inverse rotation
+    pub fn conjugate(self) -> Self {
+        Self::from_xyzw(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// The inverse rotation, same as [`Quat::conjugate`] for unit quaternions
+    pub fn inverse(self) -> Self {
+        self.conjugate().scale(1.0 / self.dot(self))
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "float vector form of q v q*"
+    )]
+    pub fn rotate<Space>(self, v: Vec3<Space, f32>) -> Vec3<Space, f32> {
+        let q = Vec3::<Space, f32>::new(self.x, self.y, self.z);
+        let t = q.cross(v) * 2.0;
+        v + t * self.w + q.cross(t)
+    }
+
+    /// Spherical interpolation along the shortest path,
+    /// `t = 0.0` returns `self`, `t = 1.0` returns `other`
+    pub fn slerp(self, other: Self, t: f32) -> Self {
+        let mut cos = self.dot(other);
+        let other = if cos < 0.0 {
+            cos = -cos;
+            other.scale(-1.0)
+        } else {
+            other
+        };
+
+        // nearly parallel rotations would divide by zero, lerping is good enough there
+        if cos > 0.9995 {
+            let lerped = self.scale(1.0 - t).add(other.scale(t));
+            return lerped.normalize().unwrap_or(self);
+        }
+
+        let angle = cos.acos();
+        let sin = angle.sin();
+        let a = ((1.0 - t) * angle).sin() / sin;
+        let b = (t * angle).sin() / sin;
+        self.scale(a).add(other.scale(b))
+    }
+
+    pub fn to_mat4(self) -> Mat4 {
+        let Self { x, y, z, w } = self;
+        let (x2, y2, z2) = (x + x, y + y, z + z);
+        let (xx, xy, xz) = (x * x2, x * y2, x * z2);
+        let (yy, yz, zz) = (y * y2, y * z2, z * z2);
+        let (wx, wy, wz) = (w * x2, w * y2, w * z2);
+
+        #[rustfmt::skip]
+        let values = [
+            1.0 - (yy + zz),         xy + wz,         xz - wy, 0.0,
+                    xy - wz, 1.0 - (xx + zz),         yz + wx, 0.0,
+                    xz + wy,         yz - wx, 1.0 - (xx + yy), 0.0,
+                        0.0,             0.0,             0.0, 1.0,
+        ];
+
+        Mat4::from_cols_array(values)
+    }
+
+    /// Column-major rotation matrix, laid out like [`ProjectionMatrix::to_array`](crate::ProjectionMatrix::to_array)
+    pub fn to_cols_array(self) -> [f32; 16] {
+        self.to_mat4().to_array()
+    }
+
+    fn scale(self, factor: f32) -> Self {
+        Self::from_xyzw(
+            self.x * factor,
+            self.y * factor,
+            self.z * factor,
+            self.w * factor,
+        )
+    }
+
+    fn add(self, other: Self) -> Self {
+        Self::from_xyzw(
+            self.x + other.x,
+            self.y + other.y,
+            self.z + other.z,
+            self.w + other.w,
+        )
+    }
+}
+
+impl Default for Quat {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// Combines two rotations, `a * b` rotates by `b` first and then by `a`
+impl Mul for Quat {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let (a, b) = (self, rhs);
+        Self::from_xyzw(
+            a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+            a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+            a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+            a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+        )
+    }
+}
+
+/// Sine and cosine of half the angle, quaternions rotate by twice their angle
+fn half_sin_cos(angle: Radians) -> (f32, f32) {
+    (angle.get() * 0.5).sin_cos()
+}
+
+impl MulAssign for Quat {
+    #[expect(clippy::arithmetic_side_effects, reason = "forwards to `Mul for Quat`")]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<Space> Mul<Vec3<Space, f32>> for Quat {
+    type Output = Vec3<Space, f32>;
+
+    fn mul(self, rhs: Vec3<Space, f32>) -> Self::Output {
+        self.rotate(rhs)
+    }
+}
+
+impl From<Quat> for Mat4 {
+    fn from(value: Quat) -> Self {
+        value.to_mat4()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Degrees;
+    use crate::WorldVec3;
+
+    #[test]
+    fn quaternion_rotation() {
+        let axis = WorldVec3::new(1.0, 2.0, 3.0);
+        let angle = Radians::new(0.8).unwrap();
+        let q = Quat::from_axis_angle(axis, angle).unwrap();
+        let m = Mat4::from_axis_angle(axis, angle).unwrap();
+        let v = WorldVec3::new(-2.0, 0.5, 1.0);
+
+        assert!((q * v - m.transform_vector(v)).length() < 1e-5);
+        assert!((q.to_mat4().transform_vector(v) - m.transform_vector(v)).length() < 1e-5);
+        assert!((q.conjugate() * (q * v) - v).length() < 1e-5);
+
+        let z = Quat::from_rotation_z(Radians::FRAC_PI_2);
+        let x = Quat::from_rotation_x(Degrees::new(90.0).unwrap());
+        let rotated = (x * z) * WorldVec3::new(1.0, 0.0, 0.0);
+        assert!((rotated - WorldVec3::new(0.0, 0.0, 1.0)).length() < 1e-5);
+
+        let half = Quat::IDENTITY.slerp(z, 0.5);
+        assert!((half.angle_between(Quat::IDENTITY).to_degrees().get() - 45.0).abs() < 1e-3);
+
+        let (rotation_axis, angle) = q.to_axis_angle::<crate::space::World>();
+        assert!((rotation_axis.unwrap() - axis.normalize().unwrap()).length() < 1e-5);
+        assert!((angle.get() - 0.8).abs() < 1e-5);
+
+        assert!((q.inverse() * q).angle_between(Quat::IDENTITY).get() < 1e-3);
+    }
+
+    #[test]
+    fn euler_angles() {
+        let degrees = |value: f32| Degrees::new(value).unwrap();
+        let q = Quat::from_euler(degrees(30.0), degrees(-20.0), degrees(10.0));
+        let (yaw, pitch, roll) = q.to_euler();
+        assert!((yaw.to_degrees().get() - 30.0).abs() < 1e-3);
+        assert!((pitch.to_degrees().get() + 20.0).abs() < 1e-3);
+        assert!((roll.to_degrees().get() - 10.0).abs() < 1e-3);
+
+        let forward = q * WorldVec3::new(0.0, 0.0, -1.0);
+        let yawed = Quat::from_rotation_y(degrees(30.0)) * Quat::from_rotation_x(degrees(-20.0));
+        assert!((forward - yawed * WorldVec3::new(0.0, 0.0, -1.0)).length() < 1e-5);
+
+        assert_eq!(Quat::IDENTITY.to_cols_array(), Mat4::IDENTITY.to_array());
+    }
+}
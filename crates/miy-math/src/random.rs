@@ -0,0 +1,330 @@
+use core::ops::Range;
+
+use crate::collision::Circle;
+use crate::{Aabb, Rect, Vec2, Vec3};
+
+const MULTIPLIER: u64 = 6364136223846793005;
+const DEFAULT_STREAM: u64 = 0xda3e39cb94b95bdb;
+
+/// Seedable PCG32 generator that yields the same sequence on every machine
+///
+/// Sampling only uses integer operations, basic float arithmetic and `sqrt`, which IEEE 754
+/// rounds exactly, so floats and vectors are bit-exact across platforms as well.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Rng {
+    state: u64,
+    increment: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self::with_stream(seed, DEFAULT_STREAM)
+    }
+
+    /// Generators with the same seed but different streams produce unrelated sequences
+    pub fn with_stream(seed: u64, stream: u64) -> Self {
+        let mut rng = Self {
+            state: 0,
+            increment: (stream << 1) | 1,
+        };
+        rng.next_u32();
+        rng.state = rng.state.wrapping_add(seed);
+        rng.next_u32();
+        rng
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        let old = self.state;
+        self.state = old.wrapping_mul(MULTIPLIER).wrapping_add(self.increment);
+
+        let xorshifted = (((old >> 18) ^ old) >> 27) as u32;
+        let rotation = (old >> 59) as u32;
+        xorshifted.rotate_right(rotation)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let high = u64::from(self.next_u32());
+        let low = u64::from(self.next_u32());
+        (high << 32) | low
+    }
+
+    /// Uniform in `[0, 1)`
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 * (1.0 / 16_777_216.0)
+    }
+
+    pub fn bool(&mut self, probability: f32) -> bool {
+        self.next_f32() < probability
+    }
+
+    /// Uniform in `range` without modulo bias, `None` if the range is empty
+    pub fn range_u32(&mut self, range: Range<u32>) -> Option<u32> {
+        let span = range
+            .end
+            .checked_sub(range.start)
+            .filter(|span| *span > 0)?;
+        Some(range.start.wrapping_add(self.below(u64::from(span)) as u32))
+    }
+
+    /// Uniform in `range` without modulo bias, `None` if the range is empty
+    pub fn range_i32(&mut self, range: Range<i32>) -> Option<i32> {
+        if range.start >= range.end {
+            return None;
+        }
+
+        let span = range.end.abs_diff(range.start);
+        Some(
+            range
+                .start
+                .wrapping_add_unsigned(self.below(u64::from(span)) as u32),
+        )
+    }
+
+    /// Uniform in `range`, `None` if the range is empty or not finite
+    pub fn range_f32(&mut self, range: Range<f32>) -> Option<f32> {
+        let Range { start, end } = range;
+        if !(start < end && start.is_finite() && end.is_finite()) {
+            return None;
+        }
+
+        let value = start + (end - start) * self.next_f32();
+        // rounding can land exactly on `end`, which the range excludes
+        Some(if value < end { value } else { start })
+    }
+
+    /// Index into a slice of `len` elements, `None` if `len` is zero
+    pub fn index(&mut self, len: usize) -> Option<usize> {
+        if len == 0 {
+            return None;
+        }
+
+        Some(self.below(len as u64) as usize)
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        items.get(self.index(items.len())?)
+    }
+
+    /// Picks an index with a probability proportional to its weight.
+    /// Weights that are negative or not finite count as zero, `None` if all of them do.
+    pub fn weighted_index(&mut self, weights: &[f32]) -> Option<usize> {
+        self.pick_weighted(weights.iter().copied())
+    }
+
+    /// Picks an item with a probability proportional to its weight, see [`Rng::weighted_index`]
+    pub fn choose_weighted<'a, T>(&mut self, items: &'a [(T, f32)]) -> Option<&'a T> {
+        let index = self.pick_weighted(items.iter().map(|(_, weight)| *weight))?;
+        items.get(index).map(|(item, _)| item)
+    }
+
+    /// Fisher-Yates shuffle
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below((i as u64).wrapping_add(1)) as usize;
+            items.swap(i, j);
+        }
+    }
+
+    /// Uniformly distributed direction
+    pub fn unit_vec2<Space>(&mut self) -> Vec2<Space, f32> {
+        loop {
+            let v = Vec2::new(self.signed_f32(), self.signed_f32());
+            // rejection sampling keeps the directions uniform without any trigonometry
+            let length_squared = v.length_squared();
+            if length_squared > 1e-6
+                && length_squared <= 1.0
+                && let Some(direction) = v.normalize()
+            {
+                return direction;
+            }
+        }
+    }
+
+    /// Uniformly distributed direction
+    pub fn unit_vec3<Space>(&mut self) -> Vec3<Space, f32> {
+        loop {
+            let v = Vec3::new(self.signed_f32(), self.signed_f32(), self.signed_f32());
+            let length_squared = v.length_squared();
+            if length_squared > 1e-6
+                && length_squared <= 1.0
+                && let Some(direction) = v.normalize()
+            {
+                return direction;
+            }
+        }
+    }
+
+    /// Uniformly distributed point within the circle
+    pub fn point_in_circle<Space>(&mut self, circle: &Circle<Space>) -> Vec2<Space, f32> {
+        loop {
+            let v = Vec2::<Space, f32>::new(self.signed_f32(), self.signed_f32());
+            if v.length_squared() <= 1.0 {
+                return Vec2::new(
+                    circle.center.x + v.x * circle.radius,
+                    circle.center.y + v.y * circle.radius,
+                );
+            }
+        }
+    }
+
+    pub fn point_in_rect<Space>(&mut self, rect: &Rect<Space, f32>) -> Vec2<Space, f32> {
+        let (min, max) = (rect.min(), rect.max());
+        Vec2::new(
+            min.x + (max.x - min.x) * self.next_f32(),
+            min.y + (max.y - min.y) * self.next_f32(),
+        )
+    }
+
+    pub fn point_in_aabb<Space>(&mut self, aabb: &Aabb<Space>) -> Vec3<Space, f32> {
+        let (min, max) = (aabb.min(), aabb.max());
+        Vec3::new(
+            min.x + (max.x - min.x) * self.next_f32(),
+            min.y + (max.y - min.y) * self.next_f32(),
+            min.z + (max.z - min.z) * self.next_f32(),
+        )
+    }
+
+    /// Uniformly distributed point within the triangle `a`, `b`, `c`
+    pub fn point_in_triangle<Space>(
+        &mut self,
+        a: Vec2<Space, f32>,
+        b: Vec2<Space, f32>,
+        c: Vec2<Space, f32>,
+    ) -> Vec2<Space, f32> {
+        let (mut u, mut v) = (self.next_f32(), self.next_f32());
+        // folds the upper half of the parallelogram back onto the triangle
+        if u + v > 1.0 {
+            (u, v) = (1.0 - u, 1.0 - v);
+        }
+
+        Vec2::new(
+            a.x + (b.x - a.x) * u + (c.x - a.x) * v,
+            a.y + (b.y - a.y) * u + (c.y - a.y) * v,
+        )
+    }
+
+    /// Uniform in `[-1, 1)`
+    fn signed_f32(&mut self) -> f32 {
+        self.next_f32() * 2.0 - 1.0
+    }
+
+    /// Lemire's nearly divisionless method, `bound` must not be zero
+    fn below(&mut self, bound: u64) -> u64 {
+        let threshold = bound.wrapping_neg().checked_rem(bound).unwrap_or(0);
+        loop {
+            let m = u128::from(self.next_u64()).wrapping_mul(u128::from(bound));
+            if m as u64 >= threshold {
+                return (m >> 64) as u64;
+            }
+        }
+    }
+
+    fn pick_weighted(&mut self, weights: impl Iterator<Item = f32> + Clone) -> Option<usize> {
+        let weight = |w: f32| if w > 0.0 && w.is_finite() { w } else { 0.0 };
+        let total: f32 = weights.clone().map(weight).sum();
+        if !(total > 0.0 && total.is_finite()) {
+            return None;
+        }
+
+        let mut target = self.next_f32() * total;
+        let mut last = None;
+        for (index, w) in weights.map(weight).enumerate() {
+            if w > 0.0 {
+                if target < w {
+                    return Some(index);
+                }
+                target -= w;
+                last = Some(index);
+            }
+        }
+
+        // float rounding can leave a sliver past the last positive weight
+        last
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{WorldPosition, WorldRect};
+
+    #[test]
+    fn pcg32_reference_sequence() {
+        // first outputs of the pcg32 reference implementation seeded with 42 on stream 54
+        let mut rng = Rng::with_stream(42, 54);
+        let expected = [
+            0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293, 0xbfa4784b, 0xcbed606e,
+        ];
+        for value in expected {
+            assert_eq!(rng.next_u32(), value);
+        }
+
+        let mut rng = Rng::new(7);
+        let direction: WorldPosition = rng.unit_vec2();
+        assert_eq!(direction.x.to_bits(), 0x3f504a99);
+        assert_eq!(direction.y.to_bits(), 0xbf14d4c2);
+        assert_eq!(rng.next_f32().to_bits(), 0x3f2d048b);
+
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        for _ in 0..100 {
+            assert_eq!(a.next_f32().to_bits(), b.next_f32().to_bits());
+        }
+    }
+
+    #[test]
+    fn ranges_and_choices() {
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            assert!((3..9).contains(&rng.range_u32(3..9).unwrap()));
+            assert!((-5..5).contains(&rng.range_i32(-5..5).unwrap()));
+            assert!((i32::MIN..i32::MAX).contains(&rng.range_i32(i32::MIN..i32::MAX).unwrap()));
+            let f = rng.range_f32(-1.0..2.0).unwrap();
+            assert!((-1.0..2.0).contains(&f));
+        }
+        assert_eq!(rng.range_u32(4..4), None);
+        assert_eq!(rng.range_f32(1.0..0.0), None);
+        assert_eq!(rng.choose::<u8>(&[]), None);
+
+        let weights = [0.0, 3.0, -1.0, 1.0];
+        let mut counts = [0; 4];
+        for _ in 0..4000 {
+            counts[rng.weighted_index(&weights).unwrap()] += 1;
+        }
+        assert_eq!((counts[0], counts[2]), (0, 0));
+        assert!((2800..3200).contains(&counts[1]));
+        assert_eq!(rng.weighted_index(&[0.0, f32::NAN]), None);
+        assert_eq!(rng.choose_weighted(&[("a", 0.0), ("b", 1.0)]), Some(&"b"));
+
+        let mut items = [0, 1, 2, 3, 4, 5, 6, 7];
+        rng.shuffle(&mut items);
+        let mut sorted = items;
+        sorted.sort();
+        assert_eq!(sorted, [0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn shape_sampling() {
+        let mut rng = Rng::new(3);
+        let circle = Circle {
+            center: WorldPosition::new(4.0, -2.0),
+            radius: 2.0,
+        };
+        let rect =
+            WorldRect::new(WorldPosition::new(-1.0, 0.0), WorldPosition::new(1.0, 5.0)).unwrap();
+
+        for _ in 0..500 {
+            let direction: WorldPosition = rng.unit_vec2();
+            assert!((direction.length() - 1.0).abs() < 1e-5);
+            assert!(rng.point_in_circle(&circle).distance(circle.center) <= 2.0 + 1e-5);
+            assert!(rect.contains(rng.point_in_rect(&rect)));
+
+            let p = rng.point_in_triangle(
+                WorldPosition::new(0.0, 0.0),
+                WorldPosition::new(1.0, 0.0),
+                WorldPosition::new(0.0, 1.0),
+            );
+            assert!(p.x >= 0.0 && p.y >= 0.0 && p.x + p.y <= 1.0);
+        }
+    }
+}
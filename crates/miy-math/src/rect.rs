@@ -0,0 +1,508 @@
+use core::ops::{Add, Sub};
+
+use crate::{Dimensions2D, Vec2, Vec3, space};
+
+/// Axis aligned rectangle tagged with the [`space`] it lives in, `min` is always `<=` `max`
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Rect<Space, T> {
+    min: Vec2<Space, T>,
+    max: Vec2<Space, T>,
+}
+
+/// Axis aligned bounding box tagged with the [`space`] it lives in, `min` is always `<=` `max`
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Aabb<Space> {
+    min: Vec3<Space, f32>,
+    max: Vec3<Space, f32>,
+}
+
+pub type WorldRect = Rect<space::World, f32>;
+pub type ScreenRect = Rect<space::Screen, u32>;
+
+pub type WorldAabb = Aabb<space::World>;
+
+fn min<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a { b } else { a }
+}
+
+fn max<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a { b } else { a }
+}
+
+fn clamp<T: PartialOrd>(value: T, low: T, high: T) -> T {
+    min(max(value, low), high)
+}
+
+impl<Space, T> Rect<Space, T>
+where
+    T: Copy + PartialOrd,
+{
+    /// Returns `None` if `min` is larger than `max` on any axis
+    pub fn new(min: Vec2<Space, T>, max: Vec2<Space, T>) -> Option<Self> {
+        if !(min.x <= max.x && min.y <= max.y) {
+            return None;
+        }
+
+        Some(Self { min, max })
+    }
+
+    /// Smallest rectangle containing both corners
+    pub fn from_corners(a: Vec2<Space, T>, b: Vec2<Space, T>) -> Self {
+        Self {
+            min: Vec2::new(min(a.x, b.x), min(a.y, b.y)),
+            max: Vec2::new(max(a.x, b.x), max(a.y, b.y)),
+        }
+    }
+
+    /// Rectangle with its minimum corner at `position`
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "integer rectangles past the component's range overflow like the component does"
+    )]
+    pub fn from_position_dimensions(
+        position: Vec2<Space, T>,
+        dimensions: Dimensions2D<Space, T>,
+    ) -> Self
+    where
+        Space: Copy,
+        T: Add<Output = T>,
+    {
+        let size = Vec2::new(dimensions.width().get(), dimensions.height().get());
+        Self {
+            min: position,
+            max: position + size,
+        }
+    }
+
+    pub fn min(&self) -> Vec2<Space, T> {
+        self.min
+    }
+
+    pub fn max(&self) -> Vec2<Space, T> {
+        self.max
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "`min <= max` holds, so unsigned widths can't underflow"
+    )]
+    pub fn width(&self) -> T
+    where
+        T: Sub<Output = T>,
+    {
+        self.max.x - self.min.x
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "`min <= max` holds, so unsigned heights can't underflow"
+    )]
+    pub fn height(&self) -> T
+    where
+        T: Sub<Output = T>,
+    {
+        self.max.y - self.min.y
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "`min <= max` holds, so unsigned sizes can't underflow"
+    )]
+    pub fn size(&self) -> Vec2<Space, T>
+    where
+        T: Sub<Output = T>,
+    {
+        self.max - self.min
+    }
+
+    /// Returns `true` if `point` is inside or on the edge of the rectangle
+    pub fn contains(&self, point: Vec2<Space, T>) -> bool {
+        point.x >= self.min.x
+            && point.y >= self.min.y
+            && point.x <= self.max.x
+            && point.y <= self.max.y
+    }
+
+    pub fn contains_rect(&self, other: &Self) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    /// Returns `true` if the rectangles overlap, touching edges count as overlapping
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+    }
+
+    /// The overlapping part of both rectangles, `None` if they don't overlap
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Self::new(
+            Vec2::new(max(self.min.x, other.min.x), max(self.min.y, other.min.y)),
+            Vec2::new(min(self.max.x, other.max.x), min(self.max.y, other.max.y)),
+        )
+    }
+
+    /// Smallest rectangle containing both rectangles
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            min: Vec2::new(min(self.min.x, other.min.x), min(self.min.y, other.min.y)),
+            max: Vec2::new(max(self.max.x, other.max.x), max(self.max.y, other.max.y)),
+        }
+    }
+
+    /// Smallest rectangle containing `self` and `point`
+    pub fn include(&self, point: Vec2<Space, T>) -> Self {
+        Self {
+            min: Vec2::new(min(self.min.x, point.x), min(self.min.y, point.y)),
+            max: Vec2::new(max(self.max.x, point.x), max(self.max.y, point.y)),
+        }
+    }
+
+    /// Moves the rectangle by `offset`
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "translating integer rectangles overflows like adding their components"
+    )]
+    pub fn translate(&self, offset: Vec2<Space, T>) -> Self
+    where
+        T: Add<Output = T>,
+    {
+        Self {
+            min: self.min + offset,
+            max: self.max + offset,
+        }
+    }
+
+    /// The point inside the rectangle closest to `point`
+    pub fn clamp(&self, point: Vec2<Space, T>) -> Vec2<Space, T> {
+        Vec2::new(
+            clamp(point.x, self.min.x, self.max.x),
+            clamp(point.y, self.min.y, self.max.y),
+        )
+    }
+}
+
+impl<Space> Rect<Space, f32> {
+    /// Returns `None` if `half_extents` is negative on any axis
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "float corners, `new` rejects negative half extents"
+    )]
+    pub fn from_center_half_extents(
+        center: Vec2<Space, f32>,
+        half_extents: Vec2<Space, f32>,
+    ) -> Option<Self> {
+        Self::new(center - half_extents, center + half_extents)
+    }
+
+    /// Grows the rectangle by `amount` on every side.
+    ///
+    /// Returns `None` if a negative `amount` would shrink it past its center
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "moves float corners, `new` rejects the result if they crossed"
+    )]
+    pub fn expand(&self, amount: f32) -> Option<Self> {
+        Self::new(
+            self.min - Vec2::splat(amount),
+            self.max + Vec2::splat(amount),
+        )
+    }
+
+    #[expect(clippy::arithmetic_side_effects, reason = "midpoint of float corners")]
+    pub fn center(&self) -> Vec2<Space, f32> {
+        (self.min + self.max) * 0.5
+    }
+
+    #[expect(clippy::arithmetic_side_effects, reason = "halves a float size")]
+    pub fn half_extents(&self) -> Vec2<Space, f32> {
+        self.size() * 0.5
+    }
+
+    pub fn area(&self) -> f32 {
+        self.width() * self.height()
+    }
+
+    /// Returns `None` if the rectangle has no area
+    pub fn dimensions(&self) -> Option<Dimensions2D<Space, f32>> {
+        Dimensions2D::<Space, f32>::new(self.width(), self.height())
+    }
+}
+
+impl<Space> Rect<Space, u32> {
+    /// Grows the rectangle by `amount` on every side, stopping at the edges of the `u32` range
+    pub fn expand(&self, amount: u32) -> Self {
+        Self {
+            min: Vec2::new(
+                self.min.x.saturating_sub(amount),
+                self.min.y.saturating_sub(amount),
+            ),
+            max: Vec2::new(
+                self.max.x.saturating_add(amount),
+                self.max.y.saturating_add(amount),
+            ),
+        }
+    }
+
+    /// Returns `None` if the rectangle has no area
+    pub fn dimensions(&self) -> Option<Dimensions2D<Space, u32>> {
+        Dimensions2D::<Space, u32>::new(self.width(), self.height())
+    }
+}
+
+impl<Space> Aabb<Space> {
+    /// Returns `None` if `min` is larger than `max` on any axis
+    pub fn new(min: Vec3<Space, f32>, max: Vec3<Space, f32>) -> Option<Self> {
+        if !(min.x <= max.x && min.y <= max.y && min.z <= max.z) {
+            return None;
+        }
+
+        Some(Self { min, max })
+    }
+
+    /// Returns `None` if `half_extents` is negative on any axis
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "float center and extents, `new` validates the corners"
+    )]
+    pub fn from_center_half_extents(
+        center: Vec3<Space, f32>,
+        half_extents: Vec3<Space, f32>,
+    ) -> Option<Self> {
+        Self::new(center - half_extents, center + half_extents)
+    }
+
+    /// Smallest box containing all `points`, `None` if there are none
+    pub fn from_points(points: impl IntoIterator<Item = Vec3<Space, f32>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(
+            Self {
+                min: first,
+                max: first,
+            },
+            |aabb, point| aabb.include(point),
+        ))
+    }
+
+    pub fn min(&self) -> Vec3<Space, f32> {
+        self.min
+    }
+
+    pub fn max(&self) -> Vec3<Space, f32> {
+        self.max
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "distance between the float corners"
+    )]
+    pub fn size(&self) -> Vec3<Space, f32> {
+        self.max - self.min
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "midpoint of the two float corners of the box"
+    )]
+    pub fn center(&self) -> Vec3<Space, f32> {
+        (self.min + self.max) * 0.5
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "half of the float size, never negative"
+    )]
+    pub fn half_extents(&self) -> Vec3<Space, f32> {
+        self.size() * 0.5
+    }
+
+    pub fn volume(&self) -> f32 {
+        let size = self.size();
+        size.x * size.y * size.z
+    }
+
+    /// Half the surface area, the usual cost metric for bounding volume hierarchies
+    pub fn half_surface_area(&self) -> f32 {
+        let size = self.size();
+        size.x * size.y + size.y * size.z + size.z * size.x
+    }
+
+    /// Returns `true` if `point` is inside or on the surface of the box
+    pub fn contains(&self, point: Vec3<Space, f32>) -> bool {
+        point.x >= self.min.x
+            && point.y >= self.min.y
+            && point.z >= self.min.z
+            && point.x <= self.max.x
+            && point.y <= self.max.y
+            && point.z <= self.max.z
+    }
+
+    pub fn contains_aabb(&self, other: &Self) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    /// Returns `true` if the boxes overlap, touching faces count as overlapping
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+            && self.min.z <= other.max.z
+            && self.max.z >= other.min.z
+    }
+
+    /// The overlapping part of both boxes, `None` if they don't overlap
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Self::new(
+            Vec3::new(
+                self.min.x.max(other.min.x),
+                self.min.y.max(other.min.y),
+                self.min.z.max(other.min.z),
+            ),
+            Vec3::new(
+                self.max.x.min(other.max.x),
+                self.max.y.min(other.max.y),
+                self.max.z.min(other.max.z),
+            ),
+        )
+    }
+
+    /// Smallest box containing both boxes
+    pub fn union(&self, other: &Self) -> Self {
+        self.include(other.min).include(other.max)
+    }
+
+    /// Smallest box containing `self` and `point`
+    pub fn include(&self, point: Vec3<Space, f32>) -> Self {
+        Self {
+            min: Vec3::new(
+                self.min.x.min(point.x),
+                self.min.y.min(point.y),
+                self.min.z.min(point.z),
+            ),
+            max: Vec3::new(
+                self.max.x.max(point.x),
+                self.max.y.max(point.y),
+                self.max.z.max(point.z),
+            ),
+        }
+    }
+
+    /// Grows the box by `amount` on every side.
+    ///
+    /// Returns `None` if a negative `amount` would shrink it past its center
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "float corners, the result is validated"
+    )]
+    pub fn expand(&self, amount: f32) -> Option<Self> {
+        Self::new(
+            self.min - Vec3::splat(amount),
+            self.max + Vec3::splat(amount),
+        )
+    }
+
+    /// Moves the box by `offset`
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "float corners moved by a float offset"
+    )]
+    pub fn translate(&self, offset: Vec3<Space, f32>) -> Self {
+        Self {
+            min: self.min + offset,
+            max: self.max + offset,
+        }
+    }
+
+    /// The point inside the box closest to `point`
+    pub fn clamp(&self, point: Vec3<Space, f32>) -> Vec3<Space, f32> {
+        Vec3::new(
+            point.x.clamp(self.min.x, self.max.x),
+            point.y.clamp(self.min.y, self.max.y),
+            point.z.clamp(self.min.z, self.max.z),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{WorldDimensions2D, WorldPosition, WorldVec3};
+
+    #[test]
+    fn rect_queries() {
+        let a = WorldRect::from_position_dimensions(
+            WorldPosition::new(0.0, 0.0),
+            WorldDimensions2D::new(4.0, 2.0).unwrap(),
+        );
+        let b = WorldRect::from_corners(WorldPosition::new(5.0, 3.0), WorldPosition::new(3.0, 1.0));
+
+        assert_eq!(b.min(), WorldPosition::new(3.0, 1.0));
+        assert!(a.contains(WorldPosition::new(4.0, 2.0)));
+        assert!(!a.contains(WorldPosition::new(4.1, 2.0)));
+        assert!(a.intersects(&b));
+
+        let overlap = a.intersection(&b).unwrap();
+        assert_eq!(overlap.min(), WorldPosition::new(3.0, 1.0));
+        assert_eq!(overlap.max(), WorldPosition::new(4.0, 2.0));
+        assert_eq!(overlap.area(), 1.0);
+
+        let union = a.union(&b);
+        assert!(union.contains_rect(&a) && union.contains_rect(&b));
+        assert_eq!(union.center(), WorldPosition::new(2.5, 1.5));
+
+        let far = a.translate(WorldPosition::new(10.0, 0.0));
+        assert_eq!(a.intersection(&far), None);
+        assert_eq!(
+            a.clamp(WorldPosition::new(-1.0, 1.0)),
+            WorldPosition::new(0.0, 1.0)
+        );
+        assert_eq!(a.expand(1.0).unwrap().size(), WorldPosition::new(6.0, 4.0));
+        assert_eq!(a.expand(-1.0).unwrap().size(), WorldPosition::new(2.0, 0.0));
+        assert_eq!(a.expand(-1.5), None);
+        assert_eq!(WorldRect::new(b.max(), b.min()), None);
+
+        let pixels = ScreenRect::from_corners(Vec2::new(10, 20), Vec2::new(30, 20));
+        assert_eq!(pixels.width(), 20);
+        assert_eq!(pixels.dimensions(), None);
+
+        let corner = ScreenRect::from_corners(Vec2::new(0, 0), Vec2::new(4, u32::MAX - 1));
+        let grown = corner.expand(2);
+        assert_eq!(grown.min(), Vec2::new(0, 0));
+        assert_eq!(grown.max(), Vec2::new(6, u32::MAX));
+    }
+
+    #[test]
+    fn aabb_queries() {
+        let aabb = WorldAabb::from_points([
+            WorldVec3::new(1.0, 0.0, -1.0),
+            WorldVec3::new(-1.0, 2.0, 0.0),
+            WorldVec3::new(0.0, 1.0, 1.0),
+        ])
+        .unwrap();
+
+        assert_eq!(aabb.min(), WorldVec3::new(-1.0, 0.0, -1.0));
+        assert_eq!(aabb.max(), WorldVec3::new(1.0, 2.0, 1.0));
+        assert_eq!(aabb.volume(), 8.0);
+        assert!(aabb.contains(WorldVec3::ZERO));
+
+        let other =
+            WorldAabb::from_center_half_extents(WorldVec3::splat(1.0), WorldVec3::splat(0.5))
+                .unwrap();
+        let overlap = aabb.intersection(&other).unwrap();
+        assert_eq!(overlap.size(), WorldVec3::new(0.5, 1.0, 0.5));
+        assert!(aabb.union(&other).contains_aabb(&other));
+        assert_eq!(
+            aabb.clamp(WorldVec3::splat(5.0)),
+            WorldVec3::new(1.0, 2.0, 1.0)
+        );
+        assert!(WorldAabb::from_points([]).is_none());
+
+        let unit = WorldAabb::new(WorldVec3::ZERO, WorldVec3::splat(1.0)).unwrap();
+        assert_eq!(unit.expand(-2.0), None);
+        let point = unit.expand(-0.5).unwrap();
+        assert_eq!(point.clamp(WorldVec3::ZERO), WorldVec3::splat(0.5));
+    }
+}
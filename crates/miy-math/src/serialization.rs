@@ -0,0 +1,386 @@
+//! Serde support, enabled with the `serde` feature.
+//!
+//! Values go through the same validating constructors as in code, so a file with a zero width
+//! or an inverted rectangle fails to load instead of producing a value that can't otherwise
+//! exist. Space, unit and tag markers are not written, they come from the type being loaded.
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::dimensions::Dimension;
+use crate::{
+    Aabb, Angle, Dimensions2D, Fixed, Mat3, Mat4, ProjectionMatrix, Quat, Radians, Rect,
+    Transform2D, Transform3D, Vec2, Vec3, Vec4,
+};
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Vec2")]
+struct Xy<T> {
+    x: T,
+    y: T,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Vec3")]
+struct Xyz<T> {
+    x: T,
+    y: T,
+    z: T,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Vec4")]
+struct Xyzw<T> {
+    x: T,
+    y: T,
+    z: T,
+    w: T,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Dimensions2D")]
+struct Size<T> {
+    width: T,
+    height: T,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Rect")]
+struct Bounds<T> {
+    min: T,
+    max: T,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Transform")]
+struct Trs<T, R> {
+    translation: T,
+    rotation: R,
+    scale: T,
+}
+
+impl<Space, T: Serialize> Serialize for Vec2<Space, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Xy {
+            x: &self.x,
+            y: &self.y,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, Space, T: Deserialize<'de>> Deserialize<'de> for Vec2<Space, T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Xy { x, y } = Xy::deserialize(deserializer)?;
+        Ok(Self::new(x, y))
+    }
+}
+
+impl<Space, T: Serialize> Serialize for Vec3<Space, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Xyz {
+            x: &self.x,
+            y: &self.y,
+            z: &self.z,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, Space, T: Deserialize<'de>> Deserialize<'de> for Vec3<Space, T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Xyz { x, y, z } = Xyz::deserialize(deserializer)?;
+        Ok(Self::new(x, y, z))
+    }
+}
+
+impl<Space, T: Serialize> Serialize for Vec4<Space, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Xyzw {
+            x: &self.x,
+            y: &self.y,
+            z: &self.z,
+            w: &self.w,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, Space, T: Deserialize<'de>> Deserialize<'de> for Vec4<Space, T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Xyzw { x, y, z, w } = Xyzw::deserialize(deserializer)?;
+        Ok(Self::new(x, y, z, w))
+    }
+}
+
+impl<Space, Tag, Kind: Copy + Serialize> Serialize for Dimension<Space, Tag, Kind> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<Space: Copy, Kind: Copy + Serialize> Serialize for Dimensions2D<Space, Kind> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Size {
+            width: self.width().get(),
+            height: self.height().get(),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Dimensions only have constructors for concrete kinds
+macro_rules! dimension {
+    ($($kind:ty),+) => {
+        $(
+            impl<'de, Space, Tag> Deserialize<'de> for Dimension<Space, Tag, $kind> {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let value = <$kind>::deserialize(deserializer)?;
+                    Self::new(value).ok_or_else(|| D::Error::custom("dimension must be positive"))
+                }
+            }
+
+            impl<'de, Space> Deserialize<'de> for Dimensions2D<Space, $kind> {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let Size { width, height } = Size::<$kind>::deserialize(deserializer)?;
+                    Self::new(width, height)
+                        .ok_or_else(|| D::Error::custom("dimensions must be positive"))
+                }
+            }
+        )+
+    };
+}
+
+dimension!(u32, f32, Fixed);
+
+impl<Unit> Serialize for Angle<Unit, f32> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<'de, Unit> Deserialize<'de> for Angle<Unit, f32> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = f32::deserialize(deserializer)?;
+        Self::new(value).ok_or_else(|| D::Error::custom("angle must be finite"))
+    }
+}
+
+impl<Space, T: Copy + PartialOrd + Serialize> Serialize for Rect<Space, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Bounds {
+            min: self.min(),
+            max: self.max(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, Space, T: Copy + PartialOrd + Deserialize<'de>> Deserialize<'de> for Rect<Space, T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Bounds { min, max } = Bounds::<Vec2<Space, T>>::deserialize(deserializer)?;
+        Self::new(min, max).ok_or_else(|| D::Error::custom("rect min must not exceed max"))
+    }
+}
+
+impl<Space> Serialize for Aabb<Space> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Bounds {
+            min: self.min(),
+            max: self.max(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, Space> Deserialize<'de> for Aabb<Space> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Bounds { min, max } = Bounds::<Vec3<Space, f32>>::deserialize(deserializer)?;
+        Self::new(min, max).ok_or_else(|| D::Error::custom("aabb min must not exceed max"))
+    }
+}
+
+/// Matrices are written as their column-major array, every element has to be finite
+macro_rules! matrix {
+    ($($name:ty, $len:literal, $from:expr);+) => {
+        $(
+            impl Serialize for $name {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    self.to_array().serialize(serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $name {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let values = <[f32; $len]>::deserialize(deserializer)?;
+                    match values.iter().all(|value| value.is_finite()) {
+                        true => Ok(($from)(values)),
+                        false => Err(D::Error::custom("matrix elements must be finite")),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+matrix!(
+    Mat3, 9, Mat3::from_cols_array;
+    Mat4, 16, Mat4::from_cols_array;
+    ProjectionMatrix, 16, |values| Mat4::from_cols_array(values).into()
+);
+
+impl Serialize for Quat {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Xyzw {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+            w: self.w,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Renormalizes hand edited rotations, saved ones are already unit length and load unchanged
+impl<'de> Deserialize<'de> for Quat {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Xyzw { x, y, z, w } = Xyzw::deserialize(deserializer)?;
+        let quat = Self::from_xyzw(x, y, z, w);
+        let normalized = quat
+            .normalize()
+            .ok_or_else(|| D::Error::custom("quaternion can't be normalized"))?;
+        match (quat.length() - 1.0).abs() <= 1e-5 {
+            true => Ok(quat),
+            false => Ok(normalized),
+        }
+    }
+}
+
+impl<Space> Serialize for Transform2D<Space> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Trs {
+            translation: &self.translation,
+            rotation: &self.rotation,
+            scale: &self.scale,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, Space> Deserialize<'de> for Transform2D<Space> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Trs {
+            translation,
+            rotation,
+            scale,
+        } = Trs::<Vec2<Space, f32>, Radians>::deserialize(deserializer)?;
+        Ok(Self {
+            translation,
+            rotation,
+            scale,
+        })
+    }
+}
+
+impl<Space> Serialize for Transform3D<Space> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Trs {
+            translation: &self.translation,
+            rotation: &self.rotation,
+            scale: &self.scale,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, Space> Deserialize<'de> for Transform3D<Space> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Trs {
+            translation,
+            rotation,
+            scale,
+        } = Trs::<Vec3<Space, f32>, Quat>::deserialize(deserializer)?;
+        Ok(Self {
+            translation,
+            rotation,
+            scale,
+        })
+    }
+}
+
+/// Written as its decimal value, which is exact for every Q16.16 number
+impl Serialize for Fixed {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_f64().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Fixed {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = f64::deserialize(deserializer)?;
+        Self::from_f64(value).ok_or_else(|| D::Error::custom("value out of Fixed range"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{WorldDimensions2D, WorldPosition, WorldRect};
+    use serde::de::value::{self, SeqDeserializer};
+
+    #[test]
+    fn round_trips_and_validates() {
+        let position = WorldPosition::new(1.5, -2.0);
+        let json = serde_json::to_string(&position).unwrap();
+        assert_eq!(json, r#"{"x":1.5,"y":-2.0}"#);
+        assert_eq!(
+            serde_json::from_str::<WorldPosition>(&json).unwrap(),
+            position
+        );
+
+        let dimensions = WorldDimensions2D::new(4.0, 3.0).unwrap();
+        let json = serde_json::to_string(&dimensions).unwrap();
+        assert_eq!(json, r#"{"width":4.0,"height":3.0}"#);
+        assert_eq!(
+            serde_json::from_str::<WorldDimensions2D>(&json).unwrap(),
+            dimensions
+        );
+        assert!(
+            serde_json::from_str::<WorldDimensions2D>(r#"{"width":0.0,"height":3.0}"#).is_err()
+        );
+
+        let rect = r#"{"min":{"x":2.0,"y":0.0},"max":{"x":1.0,"y":1.0}}"#;
+        assert!(serde_json::from_str::<WorldRect>(rect).is_err());
+
+        let projection =
+            ProjectionMatrix::perspective(Radians::new(1.0).unwrap(), 1.5, 0.1, 100.0).unwrap();
+        let json = serde_json::to_string(&projection).unwrap();
+        let loaded: ProjectionMatrix = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.to_array(), projection.to_array());
+        let mut values = projection.to_array();
+        values[5] = f32::NAN;
+        let elements = SeqDeserializer::<_, value::Error>::new(values.into_iter());
+        assert!(ProjectionMatrix::deserialize(elements).is_err());
+        values[5] = f32::INFINITY;
+        let elements = SeqDeserializer::<_, value::Error>::new(values.into_iter());
+        assert!(Mat4::deserialize(elements).is_err());
+        values[5] = 1.0;
+        let elements = SeqDeserializer::<_, value::Error>::new(values.into_iter().take(9));
+        assert!(Mat3::deserialize(elements).is_ok());
+
+        let transform = Transform3D::<crate::space::World> {
+            rotation: Quat::from_xyzw(0.0, 0.0, 1.0, 1.0).normalize().unwrap(),
+            ..Transform3D::IDENTITY
+        };
+        let json = serde_json::to_string(&transform).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Transform3D<_>>(&json).unwrap(),
+            transform
+        );
+        assert!(serde_json::from_str::<Quat>(r#"{"x":0.0,"y":0.0,"z":0.0,"w":0.0}"#).is_err());
+
+        let fixed = Fixed::from_f32(-3.25).unwrap();
+        assert_eq!(serde_json::to_string(&fixed).unwrap(), "-3.25");
+        assert!(serde_json::from_str::<Fixed>("40000.0").is_err());
+    }
+}
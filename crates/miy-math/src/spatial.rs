@@ -0,0 +1,203 @@
+//! Broad-phase spatial indices over axis aligned bounds.
+//!
+//! All indices map user IDs to [`Rect`] bounds and answer the same queries through
+//! [`SpatialIndex`], so they can be swapped without touching the calling code:
+//!
+//! - [`SpatialHash`] buckets bounds into uniform cells, best for similarly sized, evenly spread
+//!   entries in an unbounded world.
+//! - [`Quadtree`] is a loose quadtree over a fixed region, it copes with mixed sizes.
+//! - [`AabbTree`] is a dynamic bounding volume hierarchy, it copes with mixed sizes and
+//!   clustering and needs no region up front.
+//!
+//! Queries only look at bounds, exact shape tests belong in [`collision`](crate::collision).
+
+use alloc::vec::Vec;
+
+use crate::collision::Ray;
+use crate::{Rect, Vec2};
+
+mod aabb_tree;
+mod quadtree;
+mod spatial_hash;
+
+pub use aabb_tree::AabbTree;
+pub use quadtree::Quadtree;
+pub use spatial_hash::SpatialHash;
+
+/// Common interface of the spatial indices, entries are keyed by `Id`
+pub trait SpatialIndex<Space, Id> {
+    /// Adds an entry, an existing entry with the same `id` is replaced and its bounds returned
+    fn insert(&mut self, id: Id, bounds: Rect<Space, f32>) -> Option<Rect<Space, f32>>;
+
+    /// Moves an existing entry, returns `false` if there is no entry with this `id`
+    fn update(&mut self, id: Id, bounds: Rect<Space, f32>) -> bool;
+
+    /// Returns the bounds of the removed entry
+    fn remove(&mut self, id: Id) -> Option<Rect<Space, f32>>;
+
+    fn bounds(&self, id: Id) -> Option<Rect<Space, f32>>;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Calls `visit` once for every entry whose bounds overlap `region`, in no particular order
+    fn query_region(&self, region: &Rect<Space, f32>, visit: impl FnMut(Id));
+
+    /// Entries whose bounds the ray enters within `max_distance`, sorted by distance.
+    /// Entries containing the ray origin are reported at a distance of zero.
+    fn query_ray(&self, ray: &Ray<Space>, max_distance: f32) -> Vec<(Id, f32)>;
+
+    /// The entry whose bounds are closest to `point` and their distance,
+    /// zero if `point` is inside the bounds
+    fn nearest(&self, point: Vec2<Space, f32>) -> Option<(Id, f32)>;
+}
+
+/// Distance along the ray at which it enters `rect`, zero if it starts inside
+fn ray_rect<Space>(ray: &Ray<Space>, max_distance: f32, rect: &Rect<Space, f32>) -> Option<f32> {
+    let (origin, direction) = (ray.origin(), ray.direction());
+    let (min, max) = (rect.min(), rect.max());
+
+    let mut near = 0.0_f32;
+    let mut far = max_distance;
+    for (origin, direction, min, max) in [
+        (origin.x, direction.x, min.x, max.x),
+        (origin.y, direction.y, min.y, max.y),
+    ] {
+        if direction == 0.0 {
+            if origin < min || origin > max {
+                return None;
+            }
+            continue;
+        }
+
+        let (a, b) = ((min - origin) / direction, (max - origin) / direction);
+        near = near.max(a.min(b));
+        far = far.min(a.max(b));
+    }
+
+    (near <= far).then_some(near)
+}
+
+fn distance_to_rect<Space>(point: Vec2<Space, f32>, rect: &Rect<Space, f32>) -> f32 {
+    rect.clamp(point).distance(point)
+}
+
+fn sort_hits<Id>(hits: &mut [(Id, f32)]) {
+    hits.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Rng, WorldDimensions2D, WorldPosition, WorldRect, space::World};
+
+    fn random_rect(rng: &mut Rng) -> WorldRect {
+        let (x, y) = (
+            rng.range_f32(-50.0..50.0).unwrap(),
+            rng.range_f32(-50.0..50.0).unwrap(),
+        );
+        let (width, height) = (
+            rng.range_f32(0.0..6.0).unwrap(),
+            rng.range_f32(0.0..6.0).unwrap(),
+        );
+        WorldRect::new(
+            WorldPosition::new(x, y),
+            WorldPosition::new(x + width, y + height),
+        )
+        .unwrap()
+    }
+
+    /// Runs random inserts, moves and removals and compares every query against brute force
+    fn matches_brute_force(mut index: impl SpatialIndex<World, u32>) {
+        let mut rng = Rng::new(99);
+        let mut reference = Vec::new();
+
+        for id in 0..200 {
+            let bounds = random_rect(&mut rng);
+            assert_eq!(index.insert(id, bounds), None);
+            reference.push(Some(bounds));
+        }
+        for _ in 0..100 {
+            let id = rng.range_u32(0..200).unwrap();
+            if rng.bool(0.3) {
+                assert_eq!(index.remove(id), reference[id as usize].take());
+            } else {
+                let bounds = random_rect(&mut rng);
+                let known = reference[id as usize].is_some();
+                assert_eq!(index.update(id, bounds), known);
+                if known {
+                    reference[id as usize] = Some(bounds);
+                }
+            }
+        }
+        assert_eq!(index.len(), reference.iter().flatten().count());
+
+        let entries = || {
+            reference
+                .iter()
+                .enumerate()
+                .filter_map(|(id, bounds)| Some((id as u32, (*bounds)?)))
+        };
+
+        for _ in 0..50 {
+            let region = random_rect(&mut rng).expand(5.0).unwrap();
+            let mut found = Vec::new();
+            index.query_region(&region, |id| found.push(id));
+            found.sort();
+            let expected: Vec<_> = entries()
+                .filter(|(_, bounds)| bounds.intersects(&region))
+                .map(|(id, _)| id)
+                .collect();
+            assert_eq!(found, expected);
+
+            let point = random_rect(&mut rng).center();
+            let (_, distance) = index.nearest(point).unwrap();
+            let closest = entries()
+                .map(|(_, bounds)| distance_to_rect(point, &bounds))
+                .fold(f32::INFINITY, f32::min);
+            assert_eq!(distance, closest);
+
+            let direction = rng.unit_vec2();
+            let ray = Ray::new(point, direction).unwrap();
+            let hits = index.query_ray(&ray, 40.0);
+            let mut expected: Vec<_> = entries()
+                .filter_map(|(id, bounds)| Some((id, ray_rect(&ray, 40.0, &bounds)?)))
+                .collect();
+            sort_hits(&mut expected);
+            assert_eq!(hits.len(), expected.len());
+            for ((_, a), (_, b)) in hits.iter().zip(&expected) {
+                assert_eq!(a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn indices_match_brute_force() {
+        let cell = WorldDimensions2D::new(8.0, 8.0).unwrap();
+        matches_brute_force(SpatialHash::new(cell));
+
+        let region = WorldRect::new(
+            WorldPosition::new(-40.0, -40.0),
+            WorldPosition::new(40.0, 40.0),
+        )
+        .unwrap();
+        matches_brute_force(Quadtree::new(region, 6));
+
+        matches_brute_force(AabbTree::new(0.5).unwrap());
+    }
+
+    #[test]
+    fn ray_enters_rect() {
+        let rect =
+            WorldRect::new(WorldPosition::new(2.0, -1.0), WorldPosition::new(4.0, 1.0)).unwrap();
+        let ray = Ray::new(WorldPosition::new(0.0, 0.0), WorldPosition::new(1.0, 0.0)).unwrap();
+        assert_eq!(ray_rect(&ray, 10.0, &rect), Some(2.0));
+        assert_eq!(ray_rect(&ray, 1.0, &rect), None);
+
+        let inside = Ray::new(WorldPosition::new(3.0, 0.0), WorldPosition::new(0.0, 1.0)).unwrap();
+        assert_eq!(ray_rect(&inside, 10.0, &rect), Some(0.0));
+    }
+}
@@ -0,0 +1,358 @@
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+
+use super::{SpatialIndex, distance_to_rect, ray_rect, sort_hits};
+use crate::collision::Ray;
+use crate::{Rect, Vec2};
+
+/// Dynamic bounding volume hierarchy, a binary tree whose nodes bound their children
+///
+/// Leaves are fattened by a margin, entries moving within it don't touch the tree at all.
+/// New leaves go next to the sibling that grows the tree's total perimeter the least.
+#[derive(Clone, Debug)]
+pub struct AabbTree<Space, Id> {
+    margin: f32,
+    nodes: Vec<Node<Space, Id>>,
+    free: Vec<usize>,
+    root: Option<usize>,
+    leaves: BTreeMap<Id, usize>,
+}
+
+#[derive(Clone, Debug)]
+struct Node<Space, Id> {
+    /// Fattened bounds for leaves, union of the children for branches
+    bounds: Rect<Space, f32>,
+    parent: Option<usize>,
+    kind: Kind<Space, Id>,
+}
+
+#[derive(Clone, Debug)]
+enum Kind<Space, Id> {
+    Leaf { id: Id, bounds: Rect<Space, f32> },
+    Branch([usize; 2]),
+    Free,
+}
+
+fn perimeter<Space>(rect: &Rect<Space, f32>) -> f32 {
+    2.0 * (rect.width() + rect.height())
+}
+
+impl<Space: Copy, Id: Copy + Ord> AabbTree<Space, Id> {
+    /// Returns `None` if `margin` is negative or not finite
+    pub fn new(margin: f32) -> Option<Self> {
+        if !(margin >= 0.0 && margin.is_finite()) {
+            return None;
+        }
+
+        Some(Self {
+            margin,
+            nodes: Vec::new(),
+            free: Vec::new(),
+            root: None,
+            leaves: BTreeMap::new(),
+        })
+    }
+
+    pub fn margin(&self) -> f32 {
+        self.margin
+    }
+
+    fn allocate(&mut self, node: Node<Space, Id>) -> usize {
+        match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            }
+            None => {
+                let index = self.nodes.len();
+                self.nodes.push(node);
+                index
+            }
+        }
+    }
+
+    fn release(&mut self, index: usize) {
+        self.nodes[index].kind = Kind::Free;
+        self.free.push(index);
+    }
+
+    fn bounds_of(&self, leaf: usize) -> Option<Rect<Space, f32>> {
+        match self.nodes[leaf].kind {
+            Kind::Leaf { bounds, .. } => Some(bounds),
+            Kind::Branch(_) | Kind::Free => None,
+        }
+    }
+
+    fn children(&self, node: usize) -> Option<[usize; 2]> {
+        match self.nodes[node].kind {
+            Kind::Branch(children) => Some(children),
+            Kind::Leaf { .. } | Kind::Free => None,
+        }
+    }
+
+    /// The node that grows the tree the least when paired with `bounds`
+    fn best_sibling(&self, root: usize, bounds: &Rect<Space, f32>) -> usize {
+        let mut node = root;
+        while let Some(children) = self.children(node) {
+            let current = &self.nodes[node].bounds;
+            let combined = perimeter(&current.union(bounds));
+
+            // pairing here creates a new parent, descending instead grows this node anyway
+            let pair_here = 2.0 * combined;
+            let inherited = 2.0 * (combined - perimeter(current));
+
+            let descend = |child: usize| {
+                let child = &self.nodes[child];
+                let grown = perimeter(&child.bounds.union(bounds));
+                let growth = match child.kind {
+                    Kind::Leaf { .. } | Kind::Free => grown,
+                    Kind::Branch(_) => grown - perimeter(&child.bounds),
+                };
+                growth + inherited
+            };
+
+            let costs = children.map(descend);
+            if pair_here < costs[0] && pair_here < costs[1] {
+                break;
+            }
+            node = if costs[0] <= costs[1] {
+                children[0]
+            } else {
+                children[1]
+            };
+        }
+        node
+    }
+
+    /// Recomputes the bounds of `node` and all its ancestors
+    fn refit(&mut self, mut node: Option<usize>) {
+        while let Some(index) = node {
+            if let Some([a, b]) = self.children(index) {
+                self.nodes[index].bounds = self.nodes[a].bounds.union(&self.nodes[b].bounds);
+            }
+            node = self.nodes[index].parent;
+        }
+    }
+
+    fn insert_leaf(&mut self, id: Id, bounds: Rect<Space, f32>) -> usize {
+        let leaf = self.allocate(Node {
+            // the margin is never negative, so this only fails for NaN bounds
+            bounds: bounds.expand(self.margin).unwrap_or(bounds),
+            parent: None,
+            kind: Kind::Leaf { id, bounds },
+        });
+
+        let Some(root) = self.root else {
+            self.root = Some(leaf);
+            return leaf;
+        };
+
+        let sibling = self.best_sibling(root, &self.nodes[leaf].bounds);
+        let grandparent = self.nodes[sibling].parent;
+        let parent = self.allocate(Node {
+            bounds: self.nodes[sibling].bounds.union(&self.nodes[leaf].bounds),
+            parent: grandparent,
+            kind: Kind::Branch([sibling, leaf]),
+        });
+        self.nodes[sibling].parent = Some(parent);
+        self.nodes[leaf].parent = Some(parent);
+
+        match grandparent {
+            Some(grandparent) => {
+                self.replace_child(grandparent, sibling, parent);
+                self.refit(Some(grandparent));
+            }
+            None => self.root = Some(parent),
+        }
+        leaf
+    }
+
+    fn remove_leaf(&mut self, leaf: usize) {
+        let parent = self.nodes[leaf].parent;
+        self.release(leaf);
+
+        let Some(parent) = parent else {
+            self.root = None;
+            return;
+        };
+
+        let sibling = match self.children(parent) {
+            Some([a, b]) => {
+                if a == leaf {
+                    b
+                } else {
+                    a
+                }
+            }
+            None => return,
+        };
+        let grandparent = self.nodes[parent].parent;
+        self.nodes[sibling].parent = grandparent;
+        self.release(parent);
+
+        match grandparent {
+            Some(grandparent) => {
+                self.replace_child(grandparent, parent, sibling);
+                self.refit(Some(grandparent));
+            }
+            None => self.root = Some(sibling),
+        }
+    }
+
+    fn replace_child(&mut self, node: usize, old: usize, new: usize) {
+        if let Kind::Branch(children) = &mut self.nodes[node].kind {
+            for child in children.iter_mut().filter(|child| **child == old) {
+                *child = new;
+            }
+        }
+    }
+
+    /// Depth-first walk over the nodes `enter` accepts, calling `leaf` for each leaf reached
+    fn walk(
+        &self,
+        mut enter: impl FnMut(&Rect<Space, f32>) -> bool,
+        mut leaf: impl FnMut(Id, &Rect<Space, f32>),
+    ) {
+        let mut stack: Vec<usize> = self.root.into_iter().collect();
+        while let Some(node) = stack.pop() {
+            let node = &self.nodes[node];
+            if !enter(&node.bounds) {
+                continue;
+            }
+
+            match &node.kind {
+                Kind::Leaf { id, bounds } => leaf(*id, bounds),
+                Kind::Branch(children) => stack.extend(children),
+                Kind::Free => {}
+            }
+        }
+    }
+}
+
+impl<Space: Copy, Id: Copy + Ord> SpatialIndex<Space, Id> for AabbTree<Space, Id> {
+    fn insert(&mut self, id: Id, bounds: Rect<Space, f32>) -> Option<Rect<Space, f32>> {
+        let previous = self.remove(id);
+        let leaf = self.insert_leaf(id, bounds);
+        self.leaves.insert(id, leaf);
+        previous
+    }
+
+    fn update(&mut self, id: Id, bounds: Rect<Space, f32>) -> bool {
+        let Some(&leaf) = self.leaves.get(&id) else {
+            return false;
+        };
+
+        if self.nodes[leaf].bounds.contains_rect(&bounds) {
+            self.nodes[leaf].kind = Kind::Leaf { id, bounds };
+        } else {
+            self.remove_leaf(leaf);
+            let leaf = self.insert_leaf(id, bounds);
+            self.leaves.insert(id, leaf);
+        }
+        true
+    }
+
+    fn remove(&mut self, id: Id) -> Option<Rect<Space, f32>> {
+        let leaf = self.leaves.remove(&id)?;
+        let bounds = self.bounds_of(leaf);
+        self.remove_leaf(leaf);
+        bounds
+    }
+
+    fn bounds(&self, id: Id) -> Option<Rect<Space, f32>> {
+        self.bounds_of(*self.leaves.get(&id)?)
+    }
+
+    fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    fn query_region(&self, region: &Rect<Space, f32>, mut visit: impl FnMut(Id)) {
+        self.walk(
+            |bounds| bounds.intersects(region),
+            |id, bounds| {
+                if bounds.intersects(region) {
+                    visit(id);
+                }
+            },
+        );
+    }
+
+    fn query_ray(&self, ray: &Ray<Space>, max_distance: f32) -> Vec<(Id, f32)> {
+        let mut hits = Vec::new();
+        self.walk(
+            |bounds| ray_rect(ray, max_distance, bounds).is_some(),
+            |id, bounds| {
+                if let Some(distance) = ray_rect(ray, max_distance, bounds) {
+                    hits.push((id, distance));
+                }
+            },
+        );
+
+        sort_hits(&mut hits);
+        hits
+    }
+
+    fn nearest(&self, point: Vec2<Space, f32>) -> Option<(Id, f32)> {
+        let root = self.root?;
+        let mut best: Option<(Id, f32)> = None;
+        let mut stack = vec![(root, distance_to_rect(point, &self.nodes[root].bounds))];
+
+        while let Some((node, reach)) = stack.pop() {
+            if best.is_some_and(|(_, closest)| reach >= closest) {
+                continue;
+            }
+
+            match &self.nodes[node].kind {
+                Kind::Leaf { id, bounds } => {
+                    let distance = distance_to_rect(point, bounds);
+                    if best.is_none_or(|(_, closest)| distance < closest) {
+                        best = Some((*id, distance));
+                    }
+                }
+                Kind::Branch(children) => {
+                    let mut children = children
+                        .map(|child| (child, distance_to_rect(point, &self.nodes[child].bounds)));
+                    // the closest child is popped first, it tightens the bound for its sibling
+                    children.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+                    stack.extend(children);
+                }
+                Kind::Free => {}
+            }
+        }
+
+        best
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{WorldPosition, WorldRect};
+
+    #[test]
+    fn small_moves_stay_in_the_fat_bounds() {
+        let mut tree = AabbTree::new(1.0).unwrap();
+        let bounds =
+            WorldRect::new(WorldPosition::new(0.0, 0.0), WorldPosition::new(1.0, 1.0)).unwrap();
+        for id in 0..8 {
+            tree.insert(
+                id,
+                bounds.translate(WorldPosition::new(id as f32 * 3.0, 0.0)),
+            );
+        }
+
+        let leaf = tree.leaves[&3];
+        assert!(tree.update(3, bounds.translate(WorldPosition::new(9.5, 0.5))));
+        assert_eq!(tree.leaves[&3], leaf);
+
+        assert!(tree.update(3, bounds.translate(WorldPosition::new(50.0, 0.0))));
+        assert_eq!(tree.nearest(WorldPosition::new(52.0, 0.5)), Some((3, 1.0)));
+
+        for id in 0..8 {
+            assert!(tree.remove(id).is_some());
+        }
+        assert!(tree.is_empty());
+        assert_eq!(tree.root, None);
+        assert!(!tree.update(3, bounds));
+    }
+}
@@ -0,0 +1,276 @@
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use core::ops::Range;
+
+use super::{SpatialIndex, distance_to_rect, ray_rect, sort_hits};
+use crate::collision::Ray;
+use crate::{Rect, Vec2};
+
+/// Loose quadtree over a fixed region
+///
+/// Every node accepts entries reaching up to half its size beyond its own bounds, so an entry
+/// sits in a single node chosen by its center and size and never straddles a split.
+/// Entries outside of the region are kept in the root, which still works but doesn't scale.
+#[derive(Clone, Debug)]
+pub struct Quadtree<Space, Id> {
+    max_depth: u32,
+    nodes: Vec<Node<Space, Id>>,
+    entries: BTreeMap<Id, (Rect<Space, f32>, usize)>,
+}
+
+#[derive(Clone, Debug)]
+struct Node<Space, Id> {
+    bounds: Rect<Space, f32>,
+    /// Index of the first of four consecutive children
+    children: Option<usize>,
+    ids: Vec<Id>,
+}
+
+impl<Space: Copy, Id: Copy + Ord> Quadtree<Space, Id> {
+    /// Deepest level a tree can be split to
+    pub const MAX_DEPTH: u32 = 16;
+
+    /// The tree is split at most `max_depth` times, clamped to [`Quadtree::MAX_DEPTH`]
+    pub fn new(region: Rect<Space, f32>, max_depth: u32) -> Self {
+        Self {
+            max_depth: max_depth.min(Self::MAX_DEPTH),
+            nodes: vec![Node {
+                bounds: region,
+                children: None,
+                ids: Vec::new(),
+            }],
+            entries: BTreeMap::new(),
+        }
+    }
+
+    pub fn region(&self) -> Rect<Space, f32> {
+        self.nodes[0].bounds
+    }
+
+    /// The bounds of every entry stored in a node, twice the size of the node itself
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "growing finite node bounds by half their size stays finite"
+    )]
+    fn loose(bounds: &Rect<Space, f32>) -> Rect<Space, f32> {
+        let half = bounds.half_extents();
+        Rect::from_corners(bounds.min() - half, bounds.max() + half)
+    }
+
+    /// Queries have to look at the root no matter what, it also holds entries outside the region
+    fn reaches(&self, node: usize, test: impl Fn(&Rect<Space, f32>) -> bool) -> bool {
+        node == 0 || test(&Self::loose(&self.nodes[node].bounds))
+    }
+
+    /// Indices of the four children of `node`, empty if it was never split
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "`split` pushes all four children, so the range ends within `nodes`"
+    )]
+    fn children(&self, node: usize) -> Range<usize> {
+        self.nodes[node]
+            .children
+            .map_or(0..0, |first| first..first + 4)
+    }
+
+    fn split(&mut self, node: usize) -> usize {
+        if let Some(first) = self.nodes[node].children {
+            return first;
+        }
+
+        let bounds = self.nodes[node].bounds;
+        let (min, max, center) = (bounds.min(), bounds.max(), bounds.center());
+        let first = self.nodes.len();
+        for (low, high) in [
+            (min, center),
+            (Vec2::new(center.x, min.y), Vec2::new(max.x, center.y)),
+            (Vec2::new(min.x, center.y), Vec2::new(center.x, max.y)),
+            (center, max),
+        ] {
+            self.nodes.push(Node {
+                bounds: Rect::from_corners(low, high),
+                children: None,
+                ids: Vec::new(),
+            });
+        }
+        self.nodes[node].children = Some(first);
+        first
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "quadrants are below four and pick one of the children `split` just pushed"
+    )]
+    fn place(&mut self, id: Id, bounds: &Rect<Space, f32>) -> usize {
+        let center = bounds.center();
+        let mut node = 0;
+
+        for _ in 0..self.max_depth {
+            let parent = self.nodes[node].bounds;
+            if !parent.contains(center) {
+                break;
+            }
+
+            let middle = parent.center();
+            let quadrant =
+                usize::from(center.x >= middle.x) + 2 * usize::from(center.y >= middle.y);
+            let half = parent.half_extents();
+            let low = Vec2::new(
+                if quadrant & 1 == 0 {
+                    parent.min().x
+                } else {
+                    middle.x
+                },
+                if quadrant & 2 == 0 {
+                    parent.min().y
+                } else {
+                    middle.y
+                },
+            );
+            let child = Rect::from_corners(low, low + half);
+            if !Self::loose(&child).contains_rect(bounds) {
+                break;
+            }
+
+            node = self.split(node) + quadrant;
+        }
+
+        self.nodes[node].ids.push(id);
+        node
+    }
+}
+
+impl<Space: Copy, Id: Copy + Ord> SpatialIndex<Space, Id> for Quadtree<Space, Id> {
+    fn insert(&mut self, id: Id, bounds: Rect<Space, f32>) -> Option<Rect<Space, f32>> {
+        let previous = self.remove(id);
+        let node = self.place(id, &bounds);
+        self.entries.insert(id, (bounds, node));
+        previous
+    }
+
+    fn update(&mut self, id: Id, bounds: Rect<Space, f32>) -> bool {
+        if !self.entries.contains_key(&id) {
+            return false;
+        }
+
+        self.insert(id, bounds);
+        true
+    }
+
+    fn remove(&mut self, id: Id) -> Option<Rect<Space, f32>> {
+        let (bounds, node) = self.entries.remove(&id)?;
+        self.nodes[node].ids.retain(|other| *other != id);
+        Some(bounds)
+    }
+
+    fn bounds(&self, id: Id) -> Option<Rect<Space, f32>> {
+        self.entries.get(&id).map(|(bounds, _)| *bounds)
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn query_region(&self, region: &Rect<Space, f32>, mut visit: impl FnMut(Id)) {
+        let mut stack = vec![0];
+        while let Some(node) = stack.pop() {
+            if !self.reaches(node, |loose| loose.intersects(region)) {
+                continue;
+            }
+
+            for id in &self.nodes[node].ids {
+                if self
+                    .bounds(*id)
+                    .is_some_and(|bounds| bounds.intersects(region))
+                {
+                    visit(*id);
+                }
+            }
+            stack.extend(self.children(node));
+        }
+    }
+
+    fn query_ray(&self, ray: &Ray<Space>, max_distance: f32) -> Vec<(Id, f32)> {
+        let mut hits = Vec::new();
+        let mut stack = vec![0];
+        while let Some(node) = stack.pop() {
+            if !self.reaches(node, |loose| ray_rect(ray, max_distance, loose).is_some()) {
+                continue;
+            }
+
+            for id in &self.nodes[node].ids {
+                let hit = self
+                    .bounds(*id)
+                    .and_then(|bounds| ray_rect(ray, max_distance, &bounds));
+                if let Some(distance) = hit {
+                    hits.push((*id, distance));
+                }
+            }
+            stack.extend(self.children(node));
+        }
+
+        sort_hits(&mut hits);
+        hits
+    }
+
+    fn nearest(&self, point: Vec2<Space, f32>) -> Option<(Id, f32)> {
+        let mut best: Option<(Id, f32)> = None;
+        let mut stack = vec![(0, 0.0)];
+        while let Some((node, reach)) = stack.pop() {
+            if best.is_some_and(|(_, closest)| reach >= closest) {
+                continue;
+            }
+
+            for id in &self.nodes[node].ids {
+                let Some(bounds) = self.bounds(*id) else {
+                    continue;
+                };
+                let distance = distance_to_rect(point, &bounds);
+                if best.is_none_or(|(_, closest)| distance < closest) {
+                    best = Some((*id, distance));
+                }
+            }
+
+            let mut children: Vec<_> = self
+                .children(node)
+                .map(|child| {
+                    let loose = Self::loose(&self.nodes[child].bounds);
+                    (child, distance_to_rect(point, &loose))
+                })
+                .collect();
+            // the closest child is popped first, it tightens the bound for its siblings
+            children.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+            stack.extend(children);
+        }
+
+        best
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{WorldPosition, WorldRect};
+
+    #[test]
+    fn entries_sink_to_fitting_nodes() {
+        let region =
+            WorldRect::new(WorldPosition::new(0.0, 0.0), WorldPosition::new(16.0, 16.0)).unwrap();
+        let mut tree = Quadtree::new(region, 4);
+
+        let small =
+            WorldRect::new(WorldPosition::new(1.0, 1.0), WorldPosition::new(1.5, 1.5)).unwrap();
+        let outside = small.translate(WorldPosition::new(100.0, 0.0));
+        tree.insert(1, small);
+        tree.insert(2, outside);
+        tree.insert(3, region);
+
+        assert_ne!(tree.entries[&1].1, 0);
+        assert_eq!(tree.entries[&2].1, 0);
+        assert_eq!(tree.entries[&3].1, 0);
+
+        let mut found = Vec::new();
+        tree.query_region(&outside.expand(1.0).unwrap(), |id| found.push(id));
+        assert_eq!(found, [2]);
+        assert_eq!(tree.nearest(WorldPosition::new(0.0, 0.0)), Some((3, 0.0)));
+    }
+}
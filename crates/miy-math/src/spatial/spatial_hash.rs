@@ -0,0 +1,343 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use super::{SpatialIndex, distance_to_rect, ray_rect, sort_hits};
+use crate::collision::Ray;
+use crate::{Dimensions2D, Rect, Vec2};
+
+type Cell = (i32, i32);
+
+/// Buckets entries into a uniform grid of cells, every entry is stored in each cell it overlaps
+///
+/// The cell size should be around the size of a typical entry: much smaller cells store large
+/// entries many times, much larger cells put too many entries into one bucket. Entries covering
+/// more than [`SpatialHash::MAX_CELLS`] cells or with bounds that are not finite are kept in a
+/// separate list instead, which every query scans.
+#[derive(Clone, Debug)]
+pub struct SpatialHash<Space, Id> {
+    cell_size: Dimensions2D<Space, f32>,
+    cells: BTreeMap<Cell, Vec<Id>>,
+    oversized: Vec<Id>,
+    entries: BTreeMap<Id, Rect<Space, f32>>,
+}
+
+impl<Space: Copy, Id: Copy + Ord> SpatialHash<Space, Id> {
+    /// Most cells a single entry is stored in
+    pub const MAX_CELLS: u64 = 4096;
+
+    pub fn new(cell_size: Dimensions2D<Space, f32>) -> Self {
+        Self {
+            cell_size,
+            cells: BTreeMap::new(),
+            oversized: Vec::new(),
+            entries: BTreeMap::new(),
+        }
+    }
+
+    pub fn cell_size(&self) -> Dimensions2D<Space, f32> {
+        self.cell_size
+    }
+
+    fn cell(&self, point: Vec2<Space, f32>) -> Cell {
+        (
+            (point.x / self.cell_size.width().get()).floor() as i32,
+            (point.y / self.cell_size.height().get()).floor() as i32,
+        )
+    }
+
+    /// Every cell from the first to the last one, inclusive
+    fn cells_between(first: Cell, last: Cell) -> impl Iterator<Item = Cell> {
+        (first.1..=last.1).flat_map(move |y| (first.0..=last.0).map(move |x| (x, y)))
+    }
+
+    fn cell_count(first: Cell, last: Cell) -> u64 {
+        let columns = u64::from(last.0.abs_diff(first.0)) + 1;
+        let rows = u64::from(last.1.abs_diff(first.1)) + 1;
+        columns.saturating_mul(rows)
+    }
+
+    /// The first and last cell `bounds` is stored in, `None` if it belongs to the oversized list
+    fn span(&self, bounds: &Rect<Space, f32>) -> Option<(Cell, Cell)> {
+        let (min, max) = (bounds.min(), bounds.max());
+        if ![min.x, min.y, max.x, max.y]
+            .iter()
+            .all(|value| value.is_finite())
+        {
+            return None;
+        }
+
+        let (first, last) = (self.cell(min), self.cell(max));
+        (Self::cell_count(first, last) <= Self::MAX_CELLS).then_some((first, last))
+    }
+
+    fn link(&mut self, id: Id, bounds: &Rect<Space, f32>) {
+        let Some((first, last)) = self.span(bounds) else {
+            self.oversized.push(id);
+            return;
+        };
+        for cell in Self::cells_between(first, last) {
+            self.cells.entry(cell).or_default().push(id);
+        }
+    }
+
+    fn unlink(&mut self, id: Id, bounds: &Rect<Space, f32>) {
+        let Some((first, last)) = self.span(bounds) else {
+            self.oversized.retain(|other| *other != id);
+            return;
+        };
+        for cell in Self::cells_between(first, last) {
+            if let Some(ids) = self.cells.get_mut(&cell) {
+                ids.retain(|other| *other != id);
+                if ids.is_empty() {
+                    self.cells.remove(&cell);
+                }
+            }
+        }
+    }
+
+    /// Calls `visit` for every occupied cell within `first..=last`
+    fn visit_cells(&self, first: Cell, last: Cell, mut visit: impl FnMut(Cell, &[Id])) {
+        let within =
+            |(x, y): Cell| (first.0..=last.0).contains(&x) && (first.1..=last.1).contains(&y);
+
+        // large regions over a sparse grid are cheaper to answer from the occupied cells
+        if Self::cell_count(first, last) > self.cells.len() as u64 {
+            for (cell, ids) in self.cells.iter().filter(|(cell, _)| within(**cell)) {
+                visit(*cell, ids);
+            }
+        } else {
+            for cell in Self::cells_between(first, last) {
+                if let Some(ids) = self.cells.get(&cell) {
+                    visit(cell, ids);
+                }
+            }
+        }
+    }
+}
+
+impl<Space: Copy, Id: Copy + Ord> SpatialIndex<Space, Id> for SpatialHash<Space, Id> {
+    fn insert(&mut self, id: Id, bounds: Rect<Space, f32>) -> Option<Rect<Space, f32>> {
+        let previous = self.remove(id);
+        self.link(id, &bounds);
+        self.entries.insert(id, bounds);
+        previous
+    }
+
+    fn update(&mut self, id: Id, bounds: Rect<Space, f32>) -> bool {
+        let Some(previous) = self.entries.get(&id).copied() else {
+            return false;
+        };
+
+        if self.span(&previous) != self.span(&bounds) {
+            self.unlink(id, &previous);
+            self.link(id, &bounds);
+        }
+        self.entries.insert(id, bounds);
+        true
+    }
+
+    fn remove(&mut self, id: Id) -> Option<Rect<Space, f32>> {
+        let bounds = self.entries.remove(&id)?;
+        self.unlink(id, &bounds);
+        Some(bounds)
+    }
+
+    fn bounds(&self, id: Id) -> Option<Rect<Space, f32>> {
+        self.entries.get(&id).copied()
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    fn query_region(&self, region: &Rect<Space, f32>, mut visit: impl FnMut(Id)) {
+        let (first, last) = (self.cell(region.min()), self.cell(region.max()));
+
+        self.visit_cells(first, last, |cell, ids| {
+            for id in ids {
+                let Some(bounds) = self.entries.get(id) else {
+                    continue;
+                };
+
+                // an entry spanning several cells is only reported from the first shared cell
+                let own = self.cell(bounds.min());
+                let shared = (own.0.max(first.0), own.1.max(first.1));
+                if cell == shared && bounds.intersects(region) {
+                    visit(*id);
+                }
+            }
+        });
+
+        for id in &self.oversized {
+            if self
+                .entries
+                .get(id)
+                .is_some_and(|bounds| bounds.intersects(region))
+            {
+                visit(*id);
+            }
+        }
+    }
+
+    fn query_ray(&self, ray: &Ray<Space>, max_distance: f32) -> Vec<(Id, f32)> {
+        let mut hits = Vec::new();
+        let mut test = |id: Id, bounds: &Rect<Space, f32>| {
+            if let Some(distance) = ray_rect(ray, max_distance, bounds) {
+                hits.push((id, distance));
+            }
+        };
+
+        if max_distance.is_finite() {
+            let region = Rect::from_corners(ray.origin(), ray.at(max_distance));
+            self.query_region(&region, |id| {
+                if let Some(bounds) = self.entries.get(&id) {
+                    test(id, bounds);
+                }
+            });
+        } else {
+            for (id, bounds) in &self.entries {
+                test(*id, bounds);
+            }
+        }
+
+        sort_hits(&mut hits);
+        hits
+    }
+
+    fn nearest(&self, point: Vec2<Space, f32>) -> Option<(Id, f32)> {
+        let center = self.cell(point);
+        let ring_of = |(x, y): Cell| x.abs_diff(center.0).max(y.abs_diff(center.1));
+
+        let mut best: Option<(Id, f32)> = None;
+        for id in &self.oversized {
+            let Some(bounds) = self.entries.get(id) else {
+                continue;
+            };
+            let distance = distance_to_rect(point, bounds);
+            if best.is_none_or(|(_, closest)| distance < closest) {
+                best = Some((*id, distance));
+            }
+        }
+
+        // only the rings holding occupied cells, a far away query skips the empty ones between
+        let mut rings: Vec<u32> = self.cells.keys().map(|cell| ring_of(*cell)).collect();
+        rings.sort_unstable();
+        rings.dedup();
+
+        // every cell beyond ring `r` is at least `r` cells away from `point`
+        let step = self
+            .cell_size
+            .width()
+            .get()
+            .min(self.cell_size.height().get());
+
+        for ring in rings {
+            let reach = i32::try_from(ring).unwrap_or(i32::MAX);
+            let first = (
+                center.0.saturating_sub(reach),
+                center.1.saturating_sub(reach),
+            );
+            let last = (
+                center.0.saturating_add(reach),
+                center.1.saturating_add(reach),
+            );
+
+            self.visit_cells(first, last, |cell, ids| {
+                if ring_of(cell) != ring {
+                    return;
+                }
+                for id in ids {
+                    let Some(bounds) = self.entries.get(id) else {
+                        continue;
+                    };
+                    let distance = distance_to_rect(point, bounds);
+                    if best.is_none_or(|(_, closest)| distance < closest) {
+                        best = Some((*id, distance));
+                    }
+                }
+            });
+
+            if best.is_some_and(|(_, closest)| closest <= ring as f32 * step) {
+                break;
+            }
+        }
+
+        best
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{WorldDimensions2D, WorldPosition, WorldRect};
+
+    #[test]
+    fn large_entries_are_reported_once() {
+        let mut hash = SpatialHash::new(WorldDimensions2D::new(1.0, 1.0).unwrap());
+        let large =
+            WorldRect::new(WorldPosition::new(-3.5, -3.5), WorldPosition::new(3.5, 3.5)).unwrap();
+        hash.insert("large", large);
+
+        let mut found = Vec::new();
+        hash.query_region(&large.expand(1.0).unwrap(), |id| found.push(id));
+        assert_eq!(found, ["large"]);
+
+        assert!(hash.update("large", large.translate(WorldPosition::new(20.0, 0.0))));
+        found.clear();
+        hash.query_region(&large, |id| found.push(id));
+        assert!(found.is_empty());
+        assert_eq!(
+            hash.nearest(WorldPosition::new(0.0, 0.0)),
+            Some(("large", 16.5))
+        );
+    }
+
+    #[test]
+    fn distant_queries_skip_empty_rings() {
+        let mut hash = SpatialHash::new(WorldDimensions2D::new(1.0, 1.0).unwrap());
+        for id in 0..200_u16 {
+            let min = WorldPosition::new(f32::from(id % 20), f32::from(id / 20));
+            let max = WorldPosition::new(min.x + 0.5, min.y + 0.5);
+            hash.insert(id, WorldRect::new(min, max).unwrap());
+        }
+
+        assert_eq!(
+            hash.nearest(WorldPosition::new(0.25, 1e5)),
+            Some((180, 99_990.5))
+        );
+    }
+
+    #[test]
+    fn unbounded_entries_skip_the_cells() {
+        let mut hash = SpatialHash::new(WorldDimensions2D::new(1.0, 1.0).unwrap());
+        let everything = WorldRect::new(
+            WorldPosition::new(f32::NEG_INFINITY, 0.0),
+            WorldPosition::new(f32::INFINITY, 1.0),
+        )
+        .unwrap();
+        let huge =
+            WorldRect::new(WorldPosition::new(-1e9, -1e9), WorldPosition::new(1e9, 1e9)).unwrap();
+        let small =
+            WorldRect::new(WorldPosition::new(0.0, 0.0), WorldPosition::new(1.0, 1.0)).unwrap();
+        hash.insert("everything", everything);
+        hash.insert("huge", huge);
+        hash.insert("small", small);
+        assert_eq!(hash.cells.values().flatten().count(), 4);
+
+        let mut found = Vec::new();
+        hash.query_region(&small, |id| found.push(id));
+        found.sort_unstable();
+        assert_eq!(found, ["everything", "huge", "small"]);
+        assert_eq!(
+            hash.nearest(WorldPosition::new(5.0, 1e8)),
+            Some(("huge", 0.0))
+        );
+
+        assert!(hash.update("huge", small.translate(WorldPosition::new(5.0, 5.0))));
+        assert_eq!(hash.remove("everything"), Some(everything));
+        assert!(hash.oversized.is_empty());
+        assert_eq!(
+            hash.nearest(WorldPosition::new(5.0, 5.0)),
+            Some(("huge", 0.0))
+        );
+    }
+}
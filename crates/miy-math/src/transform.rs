@@ -0,0 +1,473 @@
+use crate::{Mat3, Mat4, Quat, Radians, Vec2, Vec3};
+use alloc::vec::Vec;
+
+/// Translation, counter-clockwise rotation and scale in 2D, applied as scale first,
+/// then rotation and translation last
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Transform2D<Space> {
+    pub translation: Vec2<Space, f32>,
+    pub rotation: Radians,
+    pub scale: Vec2<Space, f32>,
+}
+
+/// Translation, rotation and scale in 3D, applied as scale first,
+/// then rotation and translation last
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Transform3D<Space> {
+    pub translation: Vec3<Space, f32>,
+    pub rotation: Quat,
+    pub scale: Vec3<Space, f32>,
+}
+
+/// Parent-child composition of transforms
+pub trait Compose: Copy {
+    const IDENTITY: Self;
+
+    /// Places `child`, which is relative to `self`, into the space `self` is relative to
+    fn compose(&self, child: &Self) -> Self;
+}
+
+impl<Space> Transform2D<Space> {
+    pub const IDENTITY: Self = Self {
+        translation: Vec2::new(0.0, 0.0),
+        rotation: Radians::ZERO,
+        scale: Vec2::new(1.0, 1.0),
+    };
+
+    pub const fn from_translation(translation: Vec2<Space, f32>) -> Self {
+        Self {
+            translation,
+            ..Self::IDENTITY
+        }
+    }
+
+    pub fn from_rotation(rotation: impl Into<Radians>) -> Self {
+        Self {
+            rotation: rotation.into(),
+            ..Self::IDENTITY
+        }
+    }
+
+    pub const fn from_scale(scale: Vec2<Space, f32>) -> Self {
+        Self {
+            scale,
+            ..Self::IDENTITY
+        }
+    }
+
+    pub fn with_translation(self, translation: Vec2<Space, f32>) -> Self {
+        Self {
+            translation,
+            ..self
+        }
+    }
+
+    pub fn with_rotation(self, rotation: impl Into<Radians>) -> Self {
+        Self {
+            rotation: rotation.into(),
+            ..self
+        }
+    }
+
+    pub fn with_scale(self, scale: Vec2<Space, f32>) -> Self {
+        Self { scale, ..self }
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "rotated and scaled float point plus the translation"
+    )]
+    pub fn transform_point(&self, point: Vec2<Space, f32>) -> Vec2<Space, f32> {
+        self.transform_vector(point) + self.translation
+    }
+
+    /// Applies scale and rotation, but not translation
+    pub fn transform_vector(&self, vector: Vec2<Space, f32>) -> Vec2<Space, f32> {
+        rotate_2d(vector.mul_elements(self.scale), self.rotation)
+    }
+
+    /// The inverse transform, exact as long as the scale is uniform
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "negated float rotation and translation"
+    )]
+    pub fn inverse(&self) -> Self {
+        let scale = self.scale.map(|s| 1.0 / s);
+        let rotation = -self.rotation;
+        let translation = rotate_2d(-self.translation, rotation).mul_elements(scale);
+
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    /// Interpolates each part, the rotation along the shortest path
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "angles blend along the shorter arc, plain floats"
+    )]
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        let delta = (other.rotation - self.rotation).wrap();
+
+        Self {
+            translation: self.translation.lerp(other.translation, t),
+            rotation: self.rotation + delta * t,
+            scale: self.scale.lerp(other.scale, t),
+        }
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "multiplies the float TRS matrices"
+    )]
+    pub fn to_mat3(&self) -> Mat3 {
+        Mat3::from_translation(self.translation)
+            * Mat3::from_rotation(self.rotation)
+            * Mat3::from_scale(self.scale)
+    }
+
+    pub fn to_mat4(&self) -> Mat4 {
+        Mat4::from_mat3_2d(self.to_mat3())
+    }
+}
+
+impl<Space> Transform3D<Space> {
+    pub const IDENTITY: Self = Self {
+        translation: Vec3::new(0.0, 0.0, 0.0),
+        rotation: Quat::IDENTITY,
+        scale: Vec3::new(1.0, 1.0, 1.0),
+    };
+
+    pub const fn from_translation(translation: Vec3<Space, f32>) -> Self {
+        Self {
+            translation,
+            ..Self::IDENTITY
+        }
+    }
+
+    pub const fn from_rotation(rotation: Quat) -> Self {
+        Self {
+            rotation,
+            ..Self::IDENTITY
+        }
+    }
+
+    pub const fn from_scale(scale: Vec3<Space, f32>) -> Self {
+        Self {
+            scale,
+            ..Self::IDENTITY
+        }
+    }
+
+    pub fn with_translation(self, translation: Vec3<Space, f32>) -> Self {
+        Self {
+            translation,
+            ..self
+        }
+    }
+
+    pub fn with_rotation(self, rotation: Quat) -> Self {
+        Self { rotation, ..self }
+    }
+
+    pub fn with_scale(self, scale: Vec3<Space, f32>) -> Self {
+        Self { scale, ..self }
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "rotated and scaled 3D point plus the translation"
+    )]
+    pub fn transform_point(&self, point: Vec3<Space, f32>) -> Vec3<Space, f32> {
+        self.transform_vector(point) + self.translation
+    }
+
+    /// Applies scale and rotation, but not translation
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "applies the unit rotation to the scaled vector"
+    )]
+    pub fn transform_vector(&self, vector: Vec3<Space, f32>) -> Vec3<Space, f32> {
+        self.rotation * vector.mul_elements(self.scale)
+    }
+
+    /// The inverse transform, exact as long as the scale is uniform
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "conjugate rotation applied to the negated translation"
+    )]
+    pub fn inverse(&self) -> Self {
+        let scale = self.scale.map(|s| 1.0 / s);
+        let rotation = self.rotation.conjugate();
+        let translation = (rotation * -self.translation).mul_elements(scale);
+
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    /// Interpolates each part, the rotation with [`Quat::slerp`]
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Self {
+            translation: self.translation.lerp(other.translation, t),
+            rotation: self.rotation.slerp(other.rotation, t),
+            scale: self.scale.lerp(other.scale, t),
+        }
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "multiplies the float TRS matrices in 3D"
+    )]
+    pub fn to_mat4(&self) -> Mat4 {
+        Mat4::from_translation(self.translation)
+            * self.rotation.to_mat4()
+            * Mat4::from_scale(self.scale)
+    }
+}
+
+impl<Space> Default for Transform2D<Space> {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl<Space> Default for Transform3D<Space> {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// Scale of the result is the component-wise product, so a non-uniformly scaled parent
+/// with a rotated child can't represent the resulting shear.
+/// Use [`Transform2D::to_mat3`] if that matters.
+impl<Space: Copy> Compose for Transform2D<Space> {
+    const IDENTITY: Self = Self::IDENTITY;
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "sums the float rotation angles"
+    )]
+    fn compose(&self, child: &Self) -> Self {
+        Self {
+            translation: self.transform_point(child.translation),
+            rotation: self.rotation + child.rotation,
+            scale: self.scale.mul_elements(child.scale),
+        }
+    }
+}
+
+/// Scale of the result is the component-wise product, so a non-uniformly scaled parent
+/// with a rotated child can't represent the resulting shear.
+/// Use [`Transform3D::to_mat4`] if that matters.
+impl<Space: Copy> Compose for Transform3D<Space> {
+    const IDENTITY: Self = Self::IDENTITY;
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "chains the unit quaternions"
+    )]
+    fn compose(&self, child: &Self) -> Self {
+        Self {
+            translation: self.transform_point(child.translation),
+            rotation: self.rotation * child.rotation,
+            scale: self.scale.mul_elements(child.scale),
+        }
+    }
+}
+
+impl Compose for Mat3 {
+    const IDENTITY: Self = Self::IDENTITY;
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "matrices compose by multiplication"
+    )]
+    fn compose(&self, child: &Self) -> Self {
+        *self * *child
+    }
+}
+
+impl Compose for Mat4 {
+    const IDENTITY: Self = Self::IDENTITY;
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "4x4 matrices compose by multiplication"
+    )]
+    fn compose(&self, child: &Self) -> Self {
+        *self * *child
+    }
+}
+
+fn rotate_2d<Space>(v: Vec2<Space, f32>, angle: Radians) -> Vec2<Space, f32> {
+    let (sin, cos) = angle.sin_cos();
+    Vec2::new(v.x * cos - v.y * sin, v.x * sin + v.y * cos)
+}
+
+/// Handle to a node in a [`Hierarchy`]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct NodeId(usize);
+
+/// Flat list of transforms where every node can be attached to a parent.
+///
+/// Parents are always added before their children,
+/// so [`Hierarchy::propagate`] is a single pass over all nodes.
+#[derive(Clone, Debug)]
+pub struct Hierarchy<T> {
+    locals: Vec<T>,
+    parents: Vec<Option<NodeId>>,
+    worlds: Vec<T>,
+}
+
+impl<T: Compose> Hierarchy<T> {
+    pub fn new() -> Self {
+        Self {
+            locals: Vec::new(),
+            parents: Vec::new(),
+            worlds: Vec::new(),
+        }
+    }
+
+    /// Adds a node relative to `parent`, or the root if there is none
+    pub fn add(&mut self, local: T, parent: Option<NodeId>) -> NodeId {
+        let id = NodeId(self.locals.len());
+        let world = match parent {
+            Some(parent) => self.worlds[parent.0].compose(&local),
+            None => local,
+        };
+
+        self.locals.push(local);
+        self.parents.push(parent);
+        self.worlds.push(world);
+        id
+    }
+
+    pub fn local(&self, id: NodeId) -> T {
+        self.locals[id.0]
+    }
+
+    pub fn set_local(&mut self, id: NodeId, local: T) {
+        self.locals[id.0] = local;
+    }
+
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.parents[id.0]
+    }
+
+    /// The transform relative to the root, as of the last [`Hierarchy::propagate`]
+    pub fn world(&self, id: NodeId) -> T {
+        self.worlds[id.0]
+    }
+
+    /// Recomputes every world transform from the local ones
+    pub fn propagate(&mut self) {
+        for index in 0..self.locals.len() {
+            let local = self.locals[index];
+            self.worlds[index] = match self.parents[index] {
+                Some(parent) => self.worlds[parent.0].compose(&local),
+                None => local,
+            };
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.locals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.locals.is_empty()
+    }
+}
+
+impl<T: Compose> Default for Hierarchy<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{WorldPosition, WorldVec3, space::World};
+    use core::f32::consts::PI;
+
+    #[test]
+    fn transform_2d() {
+        let transform = Transform2D::<World>::from_translation(WorldPosition::new(10.0, 0.0))
+            .with_rotation(Radians::FRAC_PI_2)
+            .with_scale(WorldPosition::new(2.0, 2.0));
+
+        let point = transform.transform_point(WorldPosition::new(1.0, 0.0));
+        assert!((point - WorldPosition::new(10.0, 2.0)).length() < 1e-5);
+
+        let matrix = transform
+            .to_mat3()
+            .transform_point(WorldPosition::new(1.0, 0.0));
+        assert!((matrix - point).length() < 1e-5);
+
+        let back = transform.inverse().transform_point(point);
+        assert!((back - WorldPosition::new(1.0, 0.0)).length() < 1e-5);
+
+        let a = Transform2D::<World>::from_rotation(Radians::new(3.0).unwrap());
+        let b = Transform2D::<World>::from_rotation(Radians::new(-3.0).unwrap());
+        let halfway = a.lerp(&b, 0.5).rotation;
+        assert!((halfway.get() - PI).abs() < 1e-5);
+    }
+
+    #[test]
+    fn transform_3d() {
+        let transform = Transform3D::<World>::from_translation(WorldVec3::new(1.0, 2.0, 3.0))
+            .with_rotation(Quat::from_rotation_y(Radians::FRAC_PI_2))
+            .with_scale(WorldVec3::splat(3.0));
+
+        let point = WorldVec3::new(1.0, 0.0, 0.0);
+        let moved = transform.transform_point(point);
+        assert!((moved - WorldVec3::new(1.0, 2.0, 0.0)).length() < 1e-5);
+        assert!((transform.to_mat4().transform_point(point) - moved).length() < 1e-5);
+
+        let back = transform.inverse().transform_point(moved);
+        assert!((back - point).length() < 1e-5);
+
+        let composed = transform.compose(&transform.inverse());
+        assert!((composed.transform_point(point) - point).length() < 1e-5);
+    }
+
+    #[test]
+    fn hierarchy_propagation() {
+        let mut hierarchy = Hierarchy::new();
+        let parent = hierarchy.add(
+            Transform2D::<World>::from_translation(WorldPosition::new(5.0, 0.0)),
+            None,
+        );
+        let child = hierarchy.add(
+            Transform2D::from_translation(WorldPosition::new(1.0, 0.0)),
+            Some(parent),
+        );
+        let grandchild = hierarchy.add(
+            Transform2D::from_translation(WorldPosition::new(0.0, 1.0)),
+            Some(child),
+        );
+
+        assert_eq!(
+            hierarchy.world(grandchild).translation,
+            WorldPosition::new(6.0, 1.0)
+        );
+
+        hierarchy.set_local(
+            parent,
+            Transform2D::from_translation(WorldPosition::new(0.0, 0.0))
+                .with_rotation(Radians::FRAC_PI_2),
+        );
+        hierarchy.propagate();
+
+        let world = hierarchy.world(grandchild);
+        assert!((world.translation - WorldPosition::new(-1.0, 1.0)).length() < 1e-5);
+        assert!((world.rotation - Radians::FRAC_PI_2).get().abs() < 1e-5);
+        assert_eq!(hierarchy.parent(grandchild), Some(child));
+    }
+}
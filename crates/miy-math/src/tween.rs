@@ -0,0 +1,331 @@
+use alloc::boxed::Box;
+
+use crate::{Dimensions2D, Easing, Quat, Radians, Transform2D, Transform3D, Vec2, Vec3, Vec4};
+
+/// Blends between two values, `t = 0.0` returns `self`, `t = 1.0` returns `other`.
+///
+/// `t` is not clamped, eased progress like [`Easing::BackOut`] overshoots on purpose.
+/// Types with invariants keep them, e.g. a [`Dimensions2D`] never shrinks to zero.
+pub trait Interpolate: Sized {
+    fn interpolate(&self, other: &Self, t: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl<Space> Interpolate for Vec2<Space, f32> {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Vec2::new(
+            self.x.interpolate(&other.x, t),
+            self.y.interpolate(&other.y, t),
+        )
+    }
+}
+
+impl<Space> Interpolate for Vec3<Space, f32> {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Vec3::new(
+            self.x.interpolate(&other.x, t),
+            self.y.interpolate(&other.y, t),
+            self.z.interpolate(&other.z, t),
+        )
+    }
+}
+
+impl<Space> Interpolate for Vec4<Space, f32> {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Vec4::new(
+            self.x.interpolate(&other.x, t),
+            self.y.interpolate(&other.y, t),
+            self.z.interpolate(&other.z, t),
+            self.w.interpolate(&other.w, t),
+        )
+    }
+}
+
+/// Overshooting below zero is clamped to the smallest positive size
+impl<Space: Copy> Interpolate for Dimensions2D<Space, f32> {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        let dimension = |a: f32, b: f32| a.interpolate(&b, t).max(f32::MIN_POSITIVE);
+        let width = dimension(self.width().get(), other.width().get());
+        let height = dimension(self.height().get(), other.height().get());
+        Self::new(width, height).unwrap_or(*self)
+    }
+}
+
+/// Rounds to the nearest pixel, never below one
+impl<Space: Copy> Interpolate for Dimensions2D<Space, u32> {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        let dimension = |a: u32, b: u32| {
+            let value = (a as f32).interpolate(&(b as f32), t).round();
+            value.clamp(1.0, u32::MAX as f32) as u32
+        };
+        let width = dimension(self.width().get(), other.width().get());
+        let height = dimension(self.height().get(), other.height().get());
+        Self::new(width, height).unwrap_or(*self)
+    }
+}
+
+/// Interpolates the stored value, use [`Transform2D::lerp`] to take the shortest path
+impl Interpolate for Radians {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Radians::new(self.get().interpolate(&other.get(), t)).unwrap_or(*self)
+    }
+}
+
+impl Interpolate for Quat {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self.slerp(*other, t)
+    }
+}
+
+impl<Space> Interpolate for Transform2D<Space> {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self.lerp(other, t)
+    }
+}
+
+impl<Space> Interpolate for Transform3D<Space> {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self.lerp(other, t)
+    }
+}
+
+/// How often a [`Tween`] plays before it finishes
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum Repeat {
+    #[default]
+    Once,
+    /// Plays this many times in total, `Times(0)` behaves like [`Repeat::Once`]
+    Times(u32),
+    Forever,
+}
+
+/// Animates a value from `from` to `to` over `duration` seconds
+///
+/// ```
+/// use miy_math::{Easing, Tween, WorldPosition};
+///
+/// let mut tween = Tween::new(WorldPosition::new(0.0, 0.0), WorldPosition::new(10.0, 0.0), 2.0)
+///     .unwrap()
+///     .with_easing(Easing::QuadIn);
+///
+/// assert_eq!(tween.update(1.0), WorldPosition::new(2.5, 0.0));
+/// assert_eq!(tween.update(1.0), WorldPosition::new(10.0, 0.0));
+/// assert!(tween.is_finished());
+/// ```
+pub struct Tween<T> {
+    from: T,
+    to: T,
+    duration: f32,
+    delay: f32,
+    easing: Easing,
+    repeat: Repeat,
+    yoyo: bool,
+    elapsed: f32,
+    finished: bool,
+    on_complete: Option<Box<dyn FnMut()>>,
+}
+
+impl<T: Interpolate> Tween<T> {
+    /// Returns `None` if `duration` is not positive and finite
+    pub fn new(from: T, to: T, duration: f32) -> Option<Self> {
+        if !(duration > 0.0 && duration.is_finite()) {
+            return None;
+        }
+
+        Some(Self {
+            from,
+            to,
+            duration,
+            delay: 0.0,
+            easing: Easing::Linear,
+            repeat: Repeat::Once,
+            yoyo: false,
+            elapsed: 0.0,
+            finished: false,
+            on_complete: None,
+        })
+    }
+
+    pub fn with_easing(self, easing: Easing) -> Self {
+        Self { easing, ..self }
+    }
+
+    /// Seconds to hold `from` before playing, negative delays are ignored
+    pub fn with_delay(self, delay: f32) -> Self {
+        Self {
+            delay: delay.max(0.0),
+            ..self
+        }
+    }
+
+    pub fn with_repeat(self, repeat: Repeat) -> Self {
+        Self { repeat, ..self }
+    }
+
+    /// Plays every second cycle backwards, from `to` to `from`
+    pub fn with_yoyo(self, yoyo: bool) -> Self {
+        Self { yoyo, ..self }
+    }
+
+    /// Called once, on the update that finishes the tween
+    pub fn on_complete(self, callback: impl FnMut() + 'static) -> Self {
+        Self {
+            on_complete: Some(Box::new(callback)),
+            ..self
+        }
+    }
+
+    /// Advances the tween by `dt` seconds and returns the new value
+    pub fn update(&mut self, dt: f32) -> T {
+        if !self.finished && dt > 0.0 {
+            self.elapsed += dt;
+
+            if let Some(total) = self.total_duration()
+                && self.elapsed >= total
+            {
+                self.finished = true;
+                if let Some(callback) = &mut self.on_complete {
+                    callback();
+                }
+            }
+        }
+
+        self.value()
+    }
+
+    pub fn value(&self) -> T {
+        self.from.interpolate(&self.to, self.progress())
+    }
+
+    /// Eased progress from `from` towards `to`, reversed while a yoyo cycle plays back
+    pub fn progress(&self) -> f32 {
+        let active = (self.elapsed - self.delay).max(0.0);
+        let (cycle, t) = match self.total_duration() {
+            Some(total) if active >= total => {
+                // the last cycle ends on its final frame rather than wrapping back to the start
+                (((total - self.delay) / self.duration).round() - 1.0, 1.0)
+            }
+            _ => {
+                let cycles = active / self.duration;
+                (cycles.floor(), cycles.fract())
+            }
+        };
+
+        let backwards = self.yoyo && cycle % 2.0 == 1.0;
+        let t = if backwards { 1.0 - t } else { t };
+        self.easing.apply(t)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Rewinds to the start, including the delay, the callback will fire again
+    pub fn reset(&mut self) {
+        self.elapsed = 0.0;
+        self.finished = false;
+    }
+
+    pub fn from(&self) -> &T {
+        &self.from
+    }
+
+    pub fn to(&self) -> &T {
+        &self.to
+    }
+
+    pub fn duration(&self) -> f32 {
+        self.duration
+    }
+
+    /// Delay plus every cycle, `None` for [`Repeat::Forever`]
+    pub fn total_duration(&self) -> Option<f32> {
+        let cycles = match self.repeat {
+            Repeat::Once => 1,
+            Repeat::Times(times) => times.max(1),
+            Repeat::Forever => return None,
+        };
+        Some(self.delay + self.duration * cycles as f32)
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for Tween<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Tween")
+            .field("from", &self.from)
+            .field("to", &self.to)
+            .field("duration", &self.duration)
+            .field("delay", &self.delay)
+            .field("easing", &self.easing)
+            .field("repeat", &self.repeat)
+            .field("yoyo", &self.yoyo)
+            .field("elapsed", &self.elapsed)
+            .field("finished", &self.finished)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::rc::Rc;
+    use core::cell::Cell;
+
+    use super::*;
+    use crate::{WorldDimensions2D, WorldPosition};
+
+    #[test]
+    fn interpolate_values() {
+        let a = WorldPosition::new(0.0, 2.0);
+        let b = WorldPosition::new(4.0, -2.0);
+        assert_eq!(a.interpolate(&b, 0.25), WorldPosition::new(1.0, 1.0));
+
+        let small = WorldDimensions2D::new(1.0, 1.0).unwrap();
+        let large = WorldDimensions2D::new(3.0, 5.0).unwrap();
+        assert_eq!(
+            small.interpolate(&large, 0.5),
+            WorldDimensions2D::new(2.0, 3.0).unwrap()
+        );
+        assert!(large.interpolate(&small, 2.0).width().get() > 0.0);
+    }
+
+    #[test]
+    fn tween_delay_repeat_and_yoyo() {
+        let completed = Rc::new(Cell::new(0));
+        let counter = Rc::clone(&completed);
+
+        let mut tween = Tween::new(0.0, 10.0, 1.0)
+            .unwrap()
+            .with_delay(0.5)
+            .with_repeat(Repeat::Times(2))
+            .with_yoyo(true)
+            .on_complete(move || counter.set(counter.get() + 1));
+
+        assert_eq!(tween.total_duration(), Some(2.5));
+        assert_eq!(tween.update(0.25), 0.0);
+        assert_eq!(tween.update(0.5), 2.5);
+        assert_eq!(tween.update(1.0), 7.5);
+        assert!(!tween.is_finished());
+
+        assert_eq!(tween.update(5.0), 0.0);
+        assert!(tween.is_finished());
+        assert_eq!(tween.update(1.0), 0.0);
+        assert_eq!(completed.get(), 1);
+
+        tween.reset();
+        assert_eq!(tween.update(2.5), 0.0);
+        assert_eq!(completed.get(), 2);
+
+        let mut forever = Tween::new(0.0, 1.0, 2.0)
+            .unwrap()
+            .with_repeat(Repeat::Forever);
+        assert_eq!(forever.update(5.0), 0.5);
+        assert!(!forever.is_finished());
+
+        assert!(Tween::new(0.0, 1.0, 0.0).is_none());
+    }
+}
@@ -0,0 +1,352 @@
+use core::{
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::space;
+
+/// Two component vector tagged with the [`space`] it lives in
+///
+/// Vectors of different spaces can't be mixed:
+/// ```compile_fail
+/// use miy_math::{ScreenVec2, WorldVec2};
+///
+/// let _ = WorldVec2::new(1.0, 2.0) + ScreenVec2::new(3.0, 4.0);
+/// ```
+///
+/// The space marker is public, so struct literals and patterns keep working:
+/// ```
+/// use core::marker::PhantomData;
+/// use miy_math::WorldPosition;
+///
+/// let position = WorldPosition { x: 1.0, y: 2.0, space: PhantomData };
+/// let WorldPosition { x, y, .. } = position;
+/// assert_eq!((x, y), (1.0, 2.0));
+/// ```
+pub struct Vec2<Space, T> {
+    pub x: T,
+    pub y: T,
+    pub space: PhantomData<Space>,
+}
+
+/// Three component vector tagged with the [`space`] it lives in
+pub struct Vec3<Space, T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub space: PhantomData<Space>,
+}
+
+/// Four component vector tagged with the [`space`] it lives in
+pub struct Vec4<Space, T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
+    pub space: PhantomData<Space>,
+}
+
+pub type WorldVec2 = Vec2<space::World, f32>;
+pub type WorldVec3 = Vec3<space::World, f32>;
+pub type WorldVec4 = Vec4<space::World, f32>;
+
+pub type ScreenVec2 = Vec2<space::Screen, f32>;
+
+pub type ClipVec4 = Vec4<space::Clip, f32>;
+
+macro_rules! vector {
+    ($name:ident { $($field:ident),+ }, $len:literal) => {
+        impl<Space, T> $name<Space, T> {
+            pub const fn new($($field: T),+) -> Self {
+                Self {
+                    $($field,)+
+                    space: PhantomData,
+                }
+            }
+
+            pub fn splat(value: T) -> Self
+            where
+                T: Copy,
+            {
+                Self::new($(vector!(@replace $field, value)),+)
+            }
+
+            pub fn to_array(self) -> [T; $len] {
+                [$(self.$field),+]
+            }
+
+            /// Reinterprets the vector as living in another space.
+            /// Only use this if you know the spaces line up, e.g. after applying a matrix.
+            pub fn cast_space<Other>(self) -> $name<Other, T> {
+                $name::new($(self.$field),+)
+            }
+
+            pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> $name<Space, U> {
+                $name::new($(f(self.$field)),+)
+            }
+
+            #[expect(
+                clippy::arithmetic_side_effects,
+                reason = "uses the component's own operators, like the operator impls below"
+            )]
+            pub fn dot(self, other: Self) -> T
+            where
+                T: Copy + Add<Output = T> + Mul<Output = T>,
+            {
+                vector!(@sum $(self.$field * other.$field),+)
+            }
+
+            /// Component-wise multiplication
+            pub fn mul_elements(self, other: Self) -> Self
+            where
+                T: Mul<Output = T>,
+            {
+                Self::new($(self.$field * other.$field),+)
+            }
+        }
+
+        impl<Space, T> From<[T; $len]> for $name<Space, T> {
+            fn from([$($field),+]: [T; $len]) -> Self {
+                Self::new($($field),+)
+            }
+        }
+
+        impl<Space, T> From<$name<Space, T>> for [T; $len] {
+            fn from(value: $name<Space, T>) -> Self {
+                value.to_array()
+            }
+        }
+
+        impl<Space, T: Copy> Copy for $name<Space, T> {}
+
+        impl<Space, T: Clone> Clone for $name<Space, T> {
+            fn clone(&self) -> Self {
+                Self::new($(self.$field.clone()),+)
+            }
+        }
+
+        impl<Space, T: PartialEq> PartialEq for $name<Space, T> {
+            fn eq(&self, other: &Self) -> bool {
+                $(self.$field == other.$field)&&+
+            }
+        }
+
+        impl<Space, T: core::fmt::Debug> core::fmt::Debug for $name<Space, T> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct(stringify!($name))
+                    $(.field(stringify!($field), &self.$field))+
+                    .finish()
+            }
+        }
+
+        impl<Space, T: Default> Default for $name<Space, T> {
+            fn default() -> Self {
+                Self::new($(vector!(@replace $field, T::default())),+)
+            }
+        }
+
+        impl<Space, T: Add<Output = T>> Add for $name<Space, T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self::new($(self.$field + other.$field),+)
+            }
+        }
+
+        impl<Space, T: Sub<Output = T>> Sub for $name<Space, T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self::new($(self.$field - other.$field),+)
+            }
+        }
+
+        impl<Space, T: Copy + Mul<Output = T>> Mul<T> for $name<Space, T> {
+            type Output = Self;
+
+            fn mul(self, scalar: T) -> Self {
+                Self::new($(self.$field * scalar),+)
+            }
+        }
+
+        impl<Space, T: Copy + Div<Output = T>> Div<T> for $name<Space, T> {
+            type Output = Self;
+
+            fn div(self, scalar: T) -> Self {
+                Self::new($(self.$field / scalar),+)
+            }
+        }
+
+        impl<Space, T: Neg<Output = T>> Neg for $name<Space, T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self::new($(-self.$field),+)
+            }
+        }
+
+        impl<Space, T: AddAssign> AddAssign for $name<Space, T> {
+            fn add_assign(&mut self, other: Self) {
+                $(self.$field += other.$field;)+
+            }
+        }
+
+        impl<Space, T: SubAssign> SubAssign for $name<Space, T> {
+            fn sub_assign(&mut self, other: Self) {
+                $(self.$field -= other.$field;)+
+            }
+        }
+
+        impl<Space, T: Copy + MulAssign> MulAssign<T> for $name<Space, T> {
+            fn mul_assign(&mut self, scalar: T) {
+                $(self.$field *= scalar;)+
+            }
+        }
+
+        impl<Space, T: Copy + DivAssign> DivAssign<T> for $name<Space, T> {
+            fn div_assign(&mut self, scalar: T) {
+                $(self.$field /= scalar;)+
+            }
+        }
+
+        vector!(@float $name { $($field),+ }, f32);
+        vector!(@float $name { $($field),+ }, f64);
+    };
+    (@float $name:ident { $($field:ident),+ }, $float:ty) => {
+        impl<Space> $name<Space, $float> {
+            pub const ZERO: Self = Self::new($(vector!(@replace $field, 0.0)),+);
+
+            pub fn length_squared(self) -> $float {
+                self.dot(self)
+            }
+
+            pub fn length(self) -> $float {
+                self.length_squared().sqrt()
+            }
+
+            #[expect(clippy::arithmetic_side_effects, reason = "float components can't overflow")]
+            pub fn distance(self, other: Self) -> $float {
+                (other - self).length()
+            }
+
+            /// Returns `None` if the vector has a length of zero or is not finite
+            #[expect(clippy::arithmetic_side_effects, reason = "the length was checked to be non-zero")]
+            pub fn normalize(self) -> Option<Self> {
+                let length = self.length();
+                if length > 0.0 && length.is_finite() {
+                    Some(self / length)
+                } else {
+                    None
+                }
+            }
+
+            /// Linear interpolation, `t = 0.0` returns `self`, `t = 1.0` returns `other`
+            #[expect(clippy::arithmetic_side_effects, reason = "float components can't overflow")]
+            pub fn lerp(self, other: Self, t: $float) -> Self {
+                self + (other - self) * t
+            }
+        }
+    };
+    (@replace $_field:ident, $value:expr) => {
+        $value
+    };
+    (@sum $first:expr $(, $rest:expr)*) => {
+        $first $(+ $rest)*
+    };
+}
+
+vector!(Vec2 { x, y }, 2);
+vector!(Vec3 { x, y, z }, 3);
+vector!(Vec4 { x, y, z, w }, 4);
+
+impl<Space, T> Vec2<Space, T> {
+    pub fn extend(self, z: T) -> Vec3<Space, T> {
+        Vec3::new(self.x, self.y, z)
+    }
+
+    /// The z component of the 3D cross product, positive if `other` is counter-clockwise from `self`
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "generic over the component, integer vectors overflow the way their components do"
+    )]
+    pub fn perp_dot(self, other: Self) -> T
+    where
+        T: Copy + Sub<Output = T> + Mul<Output = T>,
+    {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Rotates the vector by 90° counter-clockwise
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "negation is the component's own `Neg`"
+    )]
+    pub fn perp(self) -> Self
+    where
+        T: Neg<Output = T>,
+    {
+        Self::new(-self.y, self.x)
+    }
+}
+
+impl<Space, T> Vec3<Space, T> {
+    pub fn extend(self, w: T) -> Vec4<Space, T> {
+        Vec4::new(self.x, self.y, self.z, w)
+    }
+
+    pub fn truncate(self) -> Vec2<Space, T> {
+        Vec2::new(self.x, self.y)
+    }
+
+    #[expect(
+        clippy::arithmetic_side_effects,
+        reason = "same as `Vec2::perp_dot`, integer components overflow as they would alone"
+    )]
+    pub fn cross(self, other: Self) -> Self
+    where
+        T: Copy + Sub<Output = T> + Mul<Output = T>,
+    {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+}
+
+impl<Space, T> Vec4<Space, T> {
+    pub fn truncate(self) -> Vec3<Space, T> {
+        Vec3::new(self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn vector_arithmetic() {
+        let a = WorldVec2::new(1.0, 2.0);
+        let b = WorldVec2::new(3.0, -4.0);
+
+        assert_eq!(a + b, WorldVec2::new(4.0, -2.0));
+        assert_eq!(a - b, WorldVec2::new(-2.0, 6.0));
+        assert_eq!(a * 2.0, WorldVec2::new(2.0, 4.0));
+        assert_eq!(-a, WorldVec2::new(-1.0, -2.0));
+        assert_eq!(a.dot(b), -5.0);
+        assert_eq!(b.length(), 5.0);
+        assert_eq!(a.perp_dot(b), -10.0);
+        assert_eq!(a.lerp(b, 0.5), WorldVec2::new(2.0, -1.0));
+
+        let x = WorldVec3::new(1.0, 0.0, 0.0);
+        let y = WorldVec3::new(0.0, 1.0, 0.0);
+        assert_eq!(x.cross(y), WorldVec3::new(0.0, 0.0, 1.0));
+
+        let n = WorldVec4::new(0.0, 3.0, 0.0, 4.0).normalize().unwrap();
+        assert_eq!(n, WorldVec4::new(0.0, 0.6, 0.0, 0.8));
+        assert_eq!(WorldVec3::ZERO.normalize(), None);
+
+        let cells = Vec2::<space::World, u32>::new(2, 3) + Vec2::splat(1);
+        assert_eq!(cells.to_array(), [3, 4]);
+    }
+}
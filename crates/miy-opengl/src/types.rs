@@ -153,6 +153,7 @@ impl BufferTarget {
     pub const COPY_WRITE_BUFFER: Self = Self(0x8F37);
     pub const ELEMENT_ARRAY_BUFFER: Self = Self(0x8893);
     pub const UNIFORM_BUFFER: Self = Self(0x8A11);
+    pub const SHADER_STORAGE_BUFFER: Self = Self(0x90D2);
     pub const TEXTURE_BUFFER: Self = Self(0x8C2A);
 }
 
@@ -192,6 +193,11 @@ pub enum BufferBinding {
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub struct UniformBlockIndex(u32);
 
+impl UniformBlockIndex {
+    /// Returned by `get_uniform_block_index` if the program has no block with that name
+    pub const INVALID: Self = Self(0xFFFF_FFFF);
+}
+
 #[repr(transparent)]
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub struct Shader(Uint);
@@ -292,6 +298,10 @@ pub struct TextureDataFormat(Enum);
 impl TextureDataFormat {
     pub const U8: Self = Self(0x1401);
     pub const F32: Self = Self(0x1406);
+    /// One `u32` per pixel, the first channel in the most significant byte
+    pub const U32_8_8_8_8: Self = Self(0x8035);
+    /// One `u32` per pixel, the first channel in the least significant byte
+    pub const U32_8_8_8_8_REV: Self = Self(0x8367);
 }
 
 #[repr(transparent)]
@@ -4,7 +4,7 @@ use alloc::{
     string::{String, ToString},
     vec,
 };
-use core::mem::MaybeUninit;
+use core::{ffi::CStr, mem::MaybeUninit};
 
 #[derive(Debug)]
 pub enum ShaderError {
@@ -113,3 +113,47 @@ pub fn create_buffer(
         buffer
     }
 }
+
+/// Creates a buffer holding `data` and binds it to the indexed `binding` point of `target`,
+/// which is [`gl::BufferTarget::UNIFORM_BUFFER`] or [`gl::BufferTarget::SHADER_STORAGE_BUFFER`].
+///
+/// `data` is usually a block written by `AsStd140::as_std140` or `AsStd430::as_std430`.
+pub fn create_block_buffer(
+    gl: &gl::Bindings,
+    target: gl::BufferTarget,
+    binding: gl::BufferBinding,
+    usage: gl::BufferUsage,
+    data: &[u8],
+) -> gl::Buffer {
+    let buffer = create_buffer(gl, target, usage, data);
+    unsafe {
+        gl.bind_buffer_base(target, binding, buffer);
+    }
+    buffer
+}
+
+/// Overwrites the start of `buffer` with `data`, which must fit into the buffer
+pub fn update_buffer(gl: &gl::Bindings, target: gl::BufferTarget, buffer: gl::Buffer, data: &[u8]) {
+    unsafe {
+        gl.bind_buffer(target, buffer);
+        gl.buffer_sub_data(target, 0, data.len() as gl::Sizeiptr, data.as_ptr().cast());
+    }
+}
+
+/// Connects the uniform block called `name` in `program` to the indexed `binding` point.
+/// Returns `false` if the program has no such block.
+pub fn bind_uniform_block(
+    gl: &gl::Bindings,
+    program: gl::Program,
+    name: &CStr,
+    binding: gl::BufferBinding,
+) -> bool {
+    unsafe {
+        let index = gl.get_uniform_block_index(program, name.as_ptr());
+        if index == gl::UniformBlockIndex::INVALID {
+            return false;
+        }
+        gl.uniform_block_binding(program, index, binding);
+        true
+    }
+}